@@ -1,3 +1,13 @@
 fn main() {
-    windows_macros::build!();
+    // The foundation types are generated here, so they only derive the serde traits, or
+    // implement the bytemuck traits, when the matching feature is enabled.
+    let serde = std::env::var_os("CARGO_FEATURE_SERDE").is_some();
+    let bytemuck = std::env::var_os("CARGO_FEATURE_BYTEMUCK").is_some();
+
+    match (serde, bytemuck) {
+        (true, true) => windows_macros::build!(serde = true, bytemuck = true),
+        (true, false) => windows_macros::build!(serde = true),
+        (false, true) => windows_macros::build!(bytemuck = true),
+        (false, false) => windows_macros::build!(),
+    }
 }
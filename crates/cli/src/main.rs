@@ -0,0 +1,284 @@
+use gen::*;
+use std::path::PathBuf;
+
+const USAGE: &str = "\
+Usage: windows_cli <command> [arguments]
+
+Commands:
+    diff <old> <new> [namespace...]
+        Reports the types, functions, and constants added, removed, or changed between two
+        sets of metadata, limited to the given namespaces and those nested within them. Each
+        set is a .winmd or .idl file or a directory containing them.
+
+    generate [--report] <output> <type...>
+        Writes formatted bindings for the given types, such as `Windows.Foundation.Uri`, or
+        namespaces, such as `Windows.Data.Xml.Dom.*`, to the output file, ready to be checked
+        in and included with `include!`. Metadata is found as it is by the `build` macro.
+
+        With `--report`, also lists how many types and lines each of the given types or
+        namespaces generates on its own, counting the types they depend on, to help find
+        imports that are costly to compile.
+
+    manifest <metadata> <server> [namespace...]
+        Prints the `Extensions` element that registers the activatable classes described by
+        the metadata, limited to the given namespaces and those nested within them, for a
+        packaged app's or sparse package's manifest. The server is the path of the DLL, or of
+        the executable if it ends with `.exe`, that implements the classes.
+
+    verify <bindings> <type...>
+        Regenerates bindings as `generate` does and compares them against the checked-in
+        bindings file, reporting where they differ and exiting with status 1 if they do.
+";
+
+// The number of differing lines of each file shown when bindings have drifted.
+const DRIFT_LINES: usize = 20;
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.first().map(|command| command.as_str()) {
+        Some("diff") => diff(&args[1..]),
+        Some("generate") => generate(&args[1..]),
+        Some("manifest") => manifest(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        _ => Err(USAGE.to_string()),
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        std::process::exit(2);
+    }
+}
+
+fn diff(args: &[String]) -> Result<(), String> {
+    if args.len() < 2 {
+        return Err(USAGE.to_string());
+    }
+
+    let old = reader(&args[0])?;
+    let new = reader(&args[1])?;
+    let namespaces: Vec<&str> = args[2..]
+        .iter()
+        .map(|namespace| namespace.as_str())
+        .collect();
+
+    let changes = diff_apis(old, new, &namespaces);
+
+    if changes.is_empty() {
+        println!("No changes");
+    }
+
+    for change in changes {
+        println!("{}", change);
+    }
+
+    Ok(())
+}
+
+fn generate(args: &[String]) -> Result<(), String> {
+    let report = args.iter().any(|arg| arg == "--report");
+    let args: Vec<String> = args
+        .iter()
+        .filter(|arg| *arg != "--report")
+        .cloned()
+        .collect();
+
+    let bindings = bindings(&args)?;
+
+    if report {
+        print_report(&args[1..])?;
+    }
+
+    std::fs::write(&args[0], bindings)
+        .map_err(|error| format!("Could not write {:?}: {}", args[0], error))
+}
+
+fn manifest(args: &[String]) -> Result<(), String> {
+    if args.len() < 2 {
+        return Err(USAGE.to_string());
+    }
+
+    let reader = reader(&args[0])?;
+    let namespaces: Vec<&str> = args[2..]
+        .iter()
+        .map(|namespace| namespace.as_str())
+        .collect();
+
+    let server = if args[1].to_lowercase().ends_with(".exe") {
+        ManifestServer::OutOfProcess(&args[1])
+    } else {
+        ManifestServer::InProcess(&args[1])
+    };
+
+    print!("{}", gen_manifest(reader, server, &namespaces));
+    Ok(())
+}
+
+fn verify(args: &[String]) -> Result<(), String> {
+    let bindings = bindings(args)?;
+
+    let checked_in = std::fs::read_to_string(&args[0])
+        .map_err(|error| format!("Could not read {:?}: {}", args[0], error))?
+        .replace("\r\n", "\n");
+
+    if checked_in == bindings {
+        println!("{} is up to date", args[0]);
+        return Ok(());
+    }
+
+    eprintln!(
+        "{} does not match the bindings generated from the current metadata and generator; run `windows_cli generate` to update it\n\n{}",
+        args[0],
+        drift(&checked_in, &bindings)
+    );
+
+    std::process::exit(1);
+}
+
+// Generates the bindings named by a `generate` or `verify` command's arguments.
+fn bindings(args: &[String]) -> Result<String, String> {
+    if args.len() < 2 {
+        return Err(USAGE.to_string());
+    }
+
+    let reader = winmd::TypeReader::get();
+    let limits = limits(reader, &args[1..])?;
+    let bindings = gen_bindings(reader, &limits, false);
+    Ok(format_code(bindings.as_str()))
+}
+
+fn limits(reader: &'static winmd::TypeReader, names: &[String]) -> Result<TypeLimits, String> {
+    let mut limits = TypeLimits::new(reader);
+
+    for name in names {
+        let types = namespace_types(reader, name)?;
+        limits
+            .insert(types)
+            .map_err(|namespace| format!("`{}` is not a known namespace", namespace))?;
+    }
+
+    Ok(limits)
+}
+
+// Prints the size of the bindings for each of the given types or namespaces on their own. As
+// types are shared between them, the sizes may add up to more than the size of the bindings.
+fn print_report(names: &[String]) -> Result<(), String> {
+    let reader = winmd::TypeReader::get();
+
+    println!("{:>8} {:>8} {:>8}  requested", "types", "deps", "lines");
+
+    for name in names {
+        let limits = limits(reader, std::slice::from_ref(name))?;
+        let tree = bindings_tree(reader, &limits, false);
+
+        let types = tree.type_count();
+        let requested = limits
+            .limits()
+            .map(|limit| match &limit.limit {
+                TypeLimit::All => tree
+                    .get(limit.namespace)
+                    .map_or(0, |namespace| namespace.types.len()),
+                TypeLimit::Some(types) => types.len(),
+            })
+            .sum::<usize>();
+
+        let code = tree.gen().fold(String::new(), |mut code, tokens| {
+            code.push(' ');
+            code.push_str(tokens.as_str());
+            code
+        });
+        let lines = format_code(&code).lines().count();
+
+        println!(
+            "{:>8} {:>8} {:>8}  {}",
+            types,
+            types.saturating_sub(requested),
+            lines,
+            name
+        );
+    }
+
+    Ok(())
+}
+
+// Describes the lines between the first and last that differ, as `-` lines from the checked-in
+// file and `+` lines from the regenerated bindings.
+fn drift(checked_in: &str, generated: &str) -> String {
+    let old: Vec<&str> = checked_in.lines().collect();
+    let new: Vec<&str> = generated.lines().collect();
+
+    let prefix = old
+        .iter()
+        .zip(&new)
+        .take_while(|(old, new)| old == new)
+        .count();
+
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+
+    let old = &old[prefix..old.len() - suffix];
+    let new = &new[prefix..new.len() - suffix];
+
+    let mut report = format!("@@ line {} @@\n", prefix + 1);
+
+    for (sign, lines) in &[('-', old), ('+', new)] {
+        for line in lines.iter().take(DRIFT_LINES) {
+            report.push_str(&format!("{} {}\n", sign, line));
+        }
+
+        if lines.len() > DRIFT_LINES {
+            report.push_str(&format!(
+                "{} ... {} more lines\n",
+                sign,
+                lines.len() - DRIFT_LINES
+            ));
+        }
+    }
+
+    report
+}
+
+// Names a namespace with a trailing `.*`, or as is, and a type by its full name.
+fn namespace_types(
+    reader: &'static winmd::TypeReader,
+    name: &str,
+) -> Result<NamespaceTypes, String> {
+    let namespace = name.trim_end_matches(".*");
+
+    if let Some(namespace) = reader.find_lowercase_namespace(&namespace.to_lowercase()) {
+        return Ok(NamespaceTypes {
+            namespace,
+            limit: TypeLimit::All,
+        });
+    }
+
+    let position = name
+        .rfind('.')
+        .ok_or_else(|| format!("`{}` is not a known type or namespace", name))?;
+
+    let namespace = reader
+        .find_lowercase_namespace(&name[..position].to_lowercase())
+        .ok_or_else(|| format!("`{}` is not a known type or namespace", name))?;
+
+    Ok(NamespaceTypes {
+        namespace,
+        limit: TypeLimit::Some(vec![name[position + 1..].to_string()]),
+    })
+}
+
+fn reader(path: &str) -> Result<&'static winmd::TypeReader, String> {
+    let path = PathBuf::from(path);
+
+    if !path.exists() {
+        return Err(format!("{:?} does not exist", path));
+    }
+
+    // The reader hands out metadata with a static lifetime, so it lives as long as the process.
+    let reader =
+        winmd::TypeReader::try_from_paths(vec![path]).map_err(|error| error.to_string())?;
+    Ok(Box::leak(Box::new(reader)))
+}
@@ -0,0 +1,14 @@
+#![no_main]
+
+// Feeds arbitrary bytes to the winmd file parser and then to the loader that indexes the build's
+// metadata, as `build!` does, which must reject malformed input with an error rather than
+// panicking or reading out of bounds. Run with `cargo fuzz run winmd_file`.
+
+use gen::winmd::{File, TypeReader};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(file) = File::try_from_bytes(data.to_vec()) {
+        let _ = TypeReader::try_from_files(vec![file]);
+    }
+});
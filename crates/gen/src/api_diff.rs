@@ -0,0 +1,395 @@
+use crate::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A difference between two sets of metadata, as found by [`diff_apis`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ApiChange {
+    /// A type, function, or constant was added.
+    Added { name: String, kind: &'static str },
+    /// A type, function, or constant was removed.
+    Removed { name: String, kind: &'static str },
+    /// A method, field, enum value, or implemented interface was added to a type.
+    MemberAdded { name: String, member: String },
+    /// A method, field, enum value, or implemented interface was removed from a type.
+    MemberRemoved { name: String, member: String },
+    /// A constant's value, a function's signature, or an enum value changed.
+    Changed {
+        name: String,
+        old: String,
+        new: String,
+    },
+}
+
+impl std::fmt::Display for ApiChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Added { name, kind } => write!(f, "+ {} {}", kind, name),
+            Self::Removed { name, kind } => write!(f, "- {} {}", kind, name),
+            Self::MemberAdded { name, member } => write!(f, "~ {}\n    + {}", name, member),
+            Self::MemberRemoved { name, member } => write!(f, "~ {}\n    - {}", name, member),
+            Self::Changed { name, old, new } => write!(f, "~ {}\n    {}\n => {}", name, old, new),
+        }
+    }
+}
+
+// An API is described by its members, keyed such that a member whose description changes
+// is reported as changed rather than as being removed and added.
+struct Api {
+    kind: &'static str,
+    members: BTreeMap<String, String>,
+}
+
+/// Compares the types, functions, and constants in the given namespaces, and any namespaces
+/// nested within them, returning the changes from the `old` metadata to the `new` metadata.
+///
+/// All namespaces are compared if none are given.
+pub fn diff_apis(
+    old: &'static winmd::TypeReader,
+    new: &'static winmd::TypeReader,
+    namespaces: &[&str],
+) -> Vec<ApiChange> {
+    let old = describe_apis(old, namespaces);
+    let mut new = describe_apis(new, namespaces);
+    let mut changes = Vec::new();
+
+    for (name, old) in old {
+        let new = match new.remove(&name) {
+            Some(new) => new,
+            None => {
+                changes.push(ApiChange::Removed {
+                    name,
+                    kind: old.kind,
+                });
+                continue;
+            }
+        };
+
+        if old.kind != new.kind {
+            changes.push(ApiChange::Changed {
+                name,
+                old: old.kind.to_string(),
+                new: new.kind.to_string(),
+            });
+            continue;
+        }
+
+        let keys: BTreeSet<&String> = old.members.keys().chain(new.members.keys()).collect();
+
+        for key in keys {
+            match (old.members.get(key), new.members.get(key)) {
+                (Some(member), None) => changes.push(ApiChange::MemberRemoved {
+                    name: name.clone(),
+                    member: member.clone(),
+                }),
+                (None, Some(member)) => changes.push(ApiChange::MemberAdded {
+                    name: name.clone(),
+                    member: member.clone(),
+                }),
+                (Some(old), Some(new)) if old != new => changes.push(ApiChange::Changed {
+                    name: name.clone(),
+                    old: old.clone(),
+                    new: new.clone(),
+                }),
+                _ => {}
+            }
+        }
+    }
+
+    for (name, new) in new {
+        changes.push(ApiChange::Added {
+            name,
+            kind: new.kind,
+        });
+    }
+
+    changes
+}
+
+fn describe_apis(reader: &'static winmd::TypeReader, namespaces: &[&str]) -> BTreeMap<String, Api> {
+    let mut apis = BTreeMap::new();
+
+    for namespace in selected_namespaces(reader, namespaces) {
+        for t in reader.namespace_types(namespace) {
+            let (name, api) = match t {
+                winmd::Type::TypeDef(def) => match describe_type_def(&def) {
+                    Some(api) => (def.name().1, api),
+                    None => continue,
+                },
+                winmd::Type::MethodDef((_, method)) => {
+                    let signature = Signature::new(&method, &[], namespace);
+                    let mut members = BTreeMap::new();
+                    members.insert(String::new(), describe_method(&signature));
+
+                    let api = Api {
+                        kind: "function",
+                        members,
+                    };
+
+                    (method.name(), api)
+                }
+                winmd::Type::Field((_, field)) => {
+                    let mut members = BTreeMap::new();
+                    members.insert(String::new(), describe_field(&field, namespace));
+
+                    let api = Api {
+                        kind: "constant",
+                        members,
+                    };
+
+                    (field.name(), api)
+                }
+            };
+
+            apis.insert(format!("{}.{}", namespace, name), api);
+        }
+    }
+
+    apis
+}
+
+/// Returns the given namespaces and any namespaces nested within them, or all namespaces if none
+/// are given.
+pub(crate) fn selected_namespaces(
+    reader: &'static winmd::TypeReader,
+    namespaces: &[&str],
+) -> Vec<&'static str> {
+    reader
+        .namespaces()
+        .map(|namespace| namespace.as_str())
+        .filter(|namespace| {
+            !namespace.is_empty()
+                && (namespaces.is_empty()
+                    || namespaces.iter().any(|selected| {
+                        namespace == selected
+                            || (namespace.starts_with(selected)
+                                && namespace[selected.len()..].starts_with('.'))
+                    }))
+        })
+        .collect()
+}
+
+fn describe_type_def(def: &winmd::TypeDef) -> Option<Api> {
+    let name = TypeName::from_type_def(def, def.name().0);
+
+    let kind = match def.category() {
+        winmd::TypeCategory::Interface => "interface",
+        winmd::TypeCategory::Class => "class",
+        winmd::TypeCategory::Enum => "enum",
+        winmd::TypeCategory::Struct => "struct",
+        winmd::TypeCategory::Delegate => "delegate",
+        winmd::TypeCategory::Attribute | winmd::TypeCategory::Contract => return None,
+    };
+
+    let mut members = BTreeMap::new();
+
+    for interface in def.interfaces() {
+        // Interfaces are described by name since some, like `IUnknown`, are referenced but
+        // not defined by the metadata.
+        let interface =
+            match interface.interface() {
+                winmd::TypeDefOrRef::TypeSpec(spec) => describe_type_name(
+                    &TypeName::from_type_spec(&spec, &name.generics, name.namespace),
+                ),
+                interface => {
+                    let (namespace, name) = interface.name();
+                    format!("{}.{}", namespace, name)
+                }
+            };
+
+        let member = format!("implements {}", interface);
+        members.insert(member.clone(), member);
+    }
+
+    for field in def.fields() {
+        if field.name() == "value__" {
+            continue;
+        }
+
+        members.insert(
+            format!("field {}", field.name()),
+            describe_field(&field, name.namespace),
+        );
+    }
+
+    for method in def.methods() {
+        let signature = Signature::new(&method, &name.generics, name.namespace);
+        let member = describe_method(&signature);
+        members.insert(member.clone(), member);
+    }
+
+    Some(Api { kind, members })
+}
+
+fn describe_field(field: &winmd::Field, namespace: &'static str) -> String {
+    if let Some(constant) = field.constant() {
+        return format!("{}: {}", field.name(), describe_constant(&constant.value()));
+    }
+
+    format!(
+        "{}: {}",
+        field.name(),
+        describe_type(&Type::from_field(field, namespace))
+    )
+}
+
+fn describe_constant(value: &winmd::ConstantValue) -> String {
+    match value {
+        winmd::ConstantValue::U8(value) => format!("u8 = {}", value),
+        winmd::ConstantValue::I8(value) => format!("i8 = {}", value),
+        winmd::ConstantValue::U16(value) => format!("u16 = {}", value),
+        winmd::ConstantValue::I16(value) => format!("i16 = {}", value),
+        winmd::ConstantValue::U32(value) => format!("u32 = {}", value),
+        winmd::ConstantValue::I32(value) => format!("i32 = {}", value),
+        winmd::ConstantValue::U64(value) => format!("u64 = {}", value),
+        winmd::ConstantValue::I64(value) => format!("i64 = {}", value),
+        winmd::ConstantValue::F32(value) => format!("f32 = {}", value),
+        winmd::ConstantValue::F64(value) => format!("f64 = {}", value),
+        winmd::ConstantValue::String(value) => format!("&str = {:?}", value),
+    }
+}
+
+fn describe_method(signature: &Signature) -> String {
+    let params: Vec<String> = signature
+        .params
+        .iter()
+        .map(|param| {
+            let direction = if param.is_input { "" } else { "out " };
+            let name = param.param.map_or("", |param| param.name());
+            format!("{}{}: {}", direction, name, describe_type(param))
+        })
+        .collect();
+
+    let mut description = format!("{}({})", signature.method.name(), params.join(", "));
+
+    if let Some(return_type) = &signature.return_type {
+        description.push_str(" -> ");
+        description.push_str(&describe_type(return_type));
+    }
+
+    description
+}
+
+fn describe_type(t: &Type) -> String {
+    let mut description = "*mut ".repeat(t.pointers);
+    description.push_str(&describe_kind(&t.kind));
+
+    if let Some(len) = t.array {
+        description = format!("[{}; {}]", description, len);
+    } else if t.is_array {
+        description = format!("[{}]", description);
+    }
+
+    description
+}
+
+fn describe_kind(kind: &TypeKind) -> String {
+    let name = match kind {
+        TypeKind::Void => "void",
+        TypeKind::Bool => "bool",
+        TypeKind::Char => "char",
+        TypeKind::I8 => "i8",
+        TypeKind::U8 => "u8",
+        TypeKind::I16 => "i16",
+        TypeKind::U16 => "u16",
+        TypeKind::I32 => "i32",
+        TypeKind::U32 => "u32",
+        TypeKind::I64 => "i64",
+        TypeKind::U64 => "u64",
+        TypeKind::F32 => "f32",
+        TypeKind::F64 => "f64",
+        TypeKind::ISize => "isize",
+        TypeKind::USize => "usize",
+        TypeKind::String => "String",
+        TypeKind::Object => "Object",
+        TypeKind::Guid => "Guid",
+        TypeKind::IUnknown => "IUnknown",
+        TypeKind::ErrorCode => "HRESULT",
+        TypeKind::Bool32 => "BOOL",
+        TypeKind::NtStatus => "NTSTATUS",
+        TypeKind::Matrix3x2 => "Matrix3x2",
+        TypeKind::Class(name)
+        | TypeKind::Interface(name)
+        | TypeKind::Enum(name)
+        | TypeKind::Struct(name)
+        | TypeKind::Delegate(name) => return describe_type_name(name),
+        TypeKind::Generic(name) => name,
+        TypeKind::NotYetSupported => "?",
+    };
+
+    name.to_string()
+}
+
+fn describe_type_name(name: &TypeName) -> String {
+    let mut description = format!("{}.{}", name.namespace, name.name);
+
+    if !name.generics.is_empty() {
+        let generics: Vec<String> = name.generics.iter().map(describe_kind).collect();
+        description = format!("{}<{}>", description, generics.join(", "));
+    }
+
+    description
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn reader(idl: &str) -> &'static winmd::TypeReader {
+        let bytes = winmd::compile_idl("Test.winmd", idl).unwrap();
+        let file = winmd::File::try_from_bytes(bytes).unwrap();
+        Box::leak(Box::new(
+            winmd::TypeReader::try_from_files(vec![file]).unwrap(),
+        ))
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = reader(
+            r#"
+            namespace Test
+            {
+                enum Color { Red = 0, Green = 1 };
+                struct Size { Int32 Width; Int32 Height; };
+                [uuid(5a6e2f0c-7c39-4d55-8e8b-2c6f0e8d9c11)]
+                interface IWidget { void Draw(Int32 x); };
+                struct Removed { Int32 Value; };
+            }
+            namespace Other
+            {
+                struct Ignored { Int32 Value; };
+            }
+            "#,
+        );
+
+        let new = reader(
+            r#"
+            namespace Test
+            {
+                enum Color { Red = 0, Green = 2, Blue = 3 };
+                struct Size { Int32 Width; Int32 Height; };
+                [uuid(5a6e2f0c-7c39-4d55-8e8b-2c6f0e8d9c11)]
+                interface IWidget { void Draw(Int64 x); };
+                struct Added { Int32 Value; };
+            }
+            "#,
+        );
+
+        let changes = diff_apis(old, new, &["Test"]);
+
+        let report: Vec<String> = changes.iter().map(|change| change.to_string()).collect();
+
+        assert_eq!(
+            report,
+            [
+                "~ Test.Color\n    + Blue: i32 = 3",
+                "~ Test.Color\n    Green: i32 = 1\n => Green: i32 = 2",
+                "~ Test.IWidget\n    - Draw(x: i32)",
+                "~ Test.IWidget\n    + Draw(x: i64)",
+                "- struct Test.Removed",
+                "+ struct Test.Added",
+            ]
+        );
+
+        assert!(diff_apis(old, old, &[]).is_empty());
+    }
+}
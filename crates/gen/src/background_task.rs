@@ -0,0 +1,37 @@
+use crate::*;
+use squote::{quote, TokenStream};
+
+// Registering a background task adds a new registration each time, even if one with the same
+// name already exists, so apps that register on every launch end up running the task several
+// times over. `BackgroundTaskBuilder::register_named` replaces any existing registration instead.
+pub fn gen_background_task(name: &TypeName) -> TokenStream {
+    if name.namespace != "Windows.ApplicationModel.Background"
+        || name.name != "BackgroundTaskBuilder"
+    {
+        return TokenStream::new();
+    }
+
+    quote! {
+        impl BackgroundTaskBuilder {
+            /// Registers a task named `name` that runs when `trigger` fires, first unregistering
+            /// any task already registered under that name.
+            pub fn register_named<'a, T: ::std::convert::Into<::windows::Param<'a, IBackgroundTrigger>>>(
+                name: &str,
+                trigger: T,
+            ) -> ::windows::Result<BackgroundTaskRegistration> {
+                for task in BackgroundTaskRegistration::all_tasks()? {
+                    let task = task.value()?;
+
+                    if task.name()? == name {
+                        task.unregister(true)?;
+                    }
+                }
+
+                let builder = Self::new()?;
+                builder.set_name(name)?;
+                builder.set_trigger(trigger)?;
+                builder.register()
+            }
+        }
+    }
+}
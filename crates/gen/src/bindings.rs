@@ -0,0 +1,57 @@
+use crate::*;
+use squote::TokenStream;
+
+/// The namespaces included in the `windows` crate itself.
+pub const FOUNDATION_NAMESPACES: &[&str] = &[
+    "Windows.Foundation",
+    "Windows.Foundation.Collections",
+    "Windows.Foundation.Diagnostics",
+    "Windows.Foundation.Numerics",
+];
+
+/// Builds the module tree for the given limits.
+///
+/// Unless `foundation` is set, as it is for the `windows` crate's own bindings, the foundation
+/// namespaces are left out and re-exported from the `windows` crate instead.
+pub fn bindings_tree(
+    reader: &'static winmd::TypeReader,
+    limits: &TypeLimits,
+    foundation: bool,
+) -> TypeTree {
+    let mut tree = TypeTree::from_limits(reader, limits);
+
+    if !foundation {
+        for namespace in FOUNDATION_NAMESPACES {
+            tree.remove(namespace);
+        }
+
+        tree.reexport();
+    }
+
+    tree
+}
+
+/// Generates the code for the module tree built by [`bindings_tree`], along with the
+/// `provenance` module describing where it came from.
+pub fn gen_bindings(
+    reader: &'static winmd::TypeReader,
+    limits: &TypeLimits,
+    foundation: bool,
+) -> TokenStream {
+    bindings_tree(reader, limits, foundation)
+        .gen()
+        .fold(gen_provenance(reader), |mut accum, n| {
+            accum.combine(&n);
+            accum
+        })
+}
+
+/// Lists the deprecated types and ANSI functions left out of the module tree for the given
+/// limits by [`GenOptions::exclude_deprecated`] and [`GenOptions::exclude_ansi`], one per line.
+pub fn gen_exclusions(reader: &'static winmd::TypeReader, limits: &TypeLimits) -> String {
+    TypeTree::from_limits(reader, limits)
+        .excluded
+        .iter()
+        .map(|name| format!("{}\n", name))
+        .collect()
+}
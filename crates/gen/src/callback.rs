@@ -25,6 +25,8 @@ impl Callback {
 
     pub fn gen(&self) -> TokenStream {
         let name = self.name.gen();
+        let doc = self.name.gen_doc("callback");
+        let deprecated = gen_deprecated(self.name.def.attributes());
 
         let params = self.signature.params.iter().map(|t| {
             let name = format_ident(&t.name);
@@ -39,9 +41,28 @@ impl Callback {
             TokenStream::new()
         };
 
+        // Callbacks are unsafe to call since they're handed raw pointers, and are wrapped in an
+        // `Option` wherever they're used so that null can be passed or stored.
         quote! {
+            #doc
+            #deprecated
             #[allow(non_camel_case_types)]
-            pub type #name = extern "system" fn(#(#params),*) #return_type;
+            pub type #name = unsafe extern "system" fn(#(#params),*) #return_type;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_callback() {
+        let reader = winmd::TypeReader::get();
+        let def = reader.expect_type_def(("Windows.Win32.WindowsAndMessaging", "PROPENUMPROCW"));
+        let tokens = TypeDefinition::from_type_def(&def).gen().into_string();
+        assert!(
+            tokens.contains("pub type PROPENUMPROCW = unsafe extern \"system\" fn ( param0 : HWND")
+        );
+    }
+}
@@ -92,6 +92,13 @@ impl Class {
             }
         }
 
+        if name
+            .def
+            .has_attribute(("Windows.Foundation.Metadata", "FastAbiAttribute"))
+        {
+            add_fast_abi(&mut interfaces, &name.def);
+        }
+
         rename_collisions(&mut interfaces);
 
         Self {
@@ -114,8 +121,13 @@ impl Class {
 
     pub fn gen(&self) -> TokenStream {
         let name = self.name.gen();
+        let doc = self.name.gen_doc("class");
+        let deprecated = gen_deprecated(self.name.def.attributes());
         let type_name = self.type_name(&name);
-        let methods = gen_method(&self.interfaces);
+        let owners: Vec<TypeName> = std::iter::once(self.name.clone())
+            .chain(self.bases.iter().cloned())
+            .collect();
+        let methods = gen_method(&self.interfaces, &owners);
         let call_factory = self.gen_call_factory();
 
         if let Some(default_interface) = self
@@ -129,7 +141,14 @@ impl Class {
                 .map(|interface| interface.gen_conversions(&name, &TokenStream::new()));
 
             let new = if self.default_constructor {
+                let doc = gen_doc(&format!(
+                    "Creates a new [`{}`]({}).",
+                    self.name.doc_name(),
+                    self.name.doc_url(Some("-ctor"))
+                ));
+
                 quote! {
+                    #doc
                     pub fn new() -> ::windows::Result<Self> {
                         Self::IActivationFactory(|f| f.activate_instance::<Self>())
                     }
@@ -145,6 +164,10 @@ impl Class {
             let default_name = default_interface.name.gen();
             let abi_name = default_interface.name.gen_abi();
             let (async_get, future) = gen_async(&self.name, &self.interfaces);
+            let cast = gen_cast();
+            let deferral = gen_deferral(&self.interfaces);
+            let data_stream = gen_data_stream(&self.name);
+            let background_task = gen_background_task(&self.name);
 
             let send_sync = if self.is_agile {
                 let constraints = self.name.gen_constraint();
@@ -157,6 +180,8 @@ impl Class {
             };
 
             quote! {
+                #doc
+                #deprecated
                 #[repr(transparent)]
                 pub struct #name(::windows::Object);
                 impl #name {
@@ -164,6 +189,8 @@ impl Class {
                     #methods
                     #async_get
                     #call_factory
+                    #cast
+                    #deferral
                 }
                 impl ::std::clone::Clone for #name {
                     fn clone(&self) -> Self {
@@ -207,7 +234,27 @@ impl Class {
                 }
                 impl<'a> ::std::convert::Into<::windows::Param<'a, ::windows::Object>> for &'a #name {
                     fn into(self) -> ::windows::Param<'a, ::windows::Object> {
-                        ::windows::Param::Owned(::std::convert::Into::<::windows::Object>::into(::std::clone::Clone::clone(self)))
+                        ::windows::Param::Borrowed(&self.0)
+                    }
+                }
+                impl ::std::convert::From<#name> for ::windows::IUnknown {
+                    fn from(value: #name) -> Self {
+                        value.0.into()
+                    }
+                }
+                impl ::std::convert::From<&#name> for ::windows::IUnknown {
+                    fn from(value: &#name) -> Self {
+                        ::std::convert::From::from(::std::clone::Clone::clone(value))
+                    }
+                }
+                impl<'a> ::std::convert::Into<::windows::Param<'a, ::windows::IUnknown>> for #name {
+                    fn into(self) -> ::windows::Param<'a, ::windows::IUnknown> {
+                        ::windows::Param::Owned(::std::convert::Into::<::windows::IUnknown>::into(self))
+                    }
+                }
+                impl<'a> ::std::convert::Into<::windows::Param<'a, ::windows::IUnknown>> for &'a #name {
+                    fn into(self) -> ::windows::Param<'a, ::windows::IUnknown> {
+                        ::windows::Param::Borrowed(unsafe { ::std::mem::transmute(self) })
                     }
                 }
                 #(#conversions)*
@@ -215,9 +262,13 @@ impl Class {
                 #iterator
                 #send_sync
                 #future
+                #data_stream
+                #background_task
             }
         } else {
             quote! {
+                #doc
+                #deprecated
                 pub struct #name {}
                 impl #name {
                     #methods
@@ -310,7 +361,7 @@ impl Class {
     }
 }
 
-fn attribute_factory(attribute: &winmd::Attribute) -> Option<winmd::TypeDef> {
+pub(crate) fn attribute_factory(attribute: &winmd::Attribute) -> Option<winmd::TypeDef> {
     for (_, arg) in attribute.args() {
         if let winmd::AttributeArg::TypeDef(def) = arg {
             return Some(def);
@@ -320,6 +371,64 @@ fn attribute_factory(attribute: &winmd::Attribute) -> Option<winmd::TypeDef> {
     None
 }
 
+// Whether the interface holds the class's non-default constructors.
+pub(crate) fn is_activation_factory(class: &TypeName, interface: &TypeName) -> bool {
+    class.def.attributes().any(|attribute| {
+        attribute.name() == ("Windows.Foundation.Metadata", "ActivatableAttribute")
+            && attribute_factory(&attribute) == Some(interface.def)
+    })
+}
+
+// A fast abi class appends the methods of its exclusive interfaces to the vtable of its default
+// interface, ordered by version, so that they may be called without a QueryInterface.
+fn add_fast_abi(interfaces: &mut [RequiredInterface], class: &winmd::TypeDef) {
+    let mut vtable_offset = match interfaces.iter().find(|i| i.kind == InterfaceKind::Default) {
+        Some(default) => 6 + default.methods.len() as u32,
+        None => return,
+    };
+
+    let mut fast: Vec<&mut RequiredInterface> = interfaces
+        .iter_mut()
+        .filter(|i| i.kind == InterfaceKind::NonDefault && is_exclusive_to(&i.name.def, class))
+        .collect();
+
+    fast.sort_by_key(|i| interface_version(&i.name.def));
+
+    for interface in fast {
+        interface.kind = InterfaceKind::FastAbi;
+
+        for method in &mut interface.methods {
+            method.vtable_offset = vtable_offset;
+            vtable_offset += 1;
+        }
+    }
+}
+
+pub(crate) fn is_exclusive_to(interface: &winmd::TypeDef, class: &winmd::TypeDef) -> bool {
+    interface.attributes().any(|attribute| {
+        attribute.name() == ("Windows.Foundation.Metadata", "ExclusiveToAttribute")
+            && attribute_factory(&attribute).as_ref() == Some(class)
+    })
+}
+
+fn interface_version(interface: &winmd::TypeDef) -> u32 {
+    for attribute in interface.attributes() {
+        match attribute.name() {
+            ("Windows.Foundation.Metadata", "VersionAttribute")
+            | ("Windows.Foundation.Metadata", "ContractVersionAttribute") => {
+                for (_, arg) in attribute.args() {
+                    if let winmd::AttributeArg::U32(version) = arg {
+                        return version;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    0
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -582,4 +691,48 @@ mod tests {
         let t = class(("Windows.Foundation", "Uri"));
         assert!(t.is_agile == true);
     }
+
+    #[test]
+    fn test_deferral() {
+        let t = class(("Windows.ApplicationModel", "SuspendingOperation"));
+        assert!(t.gen().to_string().contains("pub fn with_deferral"));
+
+        let t = class(("Windows.Foundation", "Uri"));
+        assert!(!t.gen().to_string().contains("with_deferral"));
+    }
+
+    #[test]
+    fn test_docs() {
+        let tokens = class(("Windows.Foundation", "Uri")).gen().into_string();
+        assert!(tokens.contains("\"The [`Uri`](https://docs.microsoft.com/uwp/api/windows.foundation.uri) class from `Windows.Foundation`.\""));
+        assert!(tokens.contains("\"The [`AbsoluteUri`](https://docs.microsoft.com/uwp/api/windows.foundation.uri.absoluteuri) property of `Uri`.\""));
+        assert!(tokens.contains("\"The [`ToString`](https://docs.microsoft.com/uwp/api/windows.foundation.uri.tostring) method of `Uri`.\""));
+        assert!(tokens.contains("\"Creates a new [`Uri`](https://docs.microsoft.com/uwp/api/windows.foundation.uri.-ctor).\""));
+
+        // Members inherited from a base class are documented on the base class.
+        let tokens = class(("Windows.UI.Composition", "SpriteVisual"))
+            .gen()
+            .into_string();
+        assert!(tokens.contains("\"The [`Brush`](https://docs.microsoft.com/uwp/api/windows.ui.composition.spritevisual.brush) property of `SpriteVisual`.\""));
+        assert!(tokens.contains("\"The [`Offset`](https://docs.microsoft.com/uwp/api/windows.ui.composition.visual.offset) property of `Visual`.\""));
+    }
+
+    #[test]
+    fn test_fast_abi() {
+        // Uri isn't marked as fast abi but has an exclusive non-default interface to exercise.
+        let mut t = class(("Windows.Foundation", "Uri"));
+        let def = t.name.def;
+        super::add_fast_abi(&mut t.interfaces, &def);
+
+        let default_methods = interface(&t, "IUriRuntimeClass").methods.len() as u32;
+        let fast = interface(&t, "IUriRuntimeClassWithAbsoluteCanonicalUri");
+        assert!(fast.kind == InterfaceKind::FastAbi);
+        assert!(fast.methods[0].vtable_offset == 6 + default_methods);
+        assert!(fast.methods[1].vtable_offset == 7 + default_methods);
+
+        // IStringable isn't exclusive to Uri so must still be queried for.
+        let stringable = interface(&t, "IStringable");
+        assert!(stringable.kind == InterfaceKind::NonDefault);
+        assert!(stringable.methods[0].vtable_offset == 6);
+    }
 }
@@ -13,7 +13,11 @@ pub struct ComInterface {
 #[derive(Debug)]
 struct Method {
     signature: Signature,
+    /// The name of the interface that declares the method, which may be a base interface.
+    interface: &'static str,
     overload: u32,
+    /// Whether the first parameter is the hidden pointer through which a struct is returned.
+    hidden_return: bool,
 }
 
 impl Method {
@@ -24,6 +28,17 @@ impl Method {
             format_ident(self.signature.method.name())
         }
     }
+
+    fn gen_doc(&self) -> TokenStream {
+        let name = self.signature.method.name();
+
+        gen_doc(&format!(
+            "The [`{}`]({}) method of `{}`.",
+            name,
+            search_doc_url(&format!("{}::{}", self.interface, name)),
+            self.interface
+        ))
+    }
 }
 
 impl ComInterface {
@@ -57,6 +72,8 @@ impl ComInterface {
             .map(|name| name.def)
             .chain(std::iter::once(name.def))
         {
+            let interface = def.name().1;
+
             for method in def.methods() {
                 let count = count.entry(method.name()).or_insert(0);
                 *count += 1;
@@ -66,20 +83,33 @@ impl ComInterface {
                 // Many years ago, the Visual C++ compiler engineers decided to diverge from the stdcall calling convention
                 // for virtual functions returning UDTs. These return values must actually be returned via a hidden output
                 // pointer. This is now part of the COM ABI and other compilers must follow along to ensure the ABI is
-                // correctly preserved.
+                // correctly preserved. Typedefs like `HDC`, `HANDLE`, and `LRESULT` are scalars
+                // rather than UDTs in the headers, so they're still returned in a register.
+
+                let mut hidden_return = false;
 
                 if let Some(t) = &signature.return_type {
-                    if let TypeKind::Struct(_) = t.kind {
-                        let mut param = t.clone();
-                        param.pointers += 1;
-                        signature.params.insert(0, param);
-                        signature.return_type = None;
+                    if let TypeKind::Struct(name) = &t.kind {
+                        if !name
+                            .def
+                            .has_attribute(("Windows.Win32.Interop", "NativeTypedefAttribute"))
+                        {
+                            let mut param = t.clone();
+                            param.pointers += 1;
+                            param.name = "result__".to_string();
+                            param.is_input = false;
+                            signature.params.insert(0, param);
+                            signature.return_type = None;
+                            hidden_return = true;
+                        }
                     }
                 }
 
                 methods.push(Method {
                     signature,
+                    interface,
                     overload: *count,
+                    hidden_return,
                 });
             }
         }
@@ -93,6 +123,8 @@ impl ComInterface {
 
     pub fn gen(&self) -> TokenStream {
         let name = self.name.gen();
+        let doc = self.name.gen_doc("interface");
+        let deprecated = gen_deprecated(self.name.def.attributes());
         let abi_name = self.name.gen_abi_definition();
         let guid = TypeGuid::from_type_def(&self.name.def);
         let guid = self.name.gen_guid(&guid);
@@ -100,6 +132,32 @@ impl ComInterface {
         // TODO: here we're looking up the param name (from the file) repeatedly - cache name in Type
 
         let methods = self.methods.iter().enumerate().map(|(vtable_offset, method)| {
+            let constraints = gen_constraint(method);
+            let params = gen_params(method);
+            let args = gen_abi_args(method);
+            let name = method.gen_name();
+            let vtable_offset = Literal::u32_unsuffixed((vtable_offset + 3) as u32);
+            let doc = method.gen_doc();
+            let deprecated = gen_deprecated(method.signature.method.attributes());
+
+            // The struct is returned by value, with the hidden pointer the ABI expects passed
+            // on the caller's behalf.
+            if method.hidden_return {
+                let mut return_type = method.signature.params[0].clone();
+                return_type.pointers -= 1;
+                let return_type = return_type.gen_field();
+
+                return quote! {
+                    #doc
+                    #deprecated
+                    pub unsafe fn #name<#constraints>(&self, #params) -> #return_type {
+                        let mut result__: #return_type = ::std::default::Default::default();
+                        (::windows::Interface::vtable(self).#vtable_offset)(::windows::Abi::abi(self), &mut result__, #args);
+                        result__
+                    }
+                };
+            }
+
             let return_type = if let Some(t) = &method.signature.return_type {
                 let tokens = t.gen_field();
                 quote! { -> #tokens }
@@ -107,13 +165,9 @@ impl ComInterface {
                 TokenStream::new()
             };
 
-            let constraints = gen_constraint(method);
-            let params = gen_params(method);
-            let args = gen_abi_args(method);
-            let name = method.gen_name();
-            let vtable_offset = Literal::u32_unsuffixed((vtable_offset + 3) as u32);
-
             quote! {
+                #doc
+                #deprecated
                 pub unsafe fn #name<#constraints>(&self, #params) #return_type {
                     (::windows::Interface::vtable(self).#vtable_offset)(::windows::Abi::abi(self), #args)
                 }
@@ -137,7 +191,7 @@ impl ComInterface {
                         quote! { #name: ::windows::RawPtr }
                     }
                     _ => {
-                        let tokens = param.gen_field();
+                        let tokens = param.gen_param();
                         quote! { #name: #tokens }
                     }
                 }
@@ -168,11 +222,29 @@ impl ComInterface {
             }
             impl<'a> ::std::convert::Into<::windows::Param<'a, ::windows::IUnknown>> for &'a #name {
                 fn into(self) -> ::windows::Param<'a, ::windows::IUnknown> {
-                    ::windows::Param::Owned(::std::convert::Into::<::windows::IUnknown>::into(::std::clone::Clone::clone(self)))
+                    ::windows::Param::Borrowed(&self.0)
                 }
             }
         });
 
+        // All COM interfaces share the same representation, so a derived interface may be
+        // borrowed as its immediate base and, through it, as any of its other bases, such as to
+        // pass it to a function taking `&IDXGIFactory` without adding a reference. The bases'
+        // methods are already generated on the derived interface, so this isn't needed to call
+        // them.
+        if let Some(base) = self.bases.first() {
+            let base = base.gen();
+
+            conversions.combine(&quote! {
+                impl ::std::ops::Deref for #name {
+                    type Target = #base;
+                    fn deref(&self) -> &Self::Target {
+                        unsafe { ::std::mem::transmute(self) }
+                    }
+                }
+            });
+        }
+
         for base in &self.bases {
             let into = base.gen();
 
@@ -194,13 +266,17 @@ impl ComInterface {
                 }
                 impl<'a> ::std::convert::Into<::windows::Param<'a, #into>> for &'a #name {
                     fn into(self) -> ::windows::Param<'a, #into> {
-                        ::windows::Param::Owned(::std::convert::Into::<#into>::into(::std::clone::Clone::clone(self)))
+                        ::windows::Param::Borrowed(unsafe { ::std::mem::transmute(self) })
                     }
                 }
             });
         }
 
+        let cast = gen_cast();
+
         quote! {
+            #doc
+            #deprecated
             #[repr(transparent)]
             #[allow(non_camel_case_types)]
             pub struct #name(::windows::IUnknown);
@@ -234,6 +310,8 @@ impl ComInterface {
             #[allow(non_snake_case)]
             impl #name {
                 #(#methods)*
+                pub const IID: ::windows::Guid = <Self as ::windows::Interface>::IID;
+                #cast
             }
             #conversions
         }
@@ -281,6 +359,7 @@ fn gen_params(method: &Method) -> TokenStream {
             .params
             .iter()
             .enumerate()
+            .skip(method.hidden_return as usize)
             .map(|(position, param)| {
                 let name = format_ident(&param.name);
 
@@ -292,7 +371,7 @@ fn gen_params(method: &Method) -> TokenStream {
                         quote! { #name: #type_tokens, }
                     }
                     _ => {
-                        let type_tokens = param.gen_field();
+                        let type_tokens = param.gen_param();
                         quote! { #name: #type_tokens, }
                     }
                 }
@@ -301,7 +380,13 @@ fn gen_params(method: &Method) -> TokenStream {
 }
 
 fn gen_abi_args(method: &Method) -> TokenStream {
-    TokenStream::from_iter(method.signature.params.iter().map(|param| {
+    let params = method
+        .signature
+        .params
+        .iter()
+        .skip(method.hidden_return as usize);
+
+    TokenStream::from_iter(params.map(|param| {
         let name = format_ident(&param.name);
 
         match &param.kind {
@@ -314,3 +399,49 @@ fn gen_abi_args(method: &Method) -> TokenStream {
         }
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_struct_return() {
+        let reader = winmd::TypeReader::get();
+        let def = reader.expect_type_def(("Windows.Win32.Direct3D12", "ID3D12DescriptorHeap"));
+        let name = TypeName::from_type_def(&def, "Windows.Win32.Direct3D12");
+        let tokens = ComInterface::from_type_name(name).gen().into_string();
+
+        // The wrapper returns the struct while the vtable takes the hidden return pointer.
+        assert!(tokens.contains(
+            "pub unsafe fn GetCPUDescriptorHandleForHeapStart < > ( & self , ) -> D3D12_CPU_DESCRIPTOR_HANDLE"
+        ));
+        assert!(tokens.contains("result__ : * mut D3D12_CPU_DESCRIPTOR_HANDLE"));
+
+        let def =
+            reader.expect_type_def(("Windows.Win32.DirectWrite", "IDWriteBitmapRenderTarget"));
+        let name = TypeName::from_type_def(&def, "Windows.Win32.DirectWrite");
+        let tokens = ComInterface::from_type_name(name).gen().into_string();
+
+        // Handles are scalars in the headers, so they're returned in a register rather than
+        // through a hidden pointer.
+        assert!(
+            tokens.contains("pub unsafe fn GetMemoryDC < > ( & self , ) -> super :: gdi :: HDC")
+        );
+        assert!(tokens.contains(
+            "pub unsafe extern \"system\" fn ( this : :: windows :: RawPtr , ) -> super :: gdi :: HDC"
+        ));
+    }
+
+    #[test]
+    fn test_docs() {
+        let reader = winmd::TypeReader::get();
+        let def = reader.expect_type_def(("Windows.Win32.Direct3D12", "ID3D12DescriptorHeap"));
+        let name = TypeName::from_type_def(&def, "Windows.Win32.Direct3D12");
+        let tokens = ComInterface::from_type_name(name).gen().into_string();
+
+        assert!(tokens.contains("\"The [`ID3D12DescriptorHeap`](https://docs.microsoft.com/search/?terms=ID3D12DescriptorHeap) interface from `Windows.Win32.Direct3D12`.\""));
+
+        // Methods are documented on the interface that declares them.
+        assert!(tokens.contains("\"The [`GetPrivateData`](https://docs.microsoft.com/search/?terms=ID3D12Object::GetPrivateData) method of `ID3D12Object`.\""));
+    }
+}
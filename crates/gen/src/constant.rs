@@ -34,12 +34,82 @@ impl Constant {
             .expect(&format!("Missing constant value: {}", name));
 
         let value = constant.value();
-
         let name = format_ident(name);
+
+        // Constants typed as an enum or a handle, like `HWND_TOPMOST`, are given that type rather
+        // than the primitive type of their value.
+        if let Some(t) = self.typed() {
+            let kind = t.gen_field();
+            let value = value.gen_literal();
+
+            return quote! {
+                pub const #name: #kind = #kind(#value as _);
+            };
+        }
+
         let value = value.gen();
 
         quote! {
             pub const #name: #value;
         }
     }
+
+    pub fn dependencies(&self) -> Vec<winmd::TypeDef> {
+        self.typed()
+            .map(|t| t.kind.dependencies())
+            .unwrap_or_default()
+    }
+
+    fn typed(&self) -> Option<Type> {
+        let t = Type::from_field(&self.field, &self.name.namespace);
+
+        if t.pointers > 0 || t.array.is_some() {
+            return None;
+        }
+
+        match &t.kind {
+            TypeKind::Enum(_) => Some(t),
+            TypeKind::Struct(name) => {
+                let def = Struct::from_type_name(name.clone());
+
+                if def.is_typedef && def.fields.len() == 1 {
+                    Some(t)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn constant((namespace, name): (&str, &str)) -> TypeDefinition {
+        let reader = winmd::TypeReader::get();
+        TypeDefinition::from_type_row(&reader.expect_type((namespace, name)))
+    }
+
+    #[test]
+    fn test_typed_constants() {
+        let t = constant(("Windows.Win32.Js", "JsRuntimeVersionEdge"));
+        assert_eq!(
+            t.gen().into_string(),
+            "pub const JsRuntimeVersionEdge : JsRuntimeVersion = JsRuntimeVersion ( -1i32 as _ ) ;"
+        );
+        assert!(t.dependencies()[0].name() == ("Windows.Win32.Js", "JsRuntimeVersion"));
+
+        let t = constant(("Windows.Win32.SystemServices", "HWND_TOPMOST"));
+        assert!(t
+            .gen()
+            .into_string()
+            .contains(":: HWND = super :: windows_and_messaging :: HWND ( -1i32 as _ ) ;"));
+
+        // Other constants keep the type of their value.
+        let t = constant(("Windows.Win32.SystemServices", "WM_KEYUP"));
+        assert_eq!(t.gen().into_string(), "pub const WM_KEYUP : i32 = 257i32 ;");
+        assert!(t.dependencies().is_empty());
+    }
 }
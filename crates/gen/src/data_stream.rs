@@ -0,0 +1,45 @@
+use crate::*;
+use squote::{quote, TokenStream};
+
+// `DataReader` and `DataWriter` implement `std::io::Read` and `std::io::Write` so that buffers
+// and streams can be parsed and produced with the standard library's adapters rather than a
+// method call per field. Reading only consumes data that has already been loaded, since loading
+// is asynchronous, and writing only fills the writer's buffer until it's stored.
+pub fn gen_data_stream(name: &TypeName) -> TokenStream {
+    if name.namespace != "Windows.Storage.Streams" {
+        return TokenStream::new();
+    }
+
+    match name.name {
+        "DataReader" => quote! {
+            impl ::std::io::Read for DataReader {
+                fn read(&mut self, buf: &mut [u8]) -> ::std::io::Result<usize> {
+                    let len = self
+                        .unconsumed_buffer_length()
+                        .map_err(|error| ::std::io::Error::new(::std::io::ErrorKind::Other, error.to_string()))?;
+                    let len = ::std::cmp::min(len as usize, buf.len());
+
+                    self.read_bytes(&mut buf[..len])
+                        .map_err(|error| ::std::io::Error::new(::std::io::ErrorKind::Other, error.to_string()))?;
+
+                    ::std::result::Result::Ok(len)
+                }
+            }
+        },
+        "DataWriter" => quote! {
+            impl ::std::io::Write for DataWriter {
+                fn write(&mut self, buf: &[u8]) -> ::std::io::Result<usize> {
+                    self.write_bytes(buf)
+                        .map_err(|error| ::std::io::Error::new(::std::io::ErrorKind::Other, error.to_string()))?;
+
+                    ::std::result::Result::Ok(buf.len())
+                }
+
+                fn flush(&mut self) -> ::std::io::Result<()> {
+                    ::std::result::Result::Ok(())
+                }
+            }
+        },
+        _ => TokenStream::new(),
+    }
+}
@@ -0,0 +1,42 @@
+use crate::*;
+use squote::{quote, TokenStream};
+
+// Event args that let a handler defer the completion of an event, such as suspension, have a
+// `GetDeferral` method. The `with_deferral` helper takes the deferral up front and completes it
+// once the handler's future is done, whether or not it succeeds, so that the work isn't cut
+// short by the event source moving on.
+pub fn gen_deferral(interfaces: &[RequiredInterface]) -> TokenStream {
+    let has_deferral = interfaces
+        .iter()
+        .filter(|interface| {
+            interface.kind != InterfaceKind::Statics && interface.kind != InterfaceKind::Composable
+        })
+        .flat_map(|interface| &interface.methods)
+        .any(|method| {
+            method.name == "get_deferral"
+                && method.signature.params.is_empty()
+                && matches!(
+                    method.signature.return_type.as_ref().map(|t| &t.kind),
+                    Some(TypeKind::Class(_)) | Some(TypeKind::Interface(_))
+                )
+        });
+
+    if !has_deferral {
+        return TokenStream::new();
+    }
+
+    quote! {
+        pub fn with_deferral<F: ::std::future::Future<Output = ::windows::Result<()>>>(
+            &self,
+            future: F,
+        ) -> ::windows::Result<impl ::std::future::Future<Output = ::windows::Result<()>>> {
+            let deferral = self.get_deferral()?;
+
+            Ok(async move {
+                let result = future.await;
+                let completed = deferral.complete();
+                result.and(completed)
+            })
+        }
+    }
+}
@@ -30,6 +30,8 @@ impl Delegate {
         let fn_constraint = self.gen_fn_constraint();
         let box_definition = self.gen_box_definition(&fn_constraint);
         let name = self.name.gen();
+        let doc = self.name.gen_doc("delegate");
+        let deprecated = gen_deprecated(self.name.def.attributes());
         let vtable_name = self.name.gen_abi();
         let box_name = self.gen_box_name();
         let phantoms = self.name.phantoms();
@@ -49,6 +51,8 @@ impl Delegate {
         let invoke_upcall = self.method.gen_upcall(quote! { ((*this).invoke) }, true);
 
         quote! {
+            #doc
+            #deprecated
             #[repr(transparent)]
             pub struct #definition(::windows::IUnknown, #phantoms) where #constraints;
             impl<#constraints> ::std::clone::Clone for #name {
@@ -86,6 +90,7 @@ impl Delegate {
             ) where #constraints;
             impl<#constraints> #name {
                 #method
+                pub const IID: ::windows::Guid = <Self as ::windows::Interface>::IID;
                 pub fn new<#fn_constraint>(invoke: F) -> Self {
                     let com = #box_name {
                         vtable: &#box_name::VTABLE,
@@ -202,11 +207,11 @@ fn param_gen_fn(t: &Type) -> TokenStream {
 
     if t.is_array {
         if t.is_input {
-            quote! { &[#tokens], }
+            quote! { &[<#tokens as ::windows::RuntimeType>::DefaultType], }
         } else if t.by_ref {
             quote! { &mut ::windows::Array<#tokens>, }
         } else {
-            quote! { &mut [#tokens], }
+            quote! { &mut [<#tokens as ::windows::RuntimeType>::DefaultType], }
         }
     } else if t.is_input {
         match t.kind {
@@ -0,0 +1,63 @@
+use crate::*;
+use squote::{quote, TokenStream};
+
+/// Generates a `#[deprecated]` attribute, with the message from the metadata as its note, for an
+/// API marked as deprecated so that callers are warned when they use it.
+///
+/// WinRT metadata marks deprecated APIs with `DeprecatedAttribute`, whose first argument is the
+/// message, while other metadata may use .NET's `ObsoleteAttribute` in the same way.
+pub fn gen_deprecated(mut attributes: impl Iterator<Item = winmd::Attribute>) -> TokenStream {
+    let attribute = match attributes.find(|attribute| {
+        matches!(
+            attribute.name(),
+            ("Windows.Foundation.Metadata", "DeprecatedAttribute")
+                | ("System", "ObsoleteAttribute")
+        )
+    }) {
+        Some(attribute) => attribute,
+        None => return TokenStream::new(),
+    };
+
+    let note = attribute.args().into_iter().find_map(|(_, arg)| match arg {
+        winmd::AttributeArg::String(note) => Some(note),
+        _ => None,
+    });
+
+    match note {
+        Some(note) => quote! { #[deprecated(note = #note)] },
+        None => quote! { #[deprecated] },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn class((namespace, name): (&str, &str)) -> String {
+        let reader = winmd::TypeReader::get();
+        let def = reader.expect_type_def((namespace, name));
+        TypeDefinition::from_type_def(&def).gen().into_string()
+    }
+
+    #[test]
+    fn test_deprecated_type() {
+        let tokens = class((
+            "Windows.ApplicationModel.Activation",
+            "FileOpenPickerContinuationEventArgs",
+        ));
+        assert!(tokens.contains("# [ deprecated ( note =\"FileOpenPickerContinuationEventArgs is deprecated and might not work on all platforms. For more info, see MSDN.\" ) ] # [ repr ( transparent ) ] pub struct FileOpenPickerContinuationEventArgs"));
+    }
+
+    #[test]
+    fn test_deprecated_method() {
+        let tokens = class(("Windows.ApplicationModel.DataTransfer", "DataPackage"));
+        assert!(
+            tokens.contains("Instead, use SetWebLink or SetApplicationLink.\" ) ] pub fn set_uri")
+        );
+
+        // The class itself isn't deprecated.
+        assert!(tokens.contains(
+            "from `Windows.ApplicationModel.DataTransfer`.\" ] # [ repr ( transparent ) ] pub struct DataPackage"
+        ));
+    }
+}
@@ -0,0 +1,14 @@
+use squote::{quote, TokenStream};
+
+/// Generates a `#[doc]` attribute so that the text is shown by rustdoc and editor hovers.
+pub fn gen_doc(text: &str) -> TokenStream {
+    quote! { #[doc = #text] }
+}
+
+/// Returns the URL of a docs.microsoft.com search for an API.
+///
+/// Win32 API pages are named after the header that declares the API, which the metadata doesn't
+/// record, so they're linked to through a search for the API's name instead.
+pub fn search_doc_url(terms: &str) -> String {
+    format!("https://docs.microsoft.com/search/?terms={}", terms)
+}
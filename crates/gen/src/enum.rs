@@ -1,5 +1,5 @@
 use crate::*;
-use squote::{format_ident, quote, Literal, TokenStream};
+use squote::{format_ident, quote, Ident, Literal, TokenStream};
 
 #[derive(Debug)]
 pub struct Enum {
@@ -22,6 +22,10 @@ impl EnumConstant {
             Self::I32(value) => Self::I32(value + 1),
         }
     }
+
+    fn is_zero(&self) -> bool {
+        matches!(self, Self::U32(0) | Self::I32(0))
+    }
 }
 
 impl Enum {
@@ -69,31 +73,78 @@ impl Enum {
         }
     }
 
+    /// Returns whether the enum's values are flags that may be combined, as marked in metadata
+    /// or implied by an unsigned underlying type, which WinRT requires of flags enums.
+    pub fn is_flags(&self) -> bool {
+        matches!(self.underlying_type, winmd::ElementType::U32)
+            || self.name.def.has_attribute(("System", "FlagsAttribute"))
+    }
+
     pub fn gen(&self) -> TokenStream {
         let name = self.name.gen();
+        let doc = self.name.gen_doc("enum");
+        let deprecated = gen_deprecated(self.name.def.attributes());
 
-        let (underlying_type, bitwise) = match self.underlying_type {
-            winmd::ElementType::I32 => (format_ident!("i32"), TokenStream::new()),
-            winmd::ElementType::U32 => (
-                format_ident!("u32"),
-                quote! {
-                    impl ::std::ops::BitOr for #name {
-                        type Output = Self;
-
-                        fn bitor(self, rhs: Self) -> Self {
-                            Self(self.0 | rhs.0)
-                        }
+        let underlying_type = match self.underlying_type {
+            winmd::ElementType::I32 => format_ident!("i32"),
+            winmd::ElementType::U32 => format_ident!("u32"),
+            _ => panic!("Unexpected enum underlying type: {}", name),
+        };
+
+        let bitwise = if self.is_flags() {
+            quote! {
+                impl #name {
+                    /// Returns whether all of the flags in `other` are set.
+                    pub const fn contains(&self, other: Self) -> bool {
+                        self.0 & other.0 == other.0
                     }
-                    impl ::std::ops::BitAnd for #name {
-                        type Output = Self;
+                }
+                impl ::std::ops::BitOr for #name {
+                    type Output = Self;
 
-                        fn bitand(self, rhs: Self) -> Self {
-                            Self(self.0 & rhs.0)
-                        }
+                    fn bitor(self, rhs: Self) -> Self {
+                        Self(self.0 | rhs.0)
                     }
-                },
-            ),
-            _ => panic!("Unexpected enum underlying type: {}", name),
+                }
+                impl ::std::ops::BitAnd for #name {
+                    type Output = Self;
+
+                    fn bitand(self, rhs: Self) -> Self {
+                        Self(self.0 & rhs.0)
+                    }
+                }
+                impl ::std::ops::BitXor for #name {
+                    type Output = Self;
+
+                    fn bitxor(self, rhs: Self) -> Self {
+                        Self(self.0 ^ rhs.0)
+                    }
+                }
+                impl ::std::ops::Not for #name {
+                    type Output = Self;
+
+                    fn not(self) -> Self {
+                        Self(!self.0)
+                    }
+                }
+                impl ::std::ops::BitOrAssign for #name {
+                    fn bitor_assign(&mut self, rhs: Self) {
+                        self.0 |= rhs.0
+                    }
+                }
+                impl ::std::ops::BitAndAssign for #name {
+                    fn bitand_assign(&mut self, rhs: Self) {
+                        self.0 &= rhs.0
+                    }
+                }
+                impl ::std::ops::BitXorAssign for #name {
+                    fn bitxor_assign(&mut self, rhs: Self) {
+                        self.0 ^= rhs.0
+                    }
+                }
+            }
+        } else {
+            TokenStream::new()
         };
 
         let fields = self.fields.iter().map(|(name, value)| {
@@ -108,6 +159,38 @@ impl Enum {
             }
         });
 
+        // Defaults to zero if it's a declared value or the enum holds flags, where it means no
+        // flags are set, and otherwise to the first declared value so that it's always valid.
+        let default = match self.fields.first() {
+            Some((_, first))
+                if !self.is_flags() && !self.fields.iter().any(|(_, value)| value.is_zero()) =>
+            {
+                match first {
+                    EnumConstant::U32(value) => quote! { #value },
+                    EnumConstant::I32(value) => quote! { #value },
+                }
+            }
+            _ => quote! { 0 },
+        };
+
+        let mut values: Vec<_> = self.fields.iter().map(|(_, value)| *value).collect();
+        values.sort();
+        values.dedup();
+
+        let values = values.iter().map(|value| match value {
+            EnumConstant::U32(value) => quote! { #value },
+            EnumConstant::I32(value) => quote! { #value },
+        });
+
+        // Flags may be combined, so any combination of the defined flags is itself defined.
+        let is_defined = if self.fields.is_empty() {
+            quote! { false }
+        } else if self.is_flags() {
+            quote! { self.0 & !(#(#values)|*) == 0 }
+        } else {
+            quote! { matches!(self.0, #(#values)|*) }
+        };
+
         let runtime_type = if self.signature.is_empty() {
             TokenStream::new()
         } else {
@@ -121,9 +204,42 @@ impl Enum {
             }
         };
 
+        let rust_enum = self.gen_rust_enum(&underlying_type);
+
+        // Values are named where they're declared, which relies on the Rust enum to match them.
+        let debug = if !self.has_rust_enum() {
+            quote! { write!(f, "{:?}", self.0) }
+        } else {
+            let rust_name = self.gen_rust_name();
+            quote! {
+                match <#rust_name as ::std::convert::TryFrom<Self>>::try_from(*self) {
+                    ::std::result::Result::Ok(value) => write!(f, "{:?}", value),
+                    ::std::result::Result::Err(_) => write!(f, "{:?}", self.0),
+                }
+            }
+        };
+
+        let serde = if GenOptions::get().serde {
+            quote! { #[derive(::serde::Serialize, ::serde::Deserialize)] }
+        } else {
+            quote! {}
+        };
+
+        let bytemuck = if GenOptions::get().bytemuck {
+            quote! {
+                unsafe impl ::bytemuck::Zeroable for #name {}
+                unsafe impl ::bytemuck::Pod for #name {}
+            }
+        } else {
+            quote! {}
+        };
+
         quote! {
+            #doc
+            #deprecated
             #[allow(non_camel_case_types)]
-            #[derive(PartialEq, Eq)]
+            #[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+            #serde
             #[repr(transparent)]
             pub struct #name(pub #underlying_type);
             impl ::std::convert::From<#underlying_type> for #name {
@@ -131,6 +247,11 @@ impl Enum {
                     Self(value)
                 }
             }
+            impl ::std::convert::From<#name> for #underlying_type {
+                fn from(value: #name) -> Self {
+                    value.0
+                }
+            }
             impl ::std::clone::Clone for #name {
                 fn clone(&self) -> Self {
                     Self(self.0)
@@ -138,24 +259,202 @@ impl Enum {
             }
             impl ::std::default::Default for #name {
                 fn default() -> Self {
-                    Self(0)
+                    Self(#default)
                 }
             }
             impl ::std::fmt::Debug for #name {
                 fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-                    write!(f, "{:?}", self.0)
+                    #debug
                 }
             }
             impl ::std::marker::Copy for #name {}
+            #bytemuck
             impl #name {
                 #![allow(non_upper_case_globals)]
                 #(#fields)*
+
+                /// Returns whether the value is one defined by the enum, as values read from the
+                /// OS or the wire may not be.
+                pub const fn is_defined(&self) -> bool {
+                    #is_defined
+                }
             }
             unsafe impl ::windows::Abi for #name {
                 type Abi = Self;
             }
             #runtime_type
             #bitwise
+            #rust_enum
         }
     }
+
+    fn has_rust_enum(&self) -> bool {
+        GenOptions::get().enums && !self.is_flags() && !self.fields.is_empty()
+    }
+
+    fn gen_rust_name(&self) -> Ident {
+        format_ident!("{}Enum", self.name.name)
+    }
+
+    // With the `enums` option, enums that don't hold flags also get a Rust enum whose values can
+    // be matched exhaustively. Values shared by more than one name become variants named for the
+    // first, with the others as aliases, since a Rust enum can't repeat a discriminant.
+    fn gen_rust_enum(&self, underlying_type: &Ident) -> TokenStream {
+        if !self.has_rust_enum() {
+            return TokenStream::new();
+        }
+
+        let name = self.name.gen();
+        let rust_name = self.gen_rust_name();
+        let mut variants = Vec::new();
+        let mut aliases = Vec::new();
+        let mut arms = Vec::new();
+
+        for (index, (field, value)) in self.fields.iter().enumerate() {
+            let field = format_ident(field);
+            let value = match value {
+                EnumConstant::U32(value) => quote! { #value },
+                EnumConstant::I32(value) => quote! { #value },
+            };
+
+            match self.fields[..index]
+                .iter()
+                .find(|(_, previous)| *previous == self.fields[index].1)
+            {
+                Some((first, _)) => {
+                    let first = format_ident(first);
+                    aliases.push(quote! { pub const #field: Self = Self::#first; });
+                }
+                None => {
+                    variants.push(quote! { #field = #value, });
+                    arms.push(quote! { #value => ::std::result::Result::Ok(Self::#field), });
+                }
+            }
+        }
+
+        let aliases = if aliases.is_empty() {
+            TokenStream::new()
+        } else {
+            quote! {
+                impl #rust_name {
+                    #![allow(non_upper_case_globals)]
+                    #(#aliases)*
+                }
+            }
+        };
+
+        let doc = format!(
+            "The values of [`{}`] as a Rust enum, which converts from it with `TryFrom`.",
+            self.name.name
+        );
+
+        quote! {
+            #[doc = #doc]
+            #[allow(non_camel_case_types)]
+            #[derive(::std::clone::Clone, ::std::marker::Copy, ::std::fmt::Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            #[repr(#underlying_type)]
+            #[non_exhaustive]
+            pub enum #rust_name {
+                #(#variants)*
+            }
+            #aliases
+            impl ::std::convert::TryFrom<#name> for #rust_name {
+                type Error = #name;
+                fn try_from(value: #name) -> ::std::result::Result<Self, #name> {
+                    match value.0 {
+                        #(#arms)*
+                        _ => ::std::result::Result::Err(value),
+                    }
+                }
+            }
+            impl ::std::convert::From<#rust_name> for #name {
+                fn from(value: #rust_name) -> Self {
+                    Self(value as #underlying_type)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn enum_tokens((namespace, type_name): (&str, &str)) -> String {
+        let reader = &winmd::TypeReader::get();
+        let def = reader.expect_type_def((namespace, type_name));
+
+        match TypeDefinition::from_type_def(&def) {
+            TypeDefinition::Enum(t) => t.gen().into_string(),
+            _ => panic!("TypeDefinition not an enum"),
+        }
+    }
+
+    #[test]
+    fn test_flags() {
+        let tokens = enum_tokens((
+            "Windows.ApplicationModel.Appointments",
+            "AppointmentDaysOfWeek",
+        ));
+        assert!(tokens.contains("pub const fn contains ( & self , other : Self ) -> bool"));
+        assert!(tokens.contains("impl :: std :: ops :: Not for AppointmentDaysOfWeek"));
+        assert!(tokens.contains("impl :: std :: ops :: BitXorAssign for AppointmentDaysOfWeek"));
+
+        let tokens = enum_tokens(("Windows.Foundation", "AsyncStatus"));
+        assert!(!tokens.contains("BitOr"));
+    }
+
+    #[test]
+    fn test_default() {
+        let tokens = enum_tokens(("Windows.Foundation", "AsyncStatus"));
+        assert!(tokens.contains("fn default ( ) -> Self { Self ( 0 ) }"));
+
+        // `TimedTextWeight` starts at `Normal`, which is 400.
+        let tokens = enum_tokens(("Windows.Media.Core", "TimedTextWeight"));
+        assert!(tokens.contains("fn default ( ) -> Self { Self ( 400i32 ) }"));
+    }
+
+    #[test]
+    fn test_is_defined() {
+        let tokens = enum_tokens(("Windows.Foundation", "AsyncStatus"));
+        assert!(tokens.contains("matches ! ( self . 0 , 0i32 | 1i32 | 2i32 | 3i32 )"));
+        assert!(tokens.contains("impl :: std :: convert :: From < AsyncStatus > for i32"));
+
+        let tokens = enum_tokens((
+            "Windows.ApplicationModel.Appointments",
+            "AppointmentDaysOfWeek",
+        ));
+        assert!(tokens.contains(
+            "self . 0 & ! ( 0u32 | 1u32 | 2u32 | 4u32 | 8u32 | 16u32 | 32u32 | 64u32 ) == 0"
+        ));
+    }
+
+    #[test]
+    fn test_rust_enums() {
+        let tokens = enum_tokens(("Windows.UI.Xaml", "TextAlignment"));
+        assert!(!tokens.contains("TextAlignmentEnum"));
+
+        GenOptions {
+            enums: true,
+            ..GenOptions::default()
+        }
+        .set();
+
+        // `Start` and `End` share the values of `Left` and `Right`.
+        let tokens = enum_tokens(("Windows.UI.Xaml", "TextAlignment"));
+        assert!(tokens.contains("# [ repr ( i32 ) ] # [ non_exhaustive ] pub enum TextAlignmentEnum { Center = 0i32 , Left = 1i32 , Right = 2i32 , Justify = 3i32 , DetectFromContent = 4i32 , }"));
+        assert!(tokens.contains("pub const Start : Self = Self :: Left ;"));
+        assert!(tokens.contains("1i32 => :: std :: result :: Result :: Ok ( Self :: Left ) ,"));
+        assert!(tokens.contains("_ => :: std :: result :: Result :: Err ( value ) ,"));
+        assert!(tokens.contains("Self ( value as i32 )"));
+
+        // Flags may be combined, so they have no Rust enum.
+        let tokens = enum_tokens((
+            "Windows.ApplicationModel.Appointments",
+            "AppointmentDaysOfWeek",
+        ));
+        assert!(!tokens.contains("AppointmentDaysOfWeekEnum"));
+
+        GenOptions::default().set();
+    }
 }
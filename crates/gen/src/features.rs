@@ -0,0 +1,112 @@
+use crate::*;
+use std::collections::BTreeSet;
+
+/// Returns the name of the cargo feature that gates a namespace's module when
+/// [`GenOptions::features`] is set, such as `Win32_Direct3D11` for `Windows.Win32.Direct3D11`.
+///
+/// Top-level namespaces like `Windows` aren't gated and have no feature.
+pub fn feature_name(namespace: &str) -> Option<String> {
+    if !namespace.contains('.') {
+        return None;
+    }
+
+    let namespace = namespace.strip_prefix("Windows.").unwrap_or(namespace);
+    Some(namespace.replace('.', "_"))
+}
+
+/// Lists the cargo features that gate the namespace modules for the given limits, one per line
+/// and ready to be pasted into the `[features]` section of a `Cargo.toml` file.
+///
+/// Each feature enables the features of its parent namespace and of the namespaces its types
+/// depend on, so that enabling one is enough for its module to compile.
+pub fn gen_features(
+    reader: &'static winmd::TypeReader,
+    limits: &TypeLimits,
+    foundation: bool,
+) -> String {
+    let tree = bindings_tree(reader, limits, foundation);
+    let mut features = String::new();
+    gen_tree_features(&tree, &tree, "", &mut features);
+    features
+}
+
+fn gen_tree_features(root: &TypeTree, tree: &TypeTree, parent: &str, features: &mut String) {
+    for (name, tree) in &tree.namespaces.0 {
+        let namespace = if parent.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", parent, name)
+        };
+
+        if let Some(feature) = feature_name(&namespace) {
+            let mut dependencies: BTreeSet<String> = tree
+                .types
+                .iter()
+                .flat_map(|t| t.dependencies())
+                .map(|def| def.name().0)
+                .filter(|dependency| *dependency != namespace && root.get(dependency).is_some())
+                .filter_map(feature_name)
+                .collect();
+
+            dependencies.extend(feature_name(parent));
+
+            let dependencies: Vec<String> = dependencies
+                .iter()
+                .map(|dependency| format!("\"{}\"", dependency))
+                .collect();
+
+            features.push_str(&format!("{} = [{}]\n", feature, dependencies.join(", ")));
+        }
+
+        gen_tree_features(root, tree, &namespace, features);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_feature_name() {
+        assert_eq!(feature_name("Windows"), None);
+        assert_eq!(feature_name("Windows.UI.Xaml").as_deref(), Some("UI_Xaml"));
+        assert_eq!(
+            feature_name("Windows.Win32.Direct3D11").as_deref(),
+            Some("Win32_Direct3D11")
+        );
+        assert_eq!(
+            feature_name("Microsoft.AI.MachineLearning").as_deref(),
+            Some("Microsoft_AI_MachineLearning")
+        );
+    }
+
+    #[test]
+    fn test_features() {
+        let reader = winmd::TypeReader::get();
+        let mut limits = TypeLimits::new(reader);
+        limits
+            .insert(NamespaceTypes {
+                namespace: "Windows.Win32.Dxgi",
+                limit: TypeLimit::Some(vec!["IDXGIFactory".to_string()]),
+            })
+            .unwrap();
+
+        // `IDXGIFactory::MakeWindowAssociation` takes an `HWND`.
+        let features = gen_features(reader, &limits, false);
+        assert!(features.contains("Win32 = []\n"));
+        assert!(features.contains("Win32_WindowsAndMessaging = [\"Win32\"]\n"));
+        assert!(features.contains("Win32_Dxgi = [\"Win32\", \"Win32_DisplayDevices\", \"Win32_Kernel\", \"Win32_WindowsAndMessaging\"]\n"));
+
+        GenOptions {
+            features: true,
+            ..GenOptions::default()
+        }
+        .set();
+
+        let tokens = gen_bindings(reader, &limits, false).into_string();
+        assert!(tokens.contains("# [ cfg ( feature =\"Win32_Dxgi\" ) ]"));
+        assert!(!tokens.contains("feature =\"Windows\""));
+
+        GenOptions::default().set();
+    }
+}
@@ -0,0 +1,168 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Formats generated code so that it may be reviewed and diffed across regenerations.
+///
+/// The code is formatted with `rustfmt` if it is installed, and otherwise with [`pretty_print`],
+/// which is cruder but still places each item and statement on its own line.
+pub fn format_code(source: &str) -> String {
+    rustfmt(source).unwrap_or_else(|| pretty_print(source))
+}
+
+fn rustfmt(source: &str) -> Option<String> {
+    let mut rustfmt = Command::new("rustfmt")
+        .args(["--edition", "2018", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+
+    // rustfmt reads all of its input before writing any output, so writing it up front won't
+    // fill the output pipe and deadlock.
+    rustfmt.stdin.take()?.write_all(source.as_bytes()).ok()?;
+
+    let output = rustfmt.wait_with_output().ok()?;
+
+    if output.status.success() {
+        String::from_utf8(output.stdout).ok()
+    } else {
+        None
+    }
+}
+
+/// Breaks the token stream produced by the generator into indented lines, starting a new line
+/// after each `;`, `{`, and `}` and after each attribute.
+pub fn pretty_print(source: &str) -> String {
+    let mut result = String::with_capacity(source.len() + source.len() / 4);
+    let mut depth = 0usize;
+    let mut brackets = 0usize;
+    let mut chars = source.chars().peekable();
+
+    fn new_line(result: &mut String, depth: usize) {
+        while result.ends_with(' ') {
+            result.pop();
+        }
+
+        result.push('\n');
+        result.push_str(&"    ".repeat(depth));
+    }
+
+    while let Some(c) = chars.next() {
+        match c {
+            ' ' if result.ends_with('\n') || result.ends_with(' ') => continue,
+            '"' => {
+                result.push(c);
+
+                while let Some(c) = chars.next() {
+                    result.push(c);
+
+                    match c {
+                        '\\' => result.extend(chars.next()),
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '[' | '(' => {
+                brackets += 1;
+                result.push(c);
+            }
+            ']' | ')' => {
+                brackets = brackets.saturating_sub(1);
+                result.push(c);
+
+                // An attribute ends at the bracket closing it.
+                if c == ']' && brackets == 0 && is_attribute(&result) {
+                    new_line(&mut result, depth);
+                }
+            }
+            '{' => {
+                depth += 1;
+                result.push(c);
+                new_line(&mut result, depth);
+            }
+            '}' => {
+                depth = depth.saturating_sub(1);
+                new_line(&mut result, depth);
+                result.push(c);
+
+                // Punctuation following a block, as in `Self { x: 1 },`, stays on its line.
+                let next = chars.clone().find(|c| *c != ' ');
+
+                if !matches!(
+                    next,
+                    Some(',') | Some(';') | Some(')') | Some('.') | Some('?')
+                ) {
+                    new_line(&mut result, depth);
+                }
+            }
+            ';' if brackets == 0 => {
+                result.push(c);
+                new_line(&mut result, depth);
+            }
+            _ => result.push(c),
+        }
+    }
+
+    let mut lines: Vec<&str> = result.lines().map(|line| line.trim_end()).collect();
+    lines.retain(|line| !line.is_empty());
+
+    let mut result = lines.join("\n");
+    result.push('\n');
+    result
+}
+
+// Returns whether the text ends with an attribute, such as `#[repr(C)]` or `#![allow(x)]`.
+fn is_attribute(text: &str) -> bool {
+    let mut depth = 0usize;
+
+    for (position, c) in text.char_indices().rev() {
+        match c {
+            ']' => depth += 1,
+            '[' => {
+                depth -= 1;
+
+                if depth == 0 {
+                    let before = text[..position].trim_end();
+                    return before.ends_with('#') || before.ends_with("#!");
+                }
+            }
+            _ => {}
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pretty_print() {
+        let source = r#"pub mod windows { # [repr (C)] pub struct Point { pub x : f32 , } impl Point { pub fn new () -> Self { Self { x : 0.0 } } pub fn name () -> & 'static str { "{;}" } } pub const ARRAY : [u8 ; 2] = [1 , 2] ; }"#;
+
+        assert_eq!(
+            pretty_print(source),
+            r#"pub mod windows {
+    # [repr (C)]
+    pub struct Point {
+        pub x : f32 ,
+    }
+    impl Point {
+        pub fn new () -> Self {
+            Self {
+                x : 0.0
+            }
+        }
+        pub fn name () -> & 'static str {
+            "{;}"
+        }
+    }
+    pub const ARRAY : [u8 ; 2] = [1 , 2] ;
+}
+"#
+        );
+    }
+}
@@ -15,12 +15,22 @@ impl Function {
     }
 
     pub fn gen(&self) -> TokenStream {
+        let function = self.gen_function();
+        let result = self.gen_result();
+
+        quote! {
+            #function
+            #result
+        }
+    }
+
+    fn gen_function(&self) -> TokenStream {
         let name = self.signature.method.name();
         let name = format_ident(name);
 
         let params = self.signature.params.iter().map(|t| {
             let name = format_ident(&t.name);
-            let tokens = t.gen_field();
+            let tokens = t.gen_param();
             quote! { #name: #tokens }
         });
 
@@ -31,22 +41,847 @@ impl Function {
             TokenStream::new()
         };
 
-        let mut link = self.signature.method.impl_map().unwrap().scope().name();
+        let options = GenOptions::get();
+        let reserved = !options.keep_reserved && self.signature.params.iter().any(is_reserved);
+        let library = self.signature.method.impl_map().unwrap().scope().name();
+        let doc = self.gen_doc(library);
+        let deprecated = gen_deprecated(self.signature.method.attributes());
 
-        // TODO: workaround for https://github.com/microsoft/windows-rs/issues/463
-        if link.contains("-ms-win-") || link == "D3DCOMPILER_47" {
-            link = "onecoreuap";
+        // Variadic functions are always imported, since a Rust function can't pass on variadic
+        // arguments, and use the C calling convention as all variadic functions do.
+        if self.is_variadic() {
+            let link = self.link();
+
+            return quote! {
+                #[link(name = #link)]
+                extern "C" {
+                    #doc
+                    #deprecated
+                    pub fn #name(#(#params,)* ...) #return_type;
+                }
+            };
+        }
+
+        if self.is_delay_loaded() {
+            let delay_load = self.gen_delay_load(library);
+            return quote! { #doc #deprecated #delay_load };
+        }
+
+        let link = self.link();
+
+        // Interfaces returned through a `ComOutPtr` parameter are returned directly, with the
+        // interface requested by `riid` picked by the caller's generic type.
+        if self.com_out().is_some() {
+            let generics = self.generics();
+            let public_params = self.public_params();
+            let args = self.args();
+
+            return quote! {
+                #doc
+                #deprecated
+                pub unsafe fn #name #generics(#(#public_params),*) -> ::windows::Result<T> {
+                    #[link(name = #link)]
+                    extern "system" {
+                        fn #name(#(#params),*) #return_type;
+                    }
+
+                    let mut result: ::std::option::Option<T> = ::std::option::Option::None;
+                    #name(#(#args),*).and_some(result)
+                }
+            };
+        }
+
+        // Reserved parameters are left out of a function that wraps the import, which also takes
+        // arrays as slices, optional pointers as options, and strings as anything that converts to
+        // a `StringParam`.
+        if reserved
+            || !self.arrays().is_empty()
+            || !self.optionals().is_empty()
+            || !self.strings().is_empty()
+        {
+            let generics = self.generics();
+            let public_params = self.public_params();
+            let args = self.args();
+
+            return quote! {
+                #doc
+                #deprecated
+                pub unsafe fn #name #generics(#(#public_params),*) #return_type {
+                    #[link(name = #link)]
+                    extern "system" {
+                        fn #name(#(#params),*) #return_type;
+                    }
+
+                    #name(#(#args),*)
+                }
+            };
         }
 
         quote! {
             #[link(name = #link)]
             extern "system" {
+                #doc
+                #deprecated
                 pub fn #name(#(#params),*) #return_type;
             }
         }
     }
 
+    fn is_delay_loaded(&self) -> bool {
+        GenOptions::get()
+            .delay_load
+            .iter()
+            .any(|function| function == self.signature.method.name())
+    }
+
+    // Functions returning a `BOOL`, an `HRESULT`, or an `NTSTATUS` also get a `try_` function that
+    // calls them and returns a `Result`, with the error from `GetLastError` if a `BOOL` is false or
+    // with the failing `HRESULT` or `NTSTATUS`, so that they can be called with `?`. Functions that already return a
+    // `Result`, such as those that are delay loaded, are left alone.
+    fn gen_result(&self) -> TokenStream {
+        match &self.signature.return_type {
+            Some(t)
+                if matches!(
+                    t.kind,
+                    TypeKind::Bool32 | TypeKind::ErrorCode | TypeKind::NtStatus
+                ) && t.pointers == 0 => {}
+            _ => return TokenStream::new(),
+        }
+
+        if self.com_out().is_some() || self.is_variadic() || self.is_delay_loaded() {
+            return TokenStream::new();
+        }
+
+        let name = self.signature.method.name();
+        let doc = gen_doc(&format!(
+            "Calls [`{}`], returning an error if it fails.",
+            name
+        ));
+        let deprecated = gen_deprecated(self.signature.method.attributes());
+        let try_name = format_ident(&format!("try_{}", name));
+        let name = format_ident(name);
+
+        let generics = self.generics();
+        let public_params = self.public_params();
+        let args = self
+            .public_indexes()
+            .into_iter()
+            .map(|index| format_ident(&self.signature.params[index].name));
+
+        quote! {
+            #doc
+            #deprecated
+            pub unsafe fn #try_name #generics(#(#public_params),*) -> ::windows::Result<()> {
+                #name(#(#args),*).ok()
+            }
+        }
+    }
+
+    /// Returns the name of the library to link against for the function's import.
+    pub fn link(&self) -> String {
+        if let Some(umbrella) = &GenOptions::get().link {
+            return umbrella.clone();
+        }
+
+        let link = self.signature.method.impl_map().unwrap().scope().name();
+
+        // TODO: workaround for https://github.com/microsoft/windows-rs/issues/463
+        if link.contains("-ms-win-") || link == "D3DCOMPILER_47" {
+            "onecoreuap".to_string()
+        } else {
+            link.to_string()
+        }
+    }
+
+    /// Returns whether the function takes variadic arguments after its fixed parameters, either
+    /// because its signature says so or because it's named by [`GenOptions::variadic`].
+    pub fn is_variadic(&self) -> bool {
+        let mut blob = self.signature.method.sig();
+
+        blob.read_unsigned() & CALLING_CONVENTION == VARARG
+            || GenOptions::get()
+                .variadic
+                .iter()
+                .any(|function| function == self.signature.method.name())
+    }
+
+    fn gen_doc(&self, library: &str) -> TokenStream {
+        let name = self.signature.method.name();
+
+        let mut doc = format!(
+            "The [`{}`]({}) function exported by `{}`.",
+            name,
+            search_doc_url(name),
+            library
+        );
+
+        if self.is_variadic() {
+            doc.push_str(
+                "\n\nThis function is variadic. Arguments after the fixed parameters are passed \
+                 as is, with no conversion or checking, so each must be the C type the function \
+                 expects, such as an `i32`, an `f64`, or a pointer to a null-terminated string.",
+            );
+        }
+
+        gen_doc(&doc)
+    }
+
+    // The index of the `riid` parameter and the `ComOutPtr` parameter it describes, for
+    // functions returning an `HRESULT` that can return the interface directly.
+    fn com_out(&self) -> Option<(usize, usize)> {
+        match &self.signature.return_type {
+            Some(t) if t.kind == TypeKind::ErrorCode => {}
+            _ => return None,
+        }
+
+        let params = &self.signature.params;
+
+        let out = params.iter().position(|t| {
+            t.kind == TypeKind::Void
+                && t.pointers == 2
+                && matches!(t.param, Some(param) if param.has_attribute(COM_OUT_PTR))
+        })?;
+
+        let riid = params
+            .iter()
+            .position(|t| t.name == "riid" && t.kind == TypeKind::Guid && t.pointers == 1)?;
+
+        Some((riid, out))
+    }
+
+    // The parameters the metadata describes as arrays whose length is passed in another
+    // parameter, as `(array, length)` indexes, for a wrapper function to take as slices unless
+    // raw arrays are to be kept. Lengths shared by several arrays are left alone, since the
+    // arrays needn't be the same length, and so are lengths passed by pointer, which the callee
+    // may update. Untyped buffers are only taken as slices of bytes if their length is known to
+    // count bytes, since many APIs, like `WriteConsoleW`, count the characters they point to.
+    fn arrays(&self) -> Vec<(usize, usize)> {
+        if GenOptions::get().raw_arrays {
+            return Vec::new();
+        }
+
+        let params = &self.signature.params;
+        let com_out: Vec<usize> = self
+            .com_out()
+            .map(|(riid, out)| vec![riid, out])
+            .unwrap_or_default();
+
+        let arrays: Vec<(usize, usize)> = params
+            .iter()
+            .enumerate()
+            .filter_map(|(index, t)| {
+                let len = size_param(t)?;
+                let len_type = params.get(len)?;
+
+                if t.pointers == 1
+                    && (t.kind != TypeKind::Void || is_byte_count(len_type))
+                    && len != index
+                    && len_type.pointers == 0
+                    && len_type.kind.is_integer()
+                    && !com_out.contains(&index)
+                    && !com_out.contains(&len)
+                {
+                    Some((index, len))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        arrays
+            .iter()
+            .filter(|(_, len)| arrays.iter().filter(|(_, other)| other == len).count() == 1)
+            .copied()
+            .collect()
+    }
+
+    // The optional parameters that point to a single value, for a wrapper function to take as
+    // an `Option` of a reference unless raw pointers are to be kept. Untyped pointers are left
+    // alone, and so are those the metadata describes further, such as arrays and strings.
+    fn optionals(&self) -> Vec<usize> {
+        if GenOptions::get().raw_pointers {
+            return Vec::new();
+        }
+
+        let com_out: Vec<usize> = self
+            .com_out()
+            .map(|(riid, out)| vec![riid, out])
+            .unwrap_or_default();
+
+        self.signature
+            .params
+            .iter()
+            .enumerate()
+            .filter(|(index, t)| {
+                let param = match t.param {
+                    Some(param) => param,
+                    None => return false,
+                };
+
+                t.pointers == 1
+                    && t.kind != TypeKind::Void
+                    && param.flags().optional()
+                    && !param.has_attribute(NATIVE_TYPE_INFO)
+                    && !com_out.contains(index)
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    // The null-terminated string parameters that are only read, for a wrapper function to take
+    // as anything that converts to a `StringParam` unless raw strings are to be kept.
+    fn strings(&self) -> Vec<usize> {
+        if GenOptions::get().raw_strings {
+            return Vec::new();
+        }
+
+        self.signature
+            .params
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| {
+                t.pointers == 1 && (t.is_const || t.is_in_only()) && string_type(t).is_some()
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    // The generic parameters of a wrapper function: the interface returned directly, if any,
+    // and a type for each string parameter.
+    fn generics(&self) -> TokenStream {
+        let strings = self.strings();
+        let mut generics = Vec::new();
+
+        if !strings.is_empty() {
+            generics.push(quote! { 'a });
+        }
+
+        if self.com_out().is_some() {
+            generics.push(quote! { T: ::windows::Interface });
+        }
+
+        for index in strings {
+            let name = format_ident(&format!("P{}", index));
+            let string = string_type(&self.signature.params[index]).unwrap();
+            generics
+                .push(quote! { #name: ::std::convert::Into<::windows::StringParam<'a, #string>> });
+        }
+
+        if generics.is_empty() {
+            TokenStream::new()
+        } else {
+            quote! { <#(#generics),*> }
+        }
+    }
+
+    // The indexes of the parameters taken by a wrapper function, which leaves out reserved
+    // parameters unless they're to be kept, the lengths of arrays taken as slices, and any
+    // parameters for an interface that's returned directly.
+    fn public_indexes(&self) -> Vec<usize> {
+        let keep_reserved = GenOptions::get().keep_reserved;
+        let com_out = self.com_out();
+        let arrays = self.arrays();
+
+        self.signature
+            .params
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| match com_out {
+                Some((riid, out)) => *index != riid && *index != out,
+                None => true,
+            })
+            .filter(|(index, _)| !arrays.iter().any(|(_, len)| len == index))
+            .filter(|(_, t)| keep_reserved || !is_reserved(t))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    // The parameters taken by a wrapper function, with arrays taken as slices, optional pointers
+    // as options, and strings as the generic types that convert to them.
+    fn public_params(&self) -> Vec<TokenStream> {
+        let arrays = self.arrays();
+        let optionals = self.optionals();
+        let strings = self.strings();
+
+        self.public_indexes()
+            .into_iter()
+            .map(|index| {
+                let t = &self.signature.params[index];
+                let name = format_ident(&t.name);
+
+                let tokens = if arrays.iter().any(|(array, _)| *array == index) {
+                    gen_slice(t)
+                } else if optionals.contains(&index) {
+                    gen_optional(t)
+                } else if strings.contains(&index) {
+                    let name = format_ident(&format!("P{}", index));
+                    quote! { #name }
+                } else {
+                    t.gen_param()
+                };
+
+                quote! { #name: #tokens }
+            })
+            .collect()
+    }
+
+    // The arguments passed on by a wrapper function, with reserved parameters zeroed and any
+    // interface returned directly requested by `T` and written to `result`. Slices are passed
+    // with their length, or as null if they're empty, as APIs expect when asked for the length
+    // they need, and options as null if they're `None`. Strings are converted in the call's
+    // expression so that any buffer they're converted to outlives the call.
+    fn args(&self) -> Vec<TokenStream> {
+        let keep_reserved = GenOptions::get().keep_reserved;
+        let com_out = self.com_out();
+        let arrays = self.arrays();
+        let optionals = self.optionals();
+        let strings = self.strings();
+
+        self.signature
+            .params
+            .iter()
+            .enumerate()
+            .map(|(index, t)| {
+                if com_out.map(|(riid, _)| riid) == Some(index) {
+                    quote! { &<T as ::windows::Interface>::IID }
+                } else if com_out.map(|(_, out)| out) == Some(index) {
+                    quote! { ::windows::Abi::set_abi(&mut result) as _ }
+                } else if let Some((array, _)) = arrays.iter().find(|(_, len)| *len == index) {
+                    let array = format_ident(&self.signature.params[*array].name);
+                    quote! { #array.len() as _ }
+                } else if arrays.iter().any(|(array, _)| *array == index) {
+                    let name = format_ident(&t.name);
+
+                    if t.is_const || t.is_in_only() {
+                        quote! { if #name.is_empty() { ::std::ptr::null() } else { #name.as_ptr() as _ } }
+                    } else {
+                        quote! { if #name.is_empty() { ::std::ptr::null_mut() } else { #name.as_mut_ptr() as _ } }
+                    }
+                } else if !keep_reserved && is_reserved(t) {
+                    quote! { ::std::mem::zeroed() }
+                } else if optionals.contains(&index) {
+                    let name = format_ident(&t.name);
+
+                    if t.is_const || t.is_in_only() {
+                        quote! { #name.map_or(::std::ptr::null(), |value| value as *const _) }
+                    } else {
+                        quote! { #name.map_or(::std::ptr::null_mut(), |value| value as *mut _) }
+                    }
+                } else if strings.contains(&index) {
+                    let name = format_ident(&t.name);
+                    let string = string_type(t).unwrap();
+                    quote! { ::std::convert::Into::<::windows::StringParam<#string>>::into(#name).abi() }
+                } else {
+                    let name = format_ident(&t.name);
+                    quote! { #name }
+                }
+            })
+            .collect()
+    }
+
+    // Resolves the function the first time it's called, returning the error from `LoadLibraryExW`
+    // or `GetProcAddress`, such as `ERROR_PROC_NOT_FOUND`, on every call if it isn't available.
+    // The address and error are cached in atomics so that no `static mut` is needed. Threads that
+    // race on the first call may each resolve the function, which only loads the library again.
+    fn gen_delay_load(&self, link: &str) -> TokenStream {
+        let name = self.signature.method.name();
+        let library = format!("{}.dll", link);
+        let ident = format_ident(name);
+
+        let params = self.public_params();
+        let types = self.signature.params.iter().map(|t| t.gen_param());
+        let args = self.args();
+
+        let return_type = if let Some(t) = &self.signature.return_type {
+            t.gen_field()
+        } else {
+            quote! { () }
+        };
+
+        let generics = self.generics();
+
+        let (result, call) = if self.com_out().is_some() {
+            (
+                quote! { T },
+                quote! {
+                    let mut result: ::std::option::Option<T> = ::std::option::Option::None;
+                    function(#(#args),*).and_some(result)
+                },
+            )
+        } else {
+            (
+                return_type.clone(),
+                quote! { ::std::result::Result::Ok(function(#(#args),*)) },
+            )
+        };
+
+        quote! {
+            pub unsafe fn #ident #generics(#(#params),*) -> ::windows::Result<#result> {
+                static FUNCTION: ::std::sync::atomic::AtomicPtr<::std::ffi::c_void> =
+                    ::std::sync::atomic::AtomicPtr::new(::std::ptr::null_mut());
+                static ERROR: ::std::sync::atomic::AtomicU32 = ::std::sync::atomic::AtomicU32::new(0);
+                const LOAD_LIBRARY_SEARCH_DEFAULT_DIRS: u32 = 0x0000_1000;
+
+                let mut address = FUNCTION.load(::std::sync::atomic::Ordering::Acquire);
+
+                if address.is_null() {
+                    let error = ERROR.load(::std::sync::atomic::Ordering::Acquire);
+
+                    if error != 0 {
+                        return ::std::result::Result::Err(::windows::ErrorCode(error).into());
+                    }
+
+                    match ::windows::delay_load(#library, #name, LOAD_LIBRARY_SEARCH_DEFAULT_DIRS) {
+                        ::std::result::Result::Ok(value) => {
+                            FUNCTION.store(value, ::std::sync::atomic::Ordering::Release);
+                            address = value;
+                        }
+                        ::std::result::Result::Err(error) => {
+                            ERROR.store(error.0, ::std::sync::atomic::Ordering::Release);
+                            return ::std::result::Result::Err(error.into());
+                        }
+                    }
+                }
+
+                let function: extern "system" fn(#(#types),*) -> #return_type =
+                    ::std::mem::transmute(address);
+
+                #call
+            }
+        }
+    }
+
     pub fn dependencies(&self) -> Vec<winmd::TypeDef> {
         self.signature.dependencies()
     }
 }
+
+// The calling convention bits of a `MethodDefSig`, and the value for a variadic method.
+const CALLING_CONVENTION: u32 = 0x0F;
+const VARARG: u32 = 0x05;
+
+const COM_OUT_PTR: (&str, &str) = ("Windows.Win32.Interop", "ComOutPtrAttribute");
+
+const NATIVE_TYPE_INFO: (&str, &str) = ("Windows.Win32.Interop", "NativeTypeInfoAttribute");
+
+// The `UnmanagedType` values `NativeTypeInfo` describes strings with.
+const LPSTR: i32 = 20;
+const LPWSTR: i32 = 21;
+
+// The index of the parameter holding the length of an array parameter, if the metadata gives one.
+fn size_param(t: &Type) -> Option<usize> {
+    t.param?
+        .attributes()
+        .find(|attribute| attribute.name() == NATIVE_TYPE_INFO)?
+        .args()
+        .into_iter()
+        .find_map(|(name, arg)| match arg {
+            winmd::AttributeArg::I16(index) if name == "SizeParamIndex" => Some(index as usize),
+            _ => None,
+        })
+}
+
+// The character type of a null-terminated string parameter, if the metadata describes it as
+// one, which is `u16` for wide strings and `i8` for ANSI strings.
+fn string_type(t: &Type) -> Option<TokenStream> {
+    let args = t
+        .param?
+        .attributes()
+        .find(|attribute| attribute.name() == NATIVE_TYPE_INFO)?
+        .args();
+
+    let null_terminated = args.iter().any(|(name, arg)| {
+        name == "IsNullTerminated" && matches!(arg, winmd::AttributeArg::Bool(true))
+    });
+
+    if !null_terminated {
+        return None;
+    }
+
+    match (args.first(), &t.kind) {
+        (Some((_, winmd::AttributeArg::I32(LPWSTR))), TypeKind::U16) => Some(quote! { u16 }),
+        (Some((_, winmd::AttributeArg::I32(LPSTR))), TypeKind::I8) => Some(quote! { i8 }),
+        _ => None,
+    }
+}
+
+// An optional pointer taken as an `Option` of a reference, which is only mutable if the callee
+// may write through it.
+fn gen_optional(t: &Type) -> TokenStream {
+    let mut pointee = t.clone();
+    pointee.pointers -= 1;
+    let pointee = pointee.gen_field();
+
+    if t.is_const || t.is_in_only() {
+        quote! { ::std::option::Option<&#pointee> }
+    } else {
+        quote! { ::std::option::Option<&mut #pointee> }
+    }
+}
+
+// An array taken as a slice, which is only borrowed mutably if the callee may write to it.
+// Untyped buffers whose length counts bytes are slices of bytes.
+fn gen_slice(t: &Type) -> TokenStream {
+    let mut element = t.clone();
+    element.pointers -= 1;
+
+    let element = if element.kind == TypeKind::Void && element.pointers == 0 {
+        quote! { u8 }
+    } else {
+        element.gen_field()
+    };
+
+    if t.is_const || t.is_in_only() {
+        quote! { &[#element] }
+    } else {
+        quote! { &mut [#element] }
+    }
+}
+
+// Metadata doesn't say what an untyped buffer's length counts, so it's only known to be bytes
+// if it's named as such, like `cbData`, `nNumberOfBytesToWrite`, and `SrcDataSize`. Character
+// and element counts, like `nNumberOfCharsToWrite` and `cchBuffer`, are named differently.
+fn is_byte_count(t: &Type) -> bool {
+    t.name == "cb"
+        || t.name.starts_with("cb_")
+        || t.name
+            .split('_')
+            .any(|word| word == "bytes" || word == "size")
+}
+
+// Metadata doesn't mark reserved parameters, which must be null or zero, so they're found by
+// their conventional names, such as `Reserved`, `dwReserved`, `pvReserved`, and `Reserved2`.
+fn is_reserved(t: &Type) -> bool {
+    let name = t.name.trim_end_matches(|c: char| c.is_ascii_digit());
+    name == "reserved" || name.ends_with("_reserved")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn function((namespace, name): (&'static str, &str)) -> Function {
+        match winmd::TypeReader::get().expect_type((namespace, name)) {
+            winmd::Type::MethodDef((def, method)) => {
+                Function::new(TypeName::from_type_def(&def, namespace), &method)
+            }
+            _ => panic!("Type not a function"),
+        }
+    }
+
+    #[test]
+    fn test_const_params() {
+        let function = function(("Windows.Win32.SystemServices", "CreateEventW"));
+        let tokens = function.gen().into_string();
+        assert!(tokens.contains("lp_event_attributes : * const SECURITY_ATTRIBUTES"));
+    }
+
+    #[test]
+    fn test_reserved_params() {
+        let function = function(("Windows.Win32.Com", "CreateUri"));
+
+        let tokens = function.gen().into_string();
+        assert!(tokens.contains("( pwz_uri : P0 , dw_flags : u32 , pp_uri : * mut"));
+        assert!(tokens.contains(":: std :: mem :: zeroed ( )"));
+
+        GenOptions {
+            keep_reserved: true,
+            ..GenOptions::default()
+        }
+        .set();
+
+        let tokens = function.gen().into_string();
+        assert!(tokens.contains("dw_reserved : usize"));
+        assert!(!tokens.contains("zeroed"));
+
+        GenOptions::default().set();
+    }
+
+    #[test]
+    fn test_com_out_params() {
+        let tokens = function(("Windows.Win32.Dxgi", "CreateDXGIFactory1"))
+            .gen()
+            .into_string();
+        assert!(tokens.contains(
+            "pub unsafe fn CreateDXGIFactory1 < T : :: windows :: Interface > ( ) -> :: windows :: Result < T >"
+        ));
+        assert!(tokens.contains("& < T as :: windows :: Interface > :: IID"));
+
+        // Other GUID parameters are left alone.
+        let tokens = function(("Windows.Win32.Com", "CoCreateInstance"))
+            .gen()
+            .into_string();
+        assert!(tokens.contains("rclsid : * const :: windows :: Guid , p_unk_outer : :: std :: option :: Option < :: windows :: IUnknown > , dw_cls_context : u32 ) -> :: windows :: Result < T >"));
+    }
+
+    #[test]
+    fn test_docs() {
+        let tokens = function(("Windows.Win32.HiDpi", "SetProcessDpiAwarenessContext"))
+            .gen()
+            .into_string();
+        assert!(tokens.contains("\"The [`SetProcessDpiAwarenessContext`](https://docs.microsoft.com/search/?terms=SetProcessDpiAwarenessContext) function exported by `USER32`.\""));
+    }
+
+    #[test]
+    fn test_arrays() {
+        let read = function(("Windows.Win32.FileSystem", "ReadFile"));
+        let tokens = read.gen().into_string();
+        assert!(tokens.contains("lp_buffer : & mut [ u8 ] , lp_number_of_bytes_read : :: std :: option :: Option < & mut u32 >"));
+        assert!(tokens.contains("lp_buffer . len ( ) as _"));
+
+        let write = function(("Windows.Win32.FileSystem", "WriteFile"));
+        let tokens = write.gen().into_string();
+        assert!(tokens.contains("lp_buffer : & [ u8 ]"));
+        assert!(tokens.contains(":: std :: ptr :: null ( )"));
+
+        // The console's buffer is counted in characters rather than bytes, so it's left raw.
+        let console = function(("Windows.Win32.SystemServices", "WriteConsoleW"));
+        let tokens = console.gen().into_string();
+        assert!(!tokens.contains("[ u8 ]"));
+        assert!(tokens.contains("n_number_of_chars_to_write : u32"));
+
+        GenOptions {
+            raw_arrays: true,
+            ..GenOptions::default()
+        }
+        .set();
+
+        let tokens = read.gen().into_string();
+        assert!(tokens.contains("n_number_of_bytes_to_read : u32"));
+        assert!(!tokens.contains("[ u8 ]"));
+
+        GenOptions::default().set();
+    }
+
+    #[test]
+    fn test_optionals() {
+        let function = function(("Windows.Win32.SystemServices", "CreateEventW"));
+
+        let tokens = function.gen().into_string();
+        assert!(tokens.contains(
+            "lp_event_attributes : :: std :: option :: Option < & SECURITY_ATTRIBUTES >"
+        ));
+        assert!(tokens.contains("lp_event_attributes . map_or ( :: std :: ptr :: null ( )"));
+
+        GenOptions {
+            raw_pointers: true,
+            ..GenOptions::default()
+        }
+        .set();
+
+        let tokens = function.gen().into_string();
+        assert!(!tokens.contains("Option"));
+
+        GenOptions::default().set();
+    }
+
+    #[test]
+    fn test_strings() {
+        let wide = function(("Windows.Win32.FileSystem", "CreateFileW"));
+        let ansi = function(("Windows.Win32.SystemServices", "CreateEventA"));
+
+        let tokens = wide.gen().into_string();
+        assert!(tokens.contains(
+            "pub unsafe fn CreateFileW < 'a , P0 : :: std :: convert :: Into < :: windows :: StringParam < 'a , u16 >> >"
+        ));
+        assert!(tokens.contains("lp_file_name : P0"));
+        assert!(tokens.contains(
+            ":: std :: convert :: Into :: < :: windows :: StringParam < u16 >> :: into ( lp_file_name ) . abi ( )"
+        ));
+
+        let tokens = ansi.gen().into_string();
+        assert!(tokens
+            .contains("P3 : :: std :: convert :: Into < :: windows :: StringParam < 'a , i8 >>"));
+
+        GenOptions {
+            raw_strings: true,
+            ..GenOptions::default()
+        }
+        .set();
+
+        let tokens = ansi.gen().into_string();
+        assert!(!tokens.contains("StringParam"));
+
+        GenOptions::default().set();
+    }
+
+    #[test]
+    fn test_variadic() {
+        let function = function(("Windows.Win32.MenusAndResources", "wsprintfW"));
+
+        let tokens = function.gen().into_string();
+        assert!(!function.is_variadic());
+        assert!(tokens.contains("extern \"system\""));
+
+        GenOptions {
+            variadic: vec!["wsprintfW".to_string()],
+            ..GenOptions::default()
+        }
+        .set();
+
+        let tokens = function.gen().into_string();
+        assert!(function.is_variadic());
+        assert!(tokens.contains("extern \"C\""));
+        assert!(tokens.contains("param1 : * const u16 , ... )"));
+        assert!(tokens.contains("This function is variadic."));
+
+        GenOptions::default().set();
+    }
+
+    #[test]
+    fn test_result() {
+        let tokens = function(("Windows.Win32.SystemServices", "SetEvent"))
+            .gen()
+            .into_string();
+        assert!(tokens.contains(
+            "pub unsafe fn try_SetEvent ( h_event : HANDLE ) -> :: windows :: Result < ( ) > { SetEvent ( h_event ) . ok ( ) }"
+        ));
+
+        // The wrapper takes the same parameters as the function it calls.
+        let tokens = function(("Windows.Win32.Com", "CreateUri"))
+            .gen()
+            .into_string();
+        assert!(tokens.contains("try_CreateUri < 'a , P0 :"));
+        assert!(tokens.contains("CreateUri ( pwz_uri , dw_flags , pp_uri ) . ok ( )"));
+
+        let tokens = function(("Windows.Win32.SystemServices", "WaitForSingleObject")).gen();
+        assert!(!tokens.into_string().contains("try_"));
+
+        // `NTSTATUS` is mapped onto the runtime's type, which fails for negative codes.
+        let tokens = function(("Windows.Win32.Security", "BCryptGenRandom"))
+            .gen()
+            .into_string();
+        assert!(tokens.contains("dw_flags : u32 ) -> :: windows :: NTSTATUS ;"));
+        assert!(tokens.contains("BCryptGenRandom ( h_algorithm , pb_buffer , dw_flags ) . ok ( )"));
+    }
+
+    #[test]
+    fn test_link() {
+        let function = function(("Windows.Win32.HiDpi", "SetProcessDpiAwarenessContext"));
+
+        let tokens = function.gen().into_string();
+        assert!(tokens.contains("name =\"USER32\""));
+
+        GenOptions {
+            link: Some("onecore".to_string()),
+            ..GenOptions::default()
+        }
+        .set();
+
+        let tokens = function.gen().into_string();
+        assert!(tokens.contains("name =\"onecore\""));
+
+        GenOptions {
+            delay_load: vec!["SetProcessDpiAwarenessContext".to_string()],
+            ..GenOptions::default()
+        }
+        .set();
+
+        let tokens = function.gen().into_string();
+        assert!(!tokens.contains("link"));
+        assert!(tokens.contains("\"USER32.dll\""));
+        assert!(!tokens.contains("static mut"));
+
+        GenOptions::default().set();
+    }
+}
@@ -59,40 +59,82 @@ fn gen_async_kind(
         _ => panic!("Unexpected AsyncKind"),
     };
 
+    // Progress is surfaced as a stream that ends when the operation completes. The operation
+    // only accepts one completion handler, so the stream, `get`, and the future all register
+    // their callbacks through `Awaitable::on_completed`, which shares it.
+    let progress_stream = match kind {
+        AsyncKind::ActionWithProgress => Some((name.generics[0].gen(), quote! { AsyncActionProgressHandler })),
+        AsyncKind::OperationWithProgress => Some((name.generics[1].gen(), quote! { AsyncOperationProgressHandler })),
+        _ => None,
+    }
+    .map_or_else(TokenStream::new, |(progress_type, progress_handler)| {
+        quote! {
+            pub fn progress_stream(&self) -> ::windows::Result<::windows::ProgressStream<#progress_type>> {
+                let (stream, sink) = ::windows::ProgressStream::new();
+                let completed = sink.clone();
+                self.set_progress(::windows::foundation:: #progress_handler::new(move |_sender, progress| {
+                    sink.report(progress);
+                    Ok(())
+                }))?;
+                ::windows::Awaitable::on_completed(self, move || completed.complete())?;
+                Ok(stream)
+            }
+        }
+    });
+
     let constraints = self_name.gen_constraint();
     let name = self_name.gen();
 
+    // A canceled operation's results are undefined, so cancelation is reported as such rather
+    // than as whatever error `GetResults` happens to return.
+    let results = quote! {
+        if self.status()? == ::windows::foundation::AsyncStatus::Canceled {
+            Err(::windows::Error::fast_error(::windows::ErrorCode::ERROR_CANCELLED))
+        } else {
+            self.get_results()
+        }
+    };
+
     (
         quote! {
             pub fn get(&self) -> ::windows::Result<#return_type> {
                 if self.status()? == ::windows::foundation::AsyncStatus::Started {
                     let (waiter, signaler) = ::windows::Waiter::new();
-                    self.set_completed(::windows::foundation:: #handler::new(move |_sender, _args| {
+                    ::windows::Awaitable::on_completed(self, move || {
                         // Safe because the waiter will only be dropped after being signaled.
                         unsafe { signaler.signal(); }
-                        Ok(())
-                    }))?;
+                    })?;
                 }
-                self.get_results()
+                #results
             }
+            #progress_stream
         },
         quote! {
-            impl<#constraints> ::std::future::Future for #name {
-                type Output = ::windows::Result<#return_type>;
-
-                fn poll(self: ::std::pin::Pin<&mut Self>, context: &mut ::std::task::Context) -> ::std::task::Poll<Self::Output> {
-                    if self.status()? == ::windows::foundation::AsyncStatus::Started {
-                        let waker = context.waker().clone();
+            impl<#constraints> ::windows::Awaitable for #name {
+                type Output = #return_type;
 
-                        let _ = self.set_completed(::windows::foundation:: #handler::new(move |_sender, _args| {
-                            waker.wake_by_ref();
-                            Ok(())
-                        }));
+                fn is_started(&self) -> ::windows::Result<bool> {
+                    Ok(self.status()? == ::windows::foundation::AsyncStatus::Started)
+                }
+                fn set_completed_callback<F: FnMut() + 'static>(&self, mut callback: F) -> ::windows::Result<()> {
+                    self.set_completed(::windows::foundation:: #handler::new(move |_sender, _args| {
+                        callback();
+                        Ok(())
+                    }))
+                }
+                fn results(&self) -> ::windows::Result<#return_type> {
+                    #results
+                }
+                fn request_cancel(&self) {
+                    let _ = self.cancel();
+                }
+            }
+            impl<#constraints> ::std::future::IntoFuture for #name {
+                type Output = ::windows::Result<#return_type>;
+                type IntoFuture = ::windows::AsyncFuture<Self>;
 
-                        ::std::task::Poll::Pending
-                    } else {
-                        ::std::task::Poll::Ready(self.get_results())
-                    }
+                fn into_future(self) -> Self::IntoFuture {
+                    ::windows::AsyncFuture::new(self)
                 }
             }
         },
@@ -0,0 +1,117 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+/// Settings that change the shape of the generated bindings.
+///
+/// The options are held per thread so that the `build` macro can set them before generating
+/// code without having to thread them through every type definition.
+#[derive(Clone, Debug)]
+pub struct GenOptions {
+    /// Return the out parameters of WinRT methods as part of a tuple rather than taking them as
+    /// `&mut` parameters. Win32 functions and COM interface methods are unchanged, since the
+    /// metadata doesn't tell their out pointers apart from in-out and optional ones.
+    pub tuple_returns: bool,
+    /// Also describe the requested WinRT types as MIDL 3.0 in a `windows.idl` file.
+    pub idl: bool,
+    /// The lints allowed on each generated module, so that crates built with `-D warnings` or
+    /// stricter lint levels aren't broken by code they can't change.
+    pub allow: Vec<String>,
+    /// The functions resolved with `LoadLibraryExW` and `GetProcAddress` the first time they're
+    /// called, rather than imported, so that binaries still load where the functions are missing.
+    pub delay_load: Vec<String>,
+    /// The functions that take variadic arguments, such as `wsprintfW`, which the metadata only
+    /// describes by their fixed parameters.
+    pub variadic: Vec<String>,
+    /// The structs given chained `with_` setters in addition to the Win32 structs that have a
+    /// size field, such as `WNDCLASSEXW`.
+    pub builders: Vec<String>,
+    /// An umbrella library, such as `onecore` or `windowsapp`, that all functions are linked
+    /// against instead of the classic import libraries like `kernel32` and `user32`.
+    pub link: Option<String>,
+    /// The names given to struct fields and parameters in place of their snake case names, keyed
+    /// by the struct, function, or method and the field or parameter's metadata name, such as
+    /// `CIECHROMA.Y`.
+    pub rename: BTreeMap<String, String>,
+    /// Keep reserved parameters, which must be null or zero, in function signatures rather than
+    /// passing them on the caller's behalf.
+    pub keep_reserved: bool,
+    /// Keep array parameters as the pointers and lengths of the imported function rather than
+    /// taking them as slices.
+    pub raw_arrays: bool,
+    /// Keep optional pointer parameters as raw pointers rather than taking them as an `Option`
+    /// of a reference.
+    pub raw_pointers: bool,
+    /// Keep string parameters as raw pointers rather than taking anything that converts to a
+    /// `StringParam`, such as a `&str`.
+    pub raw_strings: bool,
+    /// Leave deprecated types, and ANSI functions that have a wide counterpart, out of the
+    /// namespaces that are included in full.
+    pub exclude_deprecated: bool,
+    /// Leave ANSI functions and structs that have a wide counterpart, such as `MessageBoxA`, out
+    /// of the namespaces that are included in full, keeping deprecated types.
+    pub exclude_ansi: bool,
+    /// Derive `serde::Serialize` and `serde::Deserialize` for enums and for structs whose fields
+    /// can all be serialized, which requires the `serde` feature of the `windows` crate.
+    pub serde: bool,
+    /// Implement `bytemuck::Pod` and `bytemuck::Zeroable` for enums and for structs that are
+    /// plain data, which requires the `bytemuck` feature of the `windows` crate.
+    pub bytemuck: bool,
+    /// Also generate a Rust `enum`, named for the enum with an `Enum` suffix, for each enum that
+    /// doesn't hold flags, so that its values can be matched exhaustively.
+    pub enums: bool,
+    /// Gate each namespace's module behind a cargo feature named for the namespace, such as
+    /// `Win32_Direct3D11`, so that crates sharing the bindings only compile what they use.
+    pub features: bool,
+}
+
+impl Default for GenOptions {
+    fn default() -> Self {
+        Self {
+            tuple_returns: false,
+            idl: false,
+            allow: DEFAULT_ALLOW.iter().map(|lint| lint.to_string()).collect(),
+            delay_load: Vec::new(),
+            variadic: Vec::new(),
+            builders: Vec::new(),
+            link: None,
+            rename: BTreeMap::new(),
+            keep_reserved: false,
+            raw_arrays: false,
+            raw_pointers: false,
+            raw_strings: false,
+            exclude_deprecated: false,
+            exclude_ansi: false,
+            serde: false,
+            bytemuck: false,
+            enums: false,
+            features: false,
+        }
+    }
+}
+
+/// The lints allowed on generated modules unless the `build` macro is given its own list.
+pub const DEFAULT_ALLOW: &[&str] = &[
+    "non_camel_case_types",
+    "non_snake_case",
+    "non_upper_case_globals",
+    "unused_variables",
+    "deprecated",
+    "clippy::all",
+];
+
+thread_local! {
+    static OPTIONS: RefCell<Rc<GenOptions>> = RefCell::new(Rc::new(GenOptions::default()));
+}
+
+impl GenOptions {
+    /// Returns the options currently in effect.
+    pub fn get() -> Rc<Self> {
+        OPTIONS.with(|options| options.borrow().clone())
+    }
+
+    /// Replaces the options in effect for any code generated afterwards on this thread.
+    pub fn set(self) {
+        OPTIONS.with(|options| *options.borrow_mut() = Rc::new(self));
+    }
+}
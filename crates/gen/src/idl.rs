@@ -0,0 +1,478 @@
+use crate::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Renders the WinRT types selected by the limits as MIDL 3.0, grouped by namespace.
+///
+/// Only the selected types are described. Their dependencies are referenced by name and are
+/// expected to be provided by the metadata the IDL is compiled against.
+pub fn gen_idl(reader: &'static winmd::TypeReader, limits: &TypeLimits) -> String {
+    let mut defs = BTreeSet::new();
+
+    for limit in limits.limits() {
+        match &limit.limit {
+            TypeLimit::All => {
+                for t in reader.namespace_types(limit.namespace) {
+                    if let winmd::Type::TypeDef(def) = t {
+                        defs.insert(def);
+                    }
+                }
+            }
+            TypeLimit::Some(types) => {
+                for name in types {
+                    if let winmd::Type::TypeDef(def) = reader.expect_type((limit.namespace, name)) {
+                        defs.insert(def);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut namespaces = BTreeMap::<&str, Vec<String>>::new();
+
+    for def in defs {
+        if !def.is_winrt() {
+            continue;
+        }
+
+        let idl = match def.category() {
+            winmd::TypeCategory::Interface => gen_interface(&def),
+            winmd::TypeCategory::Class => gen_class(&def),
+            winmd::TypeCategory::Enum => gen_enum(&def),
+            winmd::TypeCategory::Struct => gen_struct(&def),
+            winmd::TypeCategory::Delegate => gen_delegate(&def),
+            winmd::TypeCategory::Attribute | winmd::TypeCategory::Contract => continue,
+        };
+
+        namespaces.entry(def.name().0).or_default().push(idl);
+    }
+
+    let mut idl = String::new();
+
+    for (namespace, types) in namespaces {
+        idl.push_str(&format!("namespace {}\n{{\n", namespace));
+        idl.push_str(&types.join("\n"));
+        idl.push_str("}\n");
+    }
+
+    idl
+}
+
+fn gen_interface(def: &winmd::TypeDef) -> String {
+    let name = TypeName::from_type_def(def, def.name().0);
+
+    let requires: Vec<String> = def
+        .interfaces()
+        .map(|i| {
+            gen_type_name(&TypeName::from_type_def_or_ref(
+                &i.interface(),
+                &name.generics,
+                name.namespace,
+            ))
+        })
+        .collect();
+
+    let requires = if requires.is_empty() {
+        String::new()
+    } else {
+        format!(" requires {}", requires.join(", "))
+    };
+
+    let mut members = String::new();
+    gen_members(def, &name.generics, "", &mut members);
+
+    format!(
+        "    interface {}{}\n    {{\n{}    }};\n",
+        gen_declaration_name(&name),
+        requires,
+        members
+    )
+}
+
+fn gen_class(def: &winmd::TypeDef) -> String {
+    let (namespace, class_name) = def.name();
+    let mut header = Vec::new();
+    let mut members = String::new();
+
+    let base = def.extends();
+
+    if base.name() != ("System", "Object") {
+        header.push(gen_type_name(&TypeName::from_type_def_or_ref(
+            &base,
+            &[],
+            namespace,
+        )));
+    }
+
+    for attribute in def.attributes() {
+        match attribute.name() {
+            ("Windows.Foundation.Metadata", "ActivatableAttribute") => {
+                match attribute_factory(&attribute) {
+                    Some(factory) => gen_constructors(&factory, class_name, "", 0, &mut members),
+                    None => members.push_str(&format!("        {}();\n", class_name)),
+                }
+            }
+            ("Windows.Foundation.Metadata", "ComposableAttribute") => {
+                // One of the arguments is a CompositionType enum and the Public variant
+                // has a value of 2 as a signed 32-bit integer.
+                let prefix = if attribute
+                    .args()
+                    .iter()
+                    .any(|(_, arg)| matches!(arg, winmd::AttributeArg::I32(2)))
+                {
+                    ""
+                } else {
+                    "protected "
+                };
+
+                // Composable factory methods end with the outer and inner objects used for
+                // aggregation, which MIDL supplies implicitly.
+                gen_constructors(
+                    &attribute_factory(&attribute).unwrap(),
+                    class_name,
+                    prefix,
+                    2,
+                    &mut members,
+                );
+            }
+            _ => {}
+        }
+    }
+
+    for attribute in def.attributes() {
+        if attribute.name() == ("Windows.Foundation.Metadata", "StaticAttribute") {
+            gen_members(
+                &attribute_factory(&attribute).unwrap(),
+                &[],
+                "static ",
+                &mut members,
+            );
+        }
+    }
+
+    // Interfaces exclusive to the class are described by the class body while any others
+    // are listed as being implemented by the class.
+    for interface in def.interfaces() {
+        let name = TypeName::from_type_def_or_ref(&interface.interface(), &[], namespace);
+
+        if name.generics.is_empty() && is_exclusive_to(&name.def, def) {
+            gen_members(&name.def, &[], "", &mut members);
+        } else {
+            header.push(gen_type_name(&name));
+        }
+    }
+
+    let unsealed = if def.flags().sealed() {
+        ""
+    } else {
+        "unsealed "
+    };
+
+    let header = if header.is_empty() {
+        String::new()
+    } else {
+        format!(" : {}", header.join(", "))
+    };
+
+    format!(
+        "    {}runtimeclass {}{}\n    {{\n{}    }};\n",
+        unsealed, class_name, header, members
+    )
+}
+
+fn gen_enum(def: &winmd::TypeDef) -> String {
+    let e = Enum::from_type_name(TypeName::from_type_def(def, def.name().0));
+
+    let (flags, fields) = if matches!(e.underlying_type, winmd::ElementType::U32) {
+        let fields = e.fields.iter().map(|(name, value)| match value {
+            EnumConstant::U32(value) => format!("        {} = 0x{:X},\n", name, value),
+            EnumConstant::I32(value) => format!("        {} = 0x{:X},\n", name, value),
+        });
+
+        ("    [flags]\n", fields.collect::<String>())
+    } else {
+        let fields = e.fields.iter().map(|(name, value)| match value {
+            EnumConstant::U32(value) => format!("        {} = {},\n", name, value),
+            EnumConstant::I32(value) => format!("        {} = {},\n", name, value),
+        });
+
+        ("", fields.collect::<String>())
+    };
+
+    format!(
+        "{}    enum {}\n    {{\n{}    }};\n",
+        flags, e.name.name, fields
+    )
+}
+
+fn gen_struct(def: &winmd::TypeDef) -> String {
+    let (namespace, name) = def.name();
+
+    let fields: String = def
+        .fields()
+        .filter(|field| !field.flags().literal())
+        .map(|field| {
+            format!(
+                "        {} {};\n",
+                gen_type(&Type::from_field(&field, namespace)),
+                field.name()
+            )
+        })
+        .collect();
+
+    format!("    struct {}\n    {{\n{}    }};\n", name, fields)
+}
+
+fn gen_delegate(def: &winmd::TypeDef) -> String {
+    let name = TypeName::from_type_def(def, def.name().0);
+
+    let invoke = def
+        .methods()
+        .find(|method| method.name() == "Invoke")
+        .unwrap();
+
+    let signature = Signature::new(&invoke, &name.generics, name.namespace);
+
+    format!(
+        "    delegate {} {}({});\n",
+        gen_return_type(&signature),
+        gen_declaration_name(&name),
+        gen_params(&signature.params)
+    )
+}
+
+fn gen_constructors(
+    factory: &winmd::TypeDef,
+    class_name: &str,
+    prefix: &str,
+    implicit_params: usize,
+    members: &mut String,
+) {
+    let (namespace, _) = factory.name();
+
+    for method in factory.methods() {
+        let signature = Signature::new(&method, &[], namespace);
+        let params = &signature.params[..signature.params.len() - implicit_params];
+
+        members.push_str(&format!(
+            "        {}{}({});\n",
+            prefix,
+            class_name,
+            gen_params(params)
+        ));
+    }
+}
+
+fn gen_members(
+    interface: &winmd::TypeDef,
+    generics: &[TypeKind],
+    prefix: &str,
+    members: &mut String,
+) {
+    let (namespace, _) = interface.name();
+    let methods: Vec<winmd::MethodDef> = interface.methods().collect();
+
+    let has_method = |name: String| methods.iter().any(|method| method.name() == name);
+
+    for method in &methods {
+        let signature = Signature::new(method, generics, namespace);
+
+        let member = match method.category() {
+            winmd::MethodCategory::Get => {
+                let name = &method.name()[4..];
+
+                let accessors = if has_method(format!("put_{}", name)) {
+                    "get; set;"
+                } else {
+                    "get;"
+                };
+
+                format!(
+                    "{} {} {{ {} }}",
+                    gen_return_type(&signature),
+                    name,
+                    accessors
+                )
+            }
+            winmd::MethodCategory::Set => {
+                let name = &method.name()[4..];
+
+                if has_method(format!("get_{}", name)) {
+                    continue;
+                }
+
+                format!("{} {} {{ set; }}", gen_type(&signature.params[0]), name)
+            }
+            winmd::MethodCategory::Add => format!(
+                "event {} {}",
+                gen_type(&signature.params[0]),
+                &method.name()[4..]
+            ),
+            winmd::MethodCategory::Remove => continue,
+            winmd::MethodCategory::Normal => {
+                let overload = method
+                    .attributes()
+                    .filter(|attribute| {
+                        attribute.name() == ("Windows.Foundation.Metadata", "OverloadAttribute")
+                    })
+                    .flat_map(|attribute| attribute.args())
+                    .find_map(|(_, arg)| match arg {
+                        winmd::AttributeArg::String(name) => {
+                            Some(format!("[method_name(\"{}\")] ", name))
+                        }
+                        _ => None,
+                    })
+                    .unwrap_or_default();
+
+                format!(
+                    "{}{} {}({})",
+                    overload,
+                    gen_return_type(&signature),
+                    method.name(),
+                    gen_params(&signature.params)
+                )
+            }
+        };
+
+        members.push_str(&format!("        {}{};\n", prefix, member));
+    }
+}
+
+fn gen_params(params: &[Type]) -> String {
+    params
+        .iter()
+        .map(|param| {
+            let name = param.param.map_or("value", |param| param.name());
+
+            let direction = if param.is_input {
+                ""
+            } else if param.is_array && !param.by_ref {
+                // A caller-allocated array that the callee fills.
+                "ref "
+            } else {
+                "out "
+            };
+
+            format!("{}{} {}", direction, gen_type(param), name)
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn gen_return_type(signature: &Signature) -> String {
+    signature
+        .return_type
+        .as_ref()
+        .map_or_else(|| "void".to_string(), gen_type)
+}
+
+fn gen_type(t: &Type) -> String {
+    let mut name = gen_kind(&t.kind);
+
+    if t.is_array {
+        name.push_str("[]");
+    }
+
+    name
+}
+
+fn gen_kind(kind: &TypeKind) -> String {
+    match kind {
+        TypeKind::Void => "void".to_string(),
+        TypeKind::Bool => "Boolean".to_string(),
+        TypeKind::Char => "Char".to_string(),
+        TypeKind::I8 => "Int8".to_string(),
+        TypeKind::U8 => "UInt8".to_string(),
+        TypeKind::I16 => "Int16".to_string(),
+        TypeKind::U16 => "UInt16".to_string(),
+        TypeKind::I32 => "Int32".to_string(),
+        TypeKind::U32 => "UInt32".to_string(),
+        TypeKind::I64 => "Int64".to_string(),
+        TypeKind::U64 => "UInt64".to_string(),
+        TypeKind::F32 => "Single".to_string(),
+        TypeKind::F64 => "Double".to_string(),
+        TypeKind::String => "String".to_string(),
+        TypeKind::Object => "Object".to_string(),
+        TypeKind::Guid => "Guid".to_string(),
+        TypeKind::ErrorCode => "Windows.Foundation.HResult".to_string(),
+        TypeKind::Matrix3x2 => "Windows.Foundation.Numerics.Matrix3x2".to_string(),
+        TypeKind::Class(name)
+        | TypeKind::Interface(name)
+        | TypeKind::Enum(name)
+        | TypeKind::Struct(name)
+        | TypeKind::Delegate(name) => gen_type_name(name),
+        TypeKind::Generic(name) => name.to_string(),
+        TypeKind::ISize
+        | TypeKind::USize
+        | TypeKind::IUnknown
+        | TypeKind::Bool32
+        | TypeKind::NtStatus
+        | TypeKind::NotYetSupported => panic!("gen_kind: {:?} is not a WinRT type", kind),
+    }
+}
+
+fn gen_type_name(name: &TypeName) -> String {
+    format!("{}.{}", name.namespace, gen_declaration_name(name))
+}
+
+fn gen_declaration_name(name: &TypeName) -> String {
+    // Generic types are named with a trailing backtick and arity, which MIDL doesn't accept.
+    let mut result = name.name.split('`').next().unwrap().to_string();
+
+    if !name.generics.is_empty() {
+        let generics: Vec<String> = name.generics.iter().map(gen_kind).collect();
+        result.push_str(&format!("<{}>", generics.join(", ")));
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn idl(namespace: &'static str, types: &[&str]) -> String {
+        let reader = winmd::TypeReader::get();
+        let mut limits = TypeLimits::new(reader);
+
+        limits
+            .insert(NamespaceTypes {
+                namespace,
+                limit: TypeLimit::Some(types.iter().map(|t| t.to_string()).collect()),
+            })
+            .unwrap();
+
+        gen_idl(reader, &limits)
+    }
+
+    #[test]
+    fn test_idl() {
+        let idl = idl(
+            "Windows.Foundation",
+            &[
+                "AsyncStatus",
+                "IStringable",
+                "Point",
+                "TypedEventHandler`2",
+                "Uri",
+            ],
+        );
+
+        assert!(idl.starts_with("namespace Windows.Foundation\n{\n"));
+        assert!(idl.contains("    enum AsyncStatus\n    {\n        Canceled = 2,\n"));
+        assert!(
+            idl.contains("    interface IStringable\n    {\n        String ToString();\n    };\n")
+        );
+        assert!(
+            idl.contains("    struct Point\n    {\n        Single X;\n        Single Y;\n    };\n")
+        );
+        assert!(idl.contains(
+            "    delegate void TypedEventHandler<TSender, TResult>(TSender sender, TResult args);\n"
+        ));
+        assert!(idl.contains("    runtimeclass Uri : Windows.Foundation.IStringable\n    {\n"));
+        assert!(idl.contains("        Uri(String baseUri, String relativeUri);\n"));
+        assert!(idl.contains("        static String EscapeComponent(String toEscape);\n"));
+        assert!(idl.contains("        String AbsoluteUri { get; };\n"));
+        assert!(idl.contains("        Windows.Foundation.Uri CombineUri(String relativeUri);\n"));
+        assert!(idl.ends_with("    };\n}\n"));
+    }
+}
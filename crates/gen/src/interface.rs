@@ -58,6 +58,8 @@ impl Interface {
         let definition = self.name.gen_definition();
         let abi_definition = self.name.gen_abi_definition();
         let name = self.name.gen();
+        let doc = self.name.gen_doc("interface");
+        let deprecated = gen_deprecated(self.name.def.attributes());
         let phantoms = self.name.phantoms();
         let constraints = self.name.gen_constraint();
 
@@ -71,7 +73,7 @@ impl Interface {
             .filter(|interface| interface.kind != InterfaceKind::Default)
             .map(|interface| interface.gen_conversions(&name, &constraints));
 
-        let methods = gen_method(&self.interfaces);
+        let methods = gen_method(&self.interfaces, &[]);
 
         let abi_methods = default_interface.methods.iter().map(|method| {
             let signature = method.gen_abi();
@@ -83,8 +85,12 @@ impl Interface {
 
         let iterator = gen_iterator(&self.name, &self.interfaces);
         let (async_get, future) = gen_async(&self.name, &self.interfaces);
+        let cast = gen_cast();
+        let deferral = gen_deferral(&self.interfaces);
 
         quote! {
+            #doc
+            #deprecated
             #[repr(transparent)]
             pub struct #definition(::windows::Object, #phantoms) where #constraints;
             impl<#constraints> ::std::clone::Clone for #name {
@@ -105,7 +111,10 @@ impl Interface {
             impl<#constraints> ::std::cmp::Eq for #name {}
             impl<#constraints> #name {
                 #methods
+                pub const IID: ::windows::Guid = <Self as ::windows::Interface>::IID;
                 #async_get
+                #cast
+                #deferral
             }
             unsafe impl<#constraints> ::windows::Interface for #name {
                 type Vtable = #abi_definition;
@@ -145,7 +154,27 @@ impl Interface {
             }
             impl<'a, #constraints> ::std::convert::Into<::windows::Param<'a, ::windows::Object>> for &'a #name {
                 fn into(self) -> ::windows::Param<'a, ::windows::Object> {
-                    ::windows::Param::Owned(::std::convert::Into::<::windows::Object>::into(::std::clone::Clone::clone(self)))
+                    ::windows::Param::Borrowed(&self.0)
+                }
+            }
+            impl<#constraints> ::std::convert::From<#name> for ::windows::IUnknown {
+                fn from(value: #name) -> Self {
+                    value.0.into()
+                }
+            }
+            impl<#constraints> ::std::convert::From<&#name> for ::windows::IUnknown {
+                fn from(value: &#name) -> Self {
+                    ::std::convert::From::from(::std::clone::Clone::clone(value))
+                }
+            }
+            impl<'a, #constraints> ::std::convert::Into<::windows::Param<'a, ::windows::IUnknown>> for #name {
+                fn into(self) -> ::windows::Param<'a, ::windows::IUnknown> {
+                    ::windows::Param::Owned(::std::convert::Into::<::windows::IUnknown>::into(self))
+                }
+            }
+            impl<'a, #constraints> ::std::convert::Into<::windows::Param<'a, ::windows::IUnknown>> for &'a #name {
+                fn into(self) -> ::windows::Param<'a, ::windows::IUnknown> {
+                    ::windows::Param::Borrowed(unsafe { ::std::mem::transmute(self) })
                 }
             }
             #(#conversions)*
@@ -257,3 +286,14 @@ mod tests {
         assert!(iterable.name.runtime_name() == "Windows.Foundation.Collections.IIterable`1<Windows.Foundation.Collections.IKeyValuePair`2<K, V>>");
     }
 }
+
+/// Generates an inherent `cast` method so that interfaces may be queried for other interfaces
+/// without importing the `Interface` trait. The type parameter is named so as not to collide with
+/// those of generic interfaces such as `IVector<T>`.
+pub fn gen_cast() -> TokenStream {
+    quote! {
+        pub fn cast<Target: ::windows::Interface>(&self) -> ::windows::Result<Target> {
+            ::windows::Interface::cast(self)
+        }
+    }
+}
@@ -2,6 +2,7 @@
 pub enum InterfaceKind {
     Default,
     NonDefault,
+    FastAbi,
     Overrides,
     Statics,
     Composable,
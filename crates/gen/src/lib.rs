@@ -1,19 +1,33 @@
+mod api_diff;
+mod background_task;
+mod bindings;
 mod callback;
 mod class;
 mod com_interface;
 mod constant;
+mod data_stream;
+mod deferral;
 mod delegate;
+mod deprecated;
+mod doc;
 mod r#enum;
+mod features;
+mod format;
 mod format_ident;
 mod function;
 mod futures;
+mod gen_options;
 mod hex_reader;
+mod idl;
 mod interface;
 mod interface_kind;
 mod iterator;
+mod manifest;
 mod method;
 mod method_kind;
 mod namespace;
+mod naming;
+mod provenance;
 mod required_interface;
 mod signature;
 mod r#struct;
@@ -27,21 +41,35 @@ mod type_namespaces;
 mod type_tree;
 pub mod winmd;
 
+pub use api_diff::*;
+pub use background_task::*;
+pub use bindings::*;
 pub use callback::*;
 pub use class::*;
 pub use com_interface::*;
 pub use constant::*;
+pub use data_stream::*;
+pub use deferral::*;
 pub use delegate::*;
+pub use deprecated::*;
+pub use doc::*;
+pub use features::*;
+pub use format::*;
 pub use format_ident::*;
 pub use function::*;
 pub use futures::*;
+pub use gen_options::*;
 pub use hex_reader::*;
+pub use idl::*;
 pub use interface::*;
 pub use interface_kind::*;
 pub use iterator::*;
+pub use manifest::*;
 pub use method::*;
 pub use method_kind::*;
 pub use namespace::*;
+pub use naming::*;
+pub use provenance::*;
 pub use r#enum::*;
 pub use r#struct::*;
 pub use r#type::*;
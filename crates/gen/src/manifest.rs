@@ -0,0 +1,159 @@
+use crate::*;
+
+/// The server that activates the classes registered by a package manifest fragment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ManifestServer<'a> {
+    /// A DLL loaded into the process activating the classes.
+    InProcess(&'a str),
+    /// An executable started to activate the classes, as with an `ActivationHost`.
+    OutOfProcess(&'a str),
+}
+
+/// Generates the `Extensions` element of a package manifest, whether for a packaged app or a
+/// sparse package, that registers the activatable classes in the given namespaces, and any
+/// namespaces nested within them, with the server that implements them.
+///
+/// All namespaces are included if none are given.
+pub fn gen_manifest(
+    reader: &'static winmd::TypeReader,
+    server: ManifestServer,
+    namespaces: &[&str],
+) -> String {
+    let mut classes: Vec<(String, &'static str)> = Vec::new();
+
+    for namespace in selected_namespaces(reader, namespaces) {
+        for t in reader.namespace_types(namespace) {
+            if let winmd::Type::TypeDef(def) = t {
+                if def.category() == winmd::TypeCategory::Class && is_activatable(&def) {
+                    let (namespace, name) = def.name();
+                    classes.push((format!("{}.{}", namespace, name), threading_model(&def)));
+                }
+            }
+        }
+    }
+
+    classes.sort();
+
+    let classes: Vec<(&str, &str)> = classes
+        .iter()
+        .map(|(class, threading)| (class.as_str(), *threading))
+        .collect();
+
+    let mut manifest = String::from("<Extensions>\n");
+
+    for line in gen_manifest_extension(server, &classes).lines() {
+        manifest.push_str(&format!("  {}\n", line));
+    }
+
+    manifest.push_str("</Extensions>\n");
+    manifest
+}
+
+/// Generates the `Extension` element of a package manifest that registers the given classes,
+/// each paired with its threading model, with the server that implements them. Threading models
+/// only apply to in-process servers.
+pub fn gen_manifest_extension(server: ManifestServer, classes: &[(&str, &str)]) -> String {
+    let mut manifest = String::new();
+
+    match server {
+        ManifestServer::InProcess(path) => {
+            manifest.push_str("<Extension Category=\"windows.activatableClass.inProcessServer\">\n  <InProcessServer>\n");
+            manifest.push_str(&format!("    <Path>{}</Path>\n", path));
+
+            for (class, threading) in classes {
+                manifest.push_str(&format!(
+                    "    <ActivatableClass ActivatableClassId=\"{}\" ThreadingModel=\"{}\" />\n",
+                    class, threading
+                ));
+            }
+
+            manifest.push_str("  </InProcessServer>\n");
+        }
+        ManifestServer::OutOfProcess(path) => {
+            let name = path
+                .rsplit(&['\\', '/'][..])
+                .next()
+                .unwrap_or(path)
+                .trim_end_matches(".exe");
+
+            manifest.push_str(&format!("<Extension Category=\"windows.activatableClass.outOfProcessServer\">\n  <OutOfProcessServer ServerName=\"{}\">\n", name));
+            manifest.push_str(&format!("    <Path>{}</Path>\n", path));
+            manifest.push_str("    <Instancing>singleInstance</Instancing>\n");
+
+            for (class, _) in classes {
+                manifest.push_str(&format!(
+                    "    <ActivatableClass ActivatableClassId=\"{}\" />\n",
+                    class
+                ));
+            }
+
+            manifest.push_str("  </OutOfProcessServer>\n");
+        }
+    }
+
+    manifest.push_str("</Extension>\n");
+    manifest
+}
+
+// A class is activatable if it has a constructor or statics of its own, which is also what
+// makes for an activation factory.
+fn is_activatable(def: &winmd::TypeDef) -> bool {
+    def.attributes().any(|attribute| {
+        matches!(
+            attribute.name(),
+            ("Windows.Foundation.Metadata", "ActivatableAttribute")
+                | ("Windows.Foundation.Metadata", "StaticAttribute")
+                | ("Windows.Foundation.Metadata", "ComposableAttribute")
+        )
+    })
+}
+
+fn threading_model(def: &winmd::TypeDef) -> &'static str {
+    for attribute in def.attributes() {
+        if attribute.name() == ("Windows.Foundation.Metadata", "ThreadingAttribute") {
+            // The only argument is a ThreadingModel enum.
+            match attribute.args().first() {
+                Some((_, winmd::AttributeArg::I32(1))) => return "STA",
+                Some((_, winmd::AttributeArg::I32(2))) => return "MTA",
+                _ => {}
+            }
+        }
+    }
+
+    "both"
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_manifest() {
+        let reader = winmd::TypeReader::get();
+
+        let manifest = gen_manifest(
+            reader,
+            ManifestServer::InProcess("Json.dll"),
+            &["Windows.Data.Json"],
+        );
+
+        assert!(manifest.starts_with("<Extensions>\n  <Extension Category=\"windows.activatableClass.inProcessServer\">\n    <InProcessServer>\n      <Path>Json.dll</Path>\n"));
+        assert!(manifest.contains("      <ActivatableClass ActivatableClassId=\"Windows.Data.Json.JsonArray\" ThreadingModel=\"both\" />\n"));
+        assert!(manifest.ends_with("    </InProcessServer>\n  </Extension>\n</Extensions>\n"));
+
+        // Interfaces and enums aren't activatable.
+        assert!(!manifest.contains("IJsonValue"));
+        assert!(!manifest.contains("JsonValueType"));
+
+        let manifest = gen_manifest(
+            reader,
+            ManifestServer::OutOfProcess("bin\\Json.exe"),
+            &["Windows.Data.Json"],
+        );
+
+        assert!(manifest.contains("<OutOfProcessServer ServerName=\"Json\">"));
+        assert!(manifest.contains(
+            "      <ActivatableClass ActivatableClassId=\"Windows.Data.Json.JsonObject\" />\n"
+        ));
+    }
+}
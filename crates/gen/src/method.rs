@@ -8,6 +8,7 @@ pub struct Method {
     pub vtable_offset: u32,
     pub overload: u32,
     pub signature: Signature,
+    pub is_noexcept: bool,
 }
 
 impl Method {
@@ -37,12 +38,15 @@ impl Method {
         };
 
         let signature = Signature::new(method, generics, calling_namespace);
+        let is_noexcept =
+            method.has_attribute(("Windows.Foundation.Metadata", "NoExceptionAttribute"));
 
         Method {
             name,
             signature,
             vtable_offset,
             overload: 1,
+            is_noexcept,
         }
     }
 
@@ -105,9 +109,44 @@ impl Method {
             &self.signature.params
         };
 
-        let constraints = gen_constraint(params);
-        let args = params.iter().map(|param| param_gen_abi_arg(param));
-        let params = gen_param2(params);
+        // When enabled, out parameters are returned alongside the return value rather than
+        // requiring the caller to provide them.
+        let tuple_outs: Vec<&Type> =
+            if GenOptions::get().tuple_returns && kind != InterfaceKind::Composable {
+                params
+                    .iter()
+                    .filter(|param| !param.is_input && !param.is_array)
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
+        // Parameters that fail to convert, such as values that can't be boxed, return their error
+        // from the call. Noexcept methods that return their value directly have no error to
+        // return, so they treat a failed conversion like any other unexpected failure and panic.
+        let fallible = !self.is_noexcept
+            || kind == InterfaceKind::Statics
+            || kind == InterfaceKind::Composable;
+
+        let args = params
+            .iter()
+            .map(|param| param_gen_abi_arg(param, fallible));
+
+        // ReceiveArray out parameters are received into an `Array` that is then moved into the
+        // caller's `Vec`, freeing the CoTaskMem allocation the callee returned.
+        let receive_arrays: Vec<&Type> = params
+            .iter()
+            .filter(|param| param.is_array && !param.is_input && param.by_ref)
+            .collect();
+
+        let params: Vec<Type> = params
+            .iter()
+            .filter(|param| !tuple_outs.contains(param))
+            .cloned()
+            .collect();
+
+        let constraints = gen_constraint(&params);
+        let params = gen_param2(&params);
 
         // The ABI obviously still has the two composable parameters. Here we just pass the default in and out
         // arguments to ensure the call succeeds in the non-aggregating case.
@@ -119,40 +158,143 @@ impl Method {
             TokenStream::new()
         };
 
+        // Optional values are returned boxed as `IReference<T>`, which is unboxed on the caller's
+        // behalf with a null reference returned as `None`.
+        let reference = self
+            .signature
+            .return_type
+            .as_ref()
+            .filter(|return_type| !return_type.is_array)
+            .and_then(|return_type| return_type.kind.reference_value());
+
         // TODO: move duplicate code to Type
-        let return_type_tokens = if let Some(return_type) = &self.signature.return_type {
-            param_gen_return(return_type)
+        let return_type_tokens = if let Some(value) = reference {
+            let value = value.gen();
+            quote! { ::std::option::Option<#value> }
+        } else if let Some(return_type) = &self.signature.return_type {
+            param_gen_return_vec(return_type)
         } else {
             quote! { () }
         };
 
         let vtable_offset = Literal::u32_unsuffixed(self.vtable_offset);
 
-        let vcall = if let Some(return_type) = &self.signature.return_type {
+        // Fast abi methods live beyond the end of the default interface's vtable so the
+        // function pointer is read directly from the vtable by offset.
+        let vfn = if kind == InterfaceKind::FastAbi {
+            let abi = self.gen_abi();
+            quote! {
+                (*(*(::windows::Abi::abi(this) as *const *const unsafe extern "system" fn #abi)).add(#vtable_offset))
+            }
+        } else {
+            quote! {
+                (::windows::Interface::vtable(this).#vtable_offset)
+            }
+        };
+
+        // Methods marked noexcept still return an HRESULT at the ABI but are guaranteed to succeed,
+        // so the value is returned directly rather than wrapped in a Result.
+        let vcall = if self.is_noexcept {
+            if let Some(return_type) = &self.signature.return_type {
+                let return_arg = param_gen_abi_return_arg(return_type);
+
+                if return_type.is_array {
+                    let array_tokens = param_gen_return(return_type);
+                    quote! {
+                        let mut result__: #array_tokens = ::std::mem::zeroed();
+                        #vfn(::windows::Abi::abi(this), #(#args)* #composable_args #return_arg);
+                        result__.into_vec()
+                    }
+                } else if reference.is_some() {
+                    let reference = param_gen_return(return_type);
+                    quote! {
+                        let mut result__: ::windows::RawPtr = ::std::mem::zeroed();
+                        #vfn(::windows::Abi::abi(this), #(#args)* #composable_args #return_arg);
+                        <::std::option::Option<#reference> as ::windows::Abi>::from_abi(result__).unwrap()
+                            .map(|reference| reference.value().unwrap())
+                    }
+                } else {
+                    quote! {
+                        let mut result__: <#return_type_tokens as ::windows::Abi>::Abi = ::std::mem::zeroed();
+                        #vfn(::windows::Abi::abi(this), #(#args)* #composable_args #return_arg);
+                        <#return_type_tokens as ::windows::Abi>::from_abi(result__).unwrap()
+                    }
+                }
+            } else {
+                quote! {
+                    #vfn(::windows::Abi::abi(this), #(#args)* #composable_args);
+                }
+            }
+        } else if let Some(return_type) = &self.signature.return_type {
             let return_arg = param_gen_abi_return_arg(return_type);
 
             if return_type.is_array {
+                let array_tokens = param_gen_return(return_type);
                 quote! {
-                    let mut result__: #return_type_tokens = ::std::mem::zeroed();
-                    (::windows::Interface::vtable(this).#vtable_offset)(::windows::Abi::abi(this), #(#args)* #composable_args #return_arg)
-                        .and_then(|| result__ )
+                    let mut result__: #array_tokens = ::std::mem::zeroed();
+                    #vfn(::windows::Abi::abi(this), #(#args)* #composable_args #return_arg)
+                        .and_then(|| result__.into_vec() )
+                }
+            } else if reference.is_some() {
+                let reference = param_gen_return(return_type);
+                quote! {
+                    let mut result__: ::windows::RawPtr = ::std::mem::zeroed();
+                        #vfn(::windows::Abi::abi(this), #(#args)* #composable_args #return_arg)
+                            .from_abi::<::std::option::Option<#reference>>(result__ )
+                            .and_then(|reference| reference.map(|reference| reference.value()).transpose())
                 }
             } else {
                 quote! {
                     let mut result__: <#return_type_tokens as ::windows::Abi>::Abi = ::std::mem::zeroed();
-                        (::windows::Interface::vtable(this).#vtable_offset)(::windows::Abi::abi(this), #(#args)* #composable_args #return_arg)
+                        #vfn(::windows::Abi::abi(this), #(#args)* #composable_args #return_arg)
                             .from_abi::<#return_type_tokens>(result__ )
                 }
             }
         } else {
             quote! {
-                (::windows::Interface::vtable(this).#vtable_offset)(::windows::Abi::abi(this), #(#args)* #composable_args).ok()
+                #vfn(::windows::Abi::abi(this), #(#args)* #composable_args).ok()
+            }
+        };
+
+        let vcall = if receive_arrays.is_empty() {
+            vcall
+        } else {
+            let names = receive_arrays.iter().map(|param| format_ident(&param.name));
+            let arrays: Vec<_> = receive_arrays
+                .iter()
+                .map(|param| param_gen_receive_array(param))
+                .collect();
+            let kinds = receive_arrays.iter().map(|param| param.kind.gen());
+
+            quote! {
+                #(let mut #arrays = ::windows::Array::<#kinds>::new();)*
+                let call__ = { #vcall };
+                #(*#names = #arrays.into_vec();)*
+                call__
             }
         };
 
+        let (return_type_tokens, vcall) = if tuple_outs.is_empty() {
+            (return_type_tokens, vcall)
+        } else {
+            gen_tuple_return(
+                &tuple_outs,
+                self.signature.return_type.is_some(),
+                return_type_tokens,
+                vcall,
+                self.is_noexcept,
+            )
+        };
+
+        let result_type = if self.is_noexcept {
+            return_type_tokens.clone()
+        } else {
+            quote! { ::windows::Result<#return_type_tokens> }
+        };
+
         match kind {
-            InterfaceKind::Default => quote! {
-                pub fn #method_name<#constraints>(&self, #params) -> ::windows::Result<#return_type_tokens> {
+            InterfaceKind::Default | InterfaceKind::FastAbi => quote! {
+                pub fn #method_name<#constraints>(&self, #params) -> #result_type {
                     let this = self;
                     unsafe {
                         #vcall
@@ -162,7 +304,7 @@ impl Method {
             InterfaceKind::NonDefault | InterfaceKind::Overrides => {
                 let interface = interface.gen();
                 quote! {
-                    pub fn #method_name<#constraints>(&self, #params) -> ::windows::Result<#return_type_tokens> {
+                    pub fn #method_name<#constraints>(&self, #params) -> #result_type {
                         let this = &::windows::Interface::cast::<#interface>(self).unwrap();
                         unsafe {
                             #vcall
@@ -172,6 +314,14 @@ impl Method {
             }
             InterfaceKind::Statics | InterfaceKind::Composable => {
                 let interface = interface.gen();
+
+                // Retrieving the factory may still fail even if the method itself cannot.
+                let vcall = if self.is_noexcept {
+                    quote! { ::std::result::Result::Ok({ #vcall }) }
+                } else {
+                    vcall
+                };
+
                 quote! {
                     pub fn #method_name<#constraints>(#params) -> ::windows::Result<#return_type_tokens> {
                         Self::#interface(|this| unsafe { #vcall })
@@ -181,6 +331,50 @@ impl Method {
         }
     }
 
+    /// Generates a doc comment linking to the method's documentation as a member of `owner`, the
+    /// class or interface that it's projected onto.
+    pub fn gen_doc(
+        &self,
+        owner: &TypeName,
+        interface: &TypeName,
+        kind: InterfaceKind,
+    ) -> TokenStream {
+        if kind == InterfaceKind::Composable || is_activation_factory(owner, interface) {
+            return gen_doc(&format!(
+                "Creates a new [`{}`]({}).",
+                owner.doc_name(),
+                owner.doc_url(Some("-ctor"))
+            ));
+        }
+
+        let name = self.signature.method.name();
+
+        // Accessors are documented as the property or event they belong to.
+        let (member, kind) = if !self.signature.method.flags().special() {
+            (name, "method")
+        } else if let Some(member) = name
+            .strip_prefix("get_")
+            .or_else(|| name.strip_prefix("put_"))
+        {
+            (member, "property")
+        } else if let Some(member) = name
+            .strip_prefix("add_")
+            .or_else(|| name.strip_prefix("remove_"))
+        {
+            (member, "event")
+        } else {
+            (name, "method")
+        };
+
+        gen_doc(&format!(
+            "The [`{}`]({}) {} of `{}`.",
+            member,
+            owner.doc_url(Some(member)),
+            kind,
+            owner.doc_name()
+        ))
+    }
+
     fn gen_name(&self) -> Ident {
         if self.overload > 1 {
             format_ident!("{}{}", &self.name, self.overload)
@@ -196,7 +390,7 @@ impl Method {
             .iter()
             .map(|param| param_gen_invoke_arg(param, relative));
 
-        match &self.signature.return_type {
+        let upcall = match &self.signature.return_type {
             Some(return_type) if return_type.is_array => {
                 let result = format_ident(&return_type.name);
                 let result_size = squote::format_ident!("array_size_{}", &return_type.name);
@@ -230,10 +424,90 @@ impl Method {
             None => quote! {
                 #inner(#(#invoke_args,)*).into()
             },
+        };
+
+        quote! {
+            ::windows::catch_panic(|| { #upcall })
         }
     }
 }
 
+fn gen_tuple_return(
+    outs: &[&Type],
+    has_return: bool,
+    return_type_tokens: TokenStream,
+    vcall: TokenStream,
+    is_noexcept: bool,
+) -> (TokenStream, TokenStream) {
+    let names: Vec<Ident> = outs.iter().map(|param| format_ident(&param.name)).collect();
+    let locals: Vec<Ident> = outs
+        .iter()
+        .map(|param| squote::format_ident!("{}__", &param.name))
+        .collect();
+    let types = outs.iter().map(|param| param_gen_out(param));
+
+    let mut tuple_types = Vec::new();
+    let mut tuple_values = Vec::new();
+
+    if has_return {
+        tuple_types.push(return_type_tokens);
+        tuple_values.push(quote! { result__ });
+    }
+
+    tuple_types.extend(outs.iter().map(|param| param_gen_out(param)));
+    tuple_values.extend(locals.iter().map(|local| quote! { #local }));
+
+    let (tuple_type, tuple_value) = if tuple_types.len() == 1 {
+        (tuple_types.remove(0), tuple_values.remove(0))
+    } else {
+        (
+            quote! { (#(#tuple_types),*) },
+            quote! { (#(#tuple_values),*) },
+        )
+    };
+
+    // The out parameters are zero-initialized locals that the original call writes through.
+    let result = if is_noexcept {
+        quote! { { #vcall } }
+    } else {
+        quote! { { #vcall }? }
+    };
+
+    let result = if has_return {
+        quote! { let result__ = #result; }
+    } else {
+        quote! { #result; }
+    };
+
+    let wrapped = if is_noexcept {
+        quote! { #tuple_value }
+    } else {
+        quote! { ::std::result::Result::Ok(#tuple_value) }
+    };
+
+    let vcall = quote! {
+        #(let mut #locals: #types = ::std::mem::zeroed(); let #names = &mut #locals;)*
+        #result
+        #wrapped
+    };
+
+    (tuple_type, vcall)
+}
+
+fn param_gen_out(t: &Type) -> TokenStream {
+    let tokens = t.kind.gen();
+
+    match t.kind {
+        TypeKind::Object | TypeKind::Class(_) | TypeKind::Interface(_) | TypeKind::Delegate(_) => {
+            quote! { ::std::option::Option<#tokens> }
+        }
+        TypeKind::Generic(_) => {
+            quote! { <#tokens as ::windows::RuntimeType>::DefaultType }
+        }
+        _ => tokens,
+    }
+}
+
 fn gen_param2(types: &[Type]) -> TokenStream {
     TokenStream::from_iter(
         types
@@ -283,7 +557,7 @@ fn param_gen(t: &Type, position: usize) -> TokenStream {
         if t.is_input {
             quote! { #name: &[<#tokens as ::windows::RuntimeType>::DefaultType], }
         } else if t.by_ref {
-            quote! { #name: &mut ::windows::Array<#tokens>, }
+            quote! { #name: &mut ::std::vec::Vec<<#tokens as ::windows::RuntimeType>::DefaultType>, }
         } else {
             quote! { #name: &mut [<#tokens as ::windows::RuntimeType>::DefaultType], }
         }
@@ -328,6 +602,17 @@ pub fn param_gen_return(t: &Type) -> TokenStream {
     }
 }
 
+// Arrays returned by the callee are moved into a `Vec` so that callers needn't deal with the
+// CoTaskMem allocation that backs the ABI representation.
+fn param_gen_return_vec(t: &Type) -> TokenStream {
+    if t.is_array {
+        let tokens = t.kind.gen();
+        quote! { ::std::vec::Vec<<#tokens as ::windows::RuntimeType>::DefaultType> }
+    } else {
+        param_gen_return(t)
+    }
+}
+
 fn gen_abi_wrap(t: &Type, kind_tokens: TokenStream) -> TokenStream {
     let name = format_ident(&t.name);
 
@@ -373,16 +658,23 @@ fn param_gen_abi_return_arg(t: &Type) -> TokenStream {
     }
 }
 
-fn param_gen_abi_arg(t: &Type) -> TokenStream {
+fn param_gen_abi_arg(t: &Type, fallible: bool) -> TokenStream {
     let name = format_ident(&t.name);
 
+    let into = if fallible {
+        quote! { #name.into().ok()? }
+    } else {
+        quote! { #name.into().ok().unwrap() }
+    };
+
     if t.is_array {
         if t.is_input {
             quote! { #name.len() as u32, ::std::mem::transmute(#name.as_ptr()), }
         } else if t.by_ref {
-            quote! { #name.set_abi_len(), #name.set_abi(), }
+            let array = param_gen_receive_array(t);
+            quote! { #array.set_abi_len(), #array.set_abi(), }
         } else {
-            quote! { #name.len() as u32, ::std::mem::transmute_copy(&#name), }
+            quote! { #name.len() as u32, ::std::mem::transmute(#name.as_mut_ptr()), }
         }
     } else if t.is_input {
         if t.kind.primitive() {
@@ -394,13 +686,13 @@ fn param_gen_abi_arg(t: &Type) -> TokenStream {
                 | TypeKind::Class(_)
                 | TypeKind::Interface(_)
                 | TypeKind::Delegate(_)
-                | TypeKind::Generic(_) => quote! { #name.into().abi(), },
+                | TypeKind::Generic(_) => quote! { #into.abi(), },
                 TypeKind::Enum(_) => quote! { #name, },
                 TypeKind::Guid | TypeKind::Struct(_) => {
                     if t.is_const {
-                        quote! { &#name.into().abi(), }
+                        quote! { &#into.abi(), }
                     } else {
-                        quote! { #name.into().abi(), }
+                        quote! { #into.abi(), }
                     }
                 }
                 _ => quote! { ::windows::Abi::abi(#name), },
@@ -413,6 +705,11 @@ fn param_gen_abi_arg(t: &Type) -> TokenStream {
     }
 }
 
+fn param_gen_receive_array(t: &Type) -> TokenStream {
+    let array = squote::format_ident!("array_{}__", &t.name);
+    quote! { #array }
+}
+
 fn param_gen_invoke_arg(t: &Type, relative: bool) -> TokenStream {
     let name = format_ident(&t.name);
 
@@ -422,16 +719,30 @@ fn param_gen_invoke_arg(t: &Type, relative: bool) -> TokenStream {
         t.kind.gen_full()
     };
 
-    // TODO: This compiles but doesn't property handle delegates with array parameters.
-    // https://github.com/microsoft/windows-rs/issues/212
-
     if t.is_array {
+        let name_size = squote::format_ident!("array_size_{}", &t.name);
+
+        // A null pointer is only valid for an empty array but slices may not be built from null.
         if t.is_input {
-            quote! { ::std::mem::transmute_copy(&#name) }
+            quote! {
+                if #name.is_null() {
+                    &[]
+                } else {
+                    ::std::slice::from_raw_parts(#name as *const <#kind as ::windows::RuntimeType>::DefaultType, #name_size as usize)
+                }
+            }
         } else if t.by_ref {
+            // TODO: This compiles but doesn't properly handle delegates with ReceiveArray parameters.
+            // https://github.com/microsoft/windows-rs/issues/212
             quote! { ::std::mem::transmute_copy(&#name) }
         } else {
-            quote! { ::std::mem::transmute_copy(&#name) }
+            quote! {
+                if #name.is_null() {
+                    &mut []
+                } else {
+                    ::std::slice::from_raw_parts_mut(#name as *mut <#kind as ::windows::RuntimeType>::DefaultType, #name_size as usize)
+                }
+            }
         }
     } else if t.is_input {
         if t.kind.primitive() {
@@ -483,6 +794,31 @@ mod tests {
         assert!(param.kind == TypeKind::String);
     }
 
+    #[test]
+    fn test_tuple_returns() {
+        let reader = &winmd::TypeReader::get();
+        let def = reader.expect_type_def(("TestComponent", "ITests"));
+        let name = TypeName::from_type_def(&def, "TestComponent");
+        let method = method(("TestComponent", "ITests"), "param1");
+
+        let tokens = method
+            .gen_method(&name, InterfaceKind::Default)
+            .into_string();
+        assert!(tokens.contains("b : & mut bool"));
+
+        GenOptions {
+            tuple_returns: true,
+            ..GenOptions::default()
+        }
+        .set();
+
+        let tokens = method
+            .gen_method(&name, InterfaceKind::Default)
+            .into_string();
+        assert!(!tokens.contains("b : & mut bool"));
+        assert!(tokens.contains(":: windows :: Result < ( bool , bool ) >"));
+    }
+
     #[test]
     fn test_map_changed() {
         let method = method(
@@ -541,4 +877,56 @@ mod tests {
 
         assert!(token.runtime_name() == "Windows.Foundation.EventRegistrationToken");
     }
+
+    #[test]
+    fn test_reference_return() {
+        let reader = &winmd::TypeReader::get();
+        let def = reader.expect_type_def(("Windows.ApplicationModel.Contacts", "IContactDate"));
+        let name = TypeName::from_type_def(&def, "Windows.ApplicationModel.Contacts");
+        let method = method(("Windows.ApplicationModel.Contacts", "IContactDate"), "day");
+
+        let return_type = method.signature.return_type.as_ref().unwrap();
+        assert!(return_type.kind.reference_value() == Some(&TypeKind::U32));
+
+        let tokens = method
+            .gen_method(&name, InterfaceKind::Default)
+            .into_string();
+        assert!(tokens.contains(":: windows :: Result < :: std :: option :: Option < u32 > >"));
+        assert!(tokens.contains("reference . value ( )"));
+    }
+
+    #[test]
+    fn test_noexcept() {
+        let reader = &winmd::TypeReader::get();
+
+        // The bundled metadata has no noexcept methods, so existing methods are marked as such.
+        let def = reader.expect_type_def(("Windows.ApplicationModel.Contacts", "IContactDate"));
+        let name = TypeName::from_type_def(&def, "Windows.ApplicationModel.Contacts");
+        let mut noexcept = method(
+            ("Windows.ApplicationModel.Contacts", "IContactDate"),
+            "set_day",
+        );
+        noexcept.is_noexcept = true;
+
+        let tokens = noexcept
+            .gen_method(&name, InterfaceKind::Default)
+            .into_string();
+        assert!(tokens.contains("value . into ( ) . ok ( ) . unwrap ( ) . abi ( )"));
+        assert!(!tokens.contains('?'));
+        assert!(!tokens.contains("Result"));
+
+        let def = reader.expect_type_def(("Windows.Foundation", "IUriRuntimeClass"));
+        let name = TypeName::from_type_def(&def, "Windows.Foundation");
+        let mut noexcept = method(("Windows.Foundation", "IUriRuntimeClass"), "combine_uri");
+        noexcept.is_noexcept = true;
+
+        let tokens = noexcept
+            .gen_method(&name, InterfaceKind::Default)
+            .into_string();
+        assert!(tokens.contains("-> Uri"));
+        assert!(tokens.contains("relative_uri . into ( ) . ok ( ) . unwrap ( ) . abi ( )"));
+        assert!(tokens.contains("from_abi ( result__ ) . unwrap ( )"));
+        assert!(!tokens.contains('?'));
+        assert!(!tokens.contains("Result"));
+    }
 }
@@ -0,0 +1,69 @@
+use crate::*;
+use std::collections::BTreeSet;
+
+/// Returns the Rust names of the fields of a struct, or of the parameters of a function or
+/// method, given their metadata names in declaration order.
+///
+/// Names are converted to snake case unless [`GenOptions::rename`] gives a name for
+/// `Owner.Name`, such as `CIECHROMA.Y`. Names that would still collide, like the `y` and `Y`
+/// fields of `CIECHROMA`, are numbered in declaration order so that each depends only on the
+/// names declared before it and regenerating doesn't change them. Keywords are escaped later by
+/// [`format_ident`].
+pub fn member_names<'a, I: IntoIterator<Item = &'a str>>(owner: &str, names: I) -> Vec<String> {
+    let options = GenOptions::get();
+    let mut unique = BTreeSet::new();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let name = options
+                .rename
+                .get(&format!("{}.{}", owner, name))
+                .cloned()
+                .unwrap_or_else(|| to_snake(name));
+
+            if unique.insert(name.clone()) {
+                return name;
+            }
+
+            (2..)
+                .map(|count| format!("{}{}", name, count))
+                .find(|name| unique.insert(name.clone()))
+                .unwrap()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_member_names() {
+        assert_eq!(
+            member_names("CIECHROMA", vec!["x", "y", "Y", "lGreen"]),
+            vec!["x", "y", "y2", "l_green"]
+        );
+
+        let mut options = GenOptions::default();
+        options
+            .rename
+            .insert("CIECHROMA.Y".to_string(), "luminance".to_string());
+        options.set();
+
+        assert_eq!(
+            member_names("CIECHROMA", vec!["x", "y", "Y"]),
+            vec!["x", "y", "luminance"]
+        );
+        assert_eq!(member_names("OTHER", vec!["y", "Y"]), vec!["y", "y2"]);
+
+        GenOptions::default().set();
+    }
+
+    #[test]
+    fn test_keywords() {
+        assert_eq!(format_ident("type").as_str(), "r#type");
+        assert_eq!(format_ident("super").as_str(), "super_");
+        assert_eq!(format_ident("Self").as_str(), "Self_");
+    }
+}
@@ -0,0 +1,26 @@
+use crate::*;
+use squote::{quote, TokenStream};
+
+/// Generates a `provenance` module recording the generator version and the metadata files that
+/// bindings were generated from, so that a binary's bindings can be traced back to their inputs.
+pub fn gen_provenance(reader: &'static winmd::TypeReader) -> TokenStream {
+    let version = env!("CARGO_PKG_VERSION");
+
+    let files = reader.files.iter().enumerate().map(|(index, file)| {
+        let name = reader.file_name(index);
+        let size = file.size();
+        let fingerprint = format!("{:016x}", file.fingerprint());
+
+        quote! { (#name, #size, #fingerprint), }
+    });
+
+    quote! {
+        #[doc = "The inputs that produced these bindings."]
+        pub mod provenance {
+            #[doc = "The version of the `windows_gen` crate that generated the bindings."]
+            pub const GENERATOR_VERSION: &str = #version;
+            #[doc = "The module name, size in bytes, and FNV-1a hash of each metadata file the bindings were generated from."]
+            pub const METADATA: &[(&str, usize, &str)] = &[#(#files)*];
+        }
+    }
+}
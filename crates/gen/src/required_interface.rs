@@ -87,12 +87,12 @@ impl RequiredInterface {
                     }
                     impl<'a, #constraints> ::std::convert::Into<::windows::Param<'a, #into>> for &'a #from {
                         fn into(self) -> ::windows::Param<'a, #into> {
-                            ::windows::Param::Owned(::std::convert::Into::<#into>::into(::std::clone::Clone::clone(self)))
+                            ::windows::Param::Borrowed(unsafe { ::std::mem::transmute(self) })
                         }
                     }
                 }
             }
-            InterfaceKind::NonDefault => {
+            InterfaceKind::NonDefault | InterfaceKind::FastAbi => {
                 let into = self.name.gen();
                 quote! {
                     impl<#constraints> ::std::convert::From<#from> for #into {
@@ -170,11 +170,17 @@ pub fn add_dependencies(
     }
 }
 
-pub fn gen_method(interfaces: &Vec<RequiredInterface>) -> TokenStream {
+/// Generates the methods of the interfaces, documented as members of the first of `owners` that
+/// implements the interface, or otherwise of the interface itself.
+pub fn gen_method(interfaces: &Vec<RequiredInterface>, owners: &[TypeName]) -> TokenStream {
     let mut tokens = TokenStream::new();
 
     for interface in interfaces {
+        let owner = interface_owner(interface, owners);
+
         for method in &interface.methods {
+            tokens.combine(&method.gen_doc(owner, &interface.name, interface.kind));
+            tokens.combine(&gen_deprecated(method.signature.method.attributes()));
             tokens.combine(&method.gen_method(&interface.name, interface.kind));
         }
     }
@@ -182,6 +188,29 @@ pub fn gen_method(interfaces: &Vec<RequiredInterface>) -> TokenStream {
     tokens
 }
 
+fn interface_owner<'a>(interface: &'a RequiredInterface, owners: &'a [TypeName]) -> &'a TypeName {
+    // Factory and statics interfaces aren't implemented by the class itself.
+    if matches!(
+        interface.kind,
+        InterfaceKind::Statics | InterfaceKind::Composable
+    ) {
+        return owners.first().unwrap_or(&interface.name);
+    }
+
+    owners
+        .iter()
+        .find(|owner| {
+            owner.def.interfaces().any(|required| {
+                TypeName::from_type_def_or_ref(
+                    &required.interface(),
+                    &owner.generics,
+                    owner.namespace,
+                ) == interface.name
+            })
+        })
+        .unwrap_or(&interface.name)
+}
+
 pub fn rename_collisions(interfaces: &mut Vec<RequiredInterface>) {
     // First sort interfaces to ensure a stable method renaming across versions.
     // TODO: Once fast abi support is added, sorting here will be unnecessary.
@@ -38,6 +38,17 @@ impl Signature {
             );
         }
 
+        let names = member_names(
+            method.name(),
+            param_types
+                .iter()
+                .map(|param| param.param.map_or("", |param| param.name())),
+        );
+
+        for (param, name) in param_types.iter_mut().zip(names) {
+            param.name = name;
+        }
+
         Self {
             method: *method,
             params: param_types,
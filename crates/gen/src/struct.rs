@@ -1,6 +1,5 @@
 use crate::*;
 use squote::{format_ident, quote, Literal, TokenStream};
-use std::collections::BTreeSet;
 
 #[derive(Debug)]
 pub struct Struct {
@@ -9,6 +8,7 @@ pub struct Struct {
     pub signature: String,
     pub is_typedef: bool,
     pub guid: TypeGuid,
+    pub packing: Option<usize>,
 }
 
 impl Struct {
@@ -22,7 +22,6 @@ impl Struct {
         };
 
         let mut fields = Vec::new();
-        let mut unique = BTreeSet::new();
 
         for field in name.def.fields() {
             if field.flags().literal() {
@@ -32,32 +31,25 @@ impl Struct {
             let mut t = Type::from_field(&field, &name.namespace);
 
             // TODO: workaround for https://github.com/microsoft/win32metadata/issues/132
+            // Callbacks that the headers declare as function types rather than function pointer
+            // types, like ICU's `UCharIteratorGetIndex`, are described as pointers to delegates,
+            // but the field holds the function pointer itself, which is an `Option` so that the
+            // zeroed default is `None` rather than an invalid function pointer.
             if let TypeKind::Delegate(_) = &t.kind {
                 t.pointers = 0;
             }
 
-            let mut field_name = to_snake(field.name());
-
-            // A handful of Win32 structs, like `CIECHROMA` and `GenTspecParms`, have fields whose snake case
-            // names are identical, so we handle this edge case by ensuring they get unique names.
-            if !unique.insert(field_name.clone()) {
-                let mut unique_count = 1;
-
-                loop {
-                    unique_count += 1;
-
-                    let unique_field_name = format!("{}{}", field_name, unique_count);
-
-                    if unique.insert(unique_field_name.clone()) {
-                        field_name = unique_field_name;
-                        break;
-                    }
-                }
-            }
-
-            fields.push((field_name, t));
+            fields.push((field.name(), t));
         }
 
+        // A handful of Win32 structs, like `CIECHROMA` and `GenTspecParms`, have fields whose
+        // snake case names are identical, which `member_names` numbers to keep them unique.
+        let names = member_names(name.name, fields.iter().map(|(field, _)| *field));
+        let mut fields: Vec<(String, Type)> = names
+            .into_iter()
+            .zip(fields.into_iter().map(|(_, t)| t))
+            .collect();
+
         let guid = TypeGuid::from_type_def(&name.def);
 
         // The C/C++ ABI assumes an empty struct occupies a single byte in memory.
@@ -82,12 +74,24 @@ impl Struct {
             .def
             .has_attribute(("Windows.Win32.Interop", "NativeTypedefAttribute"));
 
+        // Fields are packed more tightly than their natural alignment when the metadata says so,
+        // as with `#pragma pack` in the headers. The metadata describes the x86 headers, and
+        // several of them, such as commdlg.h and shellapi.h, only pack 32-bit builds, so the
+        // packing is only applied to 32-bit targets. Structs that are packed on every target,
+        // like `WAVEFORMATEX`, can't be told apart until the metadata records architecture.
+        let packing = name
+            .def
+            .class_layout()
+            .map(|layout| layout.packing_size() as usize)
+            .filter(|packing| *packing > 0);
+
         Self {
             name,
             fields,
             signature,
             is_typedef,
             guid,
+            packing,
         }
     }
 
@@ -95,9 +99,352 @@ impl Struct {
         self.fields
             .iter()
             .flat_map(|i| i.1.kind.dependencies())
+            .chain(
+                self.free_function()
+                    .map(|function| free_signature(self.name.namespace, &function).dependencies())
+                    .unwrap_or_default(),
+            )
             .collect()
     }
 
+    /// Returns the size and alignment of the struct for the given pointer width, or `None` if
+    /// the layout cannot be determined from metadata alone.
+    pub fn layout(&self, pointer_width: usize) -> Option<(usize, usize)> {
+        // GUID constants and explicit layouts that can't be generated as unions don't have a
+        // layout of their own.
+        if self.guid != TypeGuid::default()
+            || (self.name.def.flags().explicit() && !self.is_union())
+        {
+            return None;
+        }
+
+        let mut size = 0;
+        let mut align = 1;
+
+        for (_, t) in &self.fields {
+            let (field_size, mut field_align) = t.layout(pointer_width)?;
+
+            if let (Some(packing), 4) = (self.packing, pointer_width) {
+                field_align = field_align.min(packing);
+            }
+
+            // The fields of a union overlap rather than following one another.
+            if self.is_union() {
+                size = size.max(field_size);
+            } else {
+                size = round_up(size, field_align) + field_size;
+            }
+
+            align = align.max(field_align);
+        }
+
+        Some((round_up(size, align), align))
+    }
+
+    fn gen_layout_assertions(&self) -> TokenStream {
+        let name = self.name.gen();
+
+        let assert = |(size, align): (usize, usize)| {
+            let size = Literal::u32_unsuffixed(size as u32);
+            let align = Literal::u32_unsuffixed(align as u32);
+
+            quote! {
+                const _: () = assert!(
+                    ::std::mem::size_of::<#name>() == #size && ::std::mem::align_of::<#name>() == #align
+                );
+            }
+        };
+
+        match (self.layout(4), self.layout(8)) {
+            (Some(x86), Some(x64)) if x86 == x64 => assert(x64),
+            (Some(x86), Some(x64)) => {
+                let x86 = assert(x86);
+                let x64 = assert(x64);
+
+                quote! {
+                    #[cfg(target_pointer_width = "32")]
+                    #x86
+                    #[cfg(target_pointer_width = "64")]
+                    #x64
+                }
+            }
+            _ => TokenStream::new(),
+        }
+    }
+
+    /// Returns whether every field is blittable, in which case the struct serves as its own ABI
+    /// type rather than having a separate `_abi` struct. Unions always do.
+    pub fn is_blittable(&self) -> bool {
+        self.guid == TypeGuid::default()
+            && (self.is_union() || self.fields.iter().all(|(_, t)| t.is_blittable()))
+    }
+
+    /// Returns whether every field is `Copy`, in which case the struct is too.
+    pub fn is_copy(&self) -> bool {
+        self.guid == TypeGuid::default() && self.fields.iter().all(|(_, t)| t.is_copy())
+    }
+
+    /// Returns whether the struct has an explicit layout whose fields overlap and is generated
+    /// as a Rust union. Fields that aren't `Copy` are wrapped in `ManuallyDrop`.
+    pub fn is_union(&self) -> bool {
+        self.name.def.flags().explicit()
+    }
+
+    /// Returns whether the struct implements `PartialEq`. Unions don't, since only one of their
+    /// fields holds a value and the bytes past it may not be initialized, so neither do the
+    /// structs that hold them.
+    pub fn is_comparable(&self) -> bool {
+        !self.is_union() && self.fields.iter().all(|(_, t)| t.is_comparable())
+    }
+
+    /// Returns whether the struct implements `Clone`. Unions only do if they're `Copy`, since
+    /// cloning a field that holds a reference would have to know which field is set, and so
+    /// structs that hold other unions don't either.
+    pub fn is_cloneable(&self) -> bool {
+        if self.is_union() {
+            self.is_copy()
+        } else {
+            self.fields.iter().all(|(_, t)| t.is_cloneable())
+        }
+    }
+
+    /// Returns whether every field is hashable, in which case the struct derives `Hash`. Unions
+    /// don't, since only one of their fields holds a value, and neither do packed structs, since
+    /// derives can't borrow their fields.
+    pub fn is_hashable(&self) -> bool {
+        self.guid == TypeGuid::default()
+            && self.packing.is_none()
+            && !self.name.def.flags().explicit()
+            && self.fields.iter().all(|(_, t)| t.is_hashable())
+    }
+
+    /// Returns whether every field implements `serde::Serialize` and `serde::Deserialize`, which
+    /// leaves out unions and packed structs as well as structs holding pointers or references.
+    pub fn is_serializable(&self) -> bool {
+        self.guid == TypeGuid::default()
+            && self.packing.is_none()
+            && !self.name.def.flags().explicit()
+            && self.fields.iter().all(|(_, t)| t.is_serializable())
+    }
+
+    /// Returns whether the struct is plain data that implements `bytemuck::Pod` and
+    /// `bytemuck::Zeroable`, which requires every field to be plain data and leaves out unions,
+    /// packed structs, and structs with padding between or after their fields.
+    pub fn is_pod(&self) -> bool {
+        if self.guid != TypeGuid::default()
+            || self.packing.is_some()
+            || self.name.def.flags().explicit()
+            || !self.fields.iter().all(|(_, t)| t.is_pod())
+        {
+            return false;
+        }
+
+        [4, 8].iter().all(|pointer_width| {
+            let fields: Option<usize> = self
+                .fields
+                .iter()
+                .map(|(_, t)| t.layout(*pointer_width).map(|(size, _)| size))
+                .sum();
+
+            matches!((self.layout(*pointer_width), fields), (Some((size, _)), Some(fields)) if size == fields)
+        })
+    }
+
+    /// Returns whether the struct is a typedef for a handle, like `HWND` or `HANDLE`, rather than
+    /// for a value like `LPARAM`.
+    pub fn is_handle(&self) -> bool {
+        self.is_typedef
+            && !VALUE_TYPEDEFS.contains(&self.name.name)
+            && matches!(self.fields.as_slice(), [(_, t)] if t.kind == TypeKind::ISize && t.pointers == 0 && t.array.is_none())
+    }
+
+    /// Returns whether the struct is a typedef of an integer, such as `LPARAM` or `NTSTATUS`,
+    /// rather than a handle, so that its values may be ordered.
+    pub fn is_numeric(&self) -> bool {
+        self.is_typedef
+            && !self.is_handle()
+            && matches!(self.fields.as_slice(), [(_, t)] if t.kind.is_integer() && t.pointers == 0 && t.array.is_none())
+    }
+
+    // Typedefs convert to and from the value they wrap, and handles can be checked for the
+    // values that APIs return on failure.
+    fn gen_typedef(&self) -> TokenStream {
+        let kind = match self.fields.as_slice() {
+            [(_, kind)] if self.is_typedef => kind.gen_field(),
+            _ => return TokenStream::new(),
+        };
+
+        let name = self.name.gen();
+
+        let handle = if self.is_handle() {
+            quote! {
+                impl #name {
+                    /// Returns whether the handle is null.
+                    pub const fn is_null(&self) -> bool {
+                        self.0 == 0
+                    }
+
+                    /// Returns whether the handle is null or `INVALID_HANDLE_VALUE`, either of
+                    /// which APIs return in place of a handle when they fail.
+                    pub const fn is_invalid(&self) -> bool {
+                        self.0 == 0 || self.0 == -1
+                    }
+                }
+            }
+        } else {
+            TokenStream::new()
+        };
+
+        quote! {
+            impl ::std::convert::From<#kind> for #name {
+                fn from(value: #kind) -> Self {
+                    Self(value)
+                }
+            }
+            impl ::std::convert::From<#name> for #kind {
+                fn from(value: #name) -> Self {
+                    value.0
+                }
+            }
+            #handle
+        }
+    }
+
+    /// Returns the function the metadata names to free the handle, such as `CloseHandle`.
+    pub fn free_function(&self) -> Option<Function> {
+        if !self.is_handle() {
+            return None;
+        }
+
+        let name = self
+            .name
+            .def
+            .attributes()
+            .find(|attribute| attribute.name() == RAII_FREE)?
+            .args()
+            .into_iter()
+            .find_map(|(_, arg)| match arg {
+                winmd::AttributeArg::String(name) => Some(name),
+                _ => None,
+            })?;
+
+        let (def, method) = self.name.def.reader.find_function(&name)?;
+        let function = Function::new(TypeName::from_type_def(&def, def.name().0), &method);
+
+        if function.signature.params.len() == 1 {
+            Some(function)
+        } else {
+            None
+        }
+    }
+
+    // Handles implement `Free` by calling the function that frees them, so they can be wrapped
+    // in `windows::Owned`. The function is imported with the parameter and return types the
+    // metadata gives it, so that the import matches the function's own bindings if they're
+    // generated too.
+    fn gen_free(&self) -> TokenStream {
+        let function = match self.free_function() {
+            Some(function) => function,
+            None => return TokenStream::new(),
+        };
+
+        let signature = free_signature(self.name.namespace, &function);
+        let name = self.name.gen();
+        let free = format_ident(signature.method.name());
+        let link = function.link();
+        let param = signature.params[0].gen_param();
+
+        let return_type = match &signature.return_type {
+            Some(t) => {
+                let tokens = t.gen_field();
+                quote! { -> #tokens }
+            }
+            None => TokenStream::new(),
+        };
+
+        quote! {
+            impl ::windows::Free for #name {
+                unsafe fn free(&mut self) {
+                    if !self.is_invalid() {
+                        #[link(name = #link)]
+                        extern "system" {
+                            fn #free(handle: #param) #return_type;
+                        }
+
+                        #free(::std::mem::transmute(self.0));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the name of the field, by convention the first, that versioned Win32 structs
+    /// expect to hold the size of the struct, such as `cb_size` or `dw_size`.
+    pub fn size_field(&self) -> Option<&str> {
+        let (name, kind) = self.fields.first()?;
+
+        if !SIZE_FIELDS.contains(&name.as_str())
+            || !matches!(kind.kind, TypeKind::U32 | TypeKind::I32)
+            || kind.pointers != 0
+            || kind.array.is_some()
+        {
+            return None;
+        }
+
+        Some(name)
+    }
+
+    /// Returns whether the struct gets chained `with_` setters, which are generated for Win32
+    /// structs with a size field and those named by [`GenOptions::builders`].
+    pub fn has_builder(&self) -> bool {
+        if self.is_typedef || self.name.def.is_winrt() {
+            return false;
+        }
+
+        self.size_field().is_some()
+            || GenOptions::get()
+                .builders
+                .iter()
+                .any(|name| name == self.name.name)
+    }
+
+    // Setters that start from `default`, which fills in the size field, and return the struct so
+    // that they can be chained, such as `WNDCLASSEXW::default().with_style(...)`.
+    fn gen_builder(&self) -> TokenStream {
+        if !self.has_builder() {
+            return TokenStream::new();
+        }
+
+        let size_field = self.size_field();
+
+        let setters = self
+            .fields
+            .iter()
+            .filter(|(name, _)| size_field != Some(name.as_str()))
+            .map(|(name, kind)| {
+                let doc = gen_doc(&format!("Sets `{}`, returning the struct.", name));
+                let setter = format_ident!("with_{}", name);
+                let name = format_ident(name);
+                let kind = kind.gen_field();
+
+                quote! {
+                    #doc
+                    pub fn #setter(mut self, value: #kind) -> Self {
+                        self.#name = value;
+                        self
+                    }
+                }
+            });
+
+        let name = self.name.gen();
+
+        quote! {
+            impl #name {
+                #(#setters)*
+            }
+        }
+    }
+
     pub fn gen(&self) -> TokenStream {
         let name = self.name.gen();
 
@@ -109,8 +456,9 @@ impl Struct {
             };
         }
 
-        // TODO: if the struct is blittable then don't generate a separate abi type.
-        let abi_ident = format_ident!("{}_abi", self.name.name);
+        if self.is_union() {
+            return self.gen_union();
+        }
 
         let body = if self.is_typedef {
             let fields = self.fields.iter().map(|(_, kind)| {
@@ -149,9 +497,17 @@ impl Struct {
                 Self( #(#defaults),* )
             }
         } else {
+            let size_field = self.size_field();
+
             let defaults = self.fields.iter().map(|(name, kind)| {
+                let value = if size_field == Some(name.as_str()) {
+                    let kind = kind.gen_field();
+                    quote! { ::std::mem::size_of::<Self>() as #kind }
+                } else {
+                    kind.gen_default()
+                };
+
                 let name = format_ident(&name);
-                let value = kind.gen_default();
                 quote! {
                     #name: #value
                 }
@@ -165,7 +521,7 @@ impl Struct {
         let clones = if self.is_typedef {
             let clones = self.fields.iter().enumerate().map(|(index, (_, kind))| {
                 let index = Literal::u32_unsuffixed(index as u32);
-                let clone = kind.gen_clone(&quote! { #index });
+                let clone = self.gen_clone(kind, &quote! { #index });
                 quote! {
                     #clone
                 }
@@ -177,7 +533,7 @@ impl Struct {
         } else {
             let clones = self.fields.iter().map(|(name, kind)| {
                 let name = format_ident(&name);
-                let clone = kind.gen_clone(&quote! { #name });
+                let clone = self.gen_clone(kind, &quote! { #name });
                 quote! {
                     #name: #clone
                 }
@@ -188,6 +544,18 @@ impl Struct {
             }
         };
 
+        let clone = if self.is_cloneable() {
+            quote! {
+                impl ::std::clone::Clone for #name {
+                    fn clone(&self) -> Self {
+                        #clones
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+
         let constants = self.name.def.fields().filter_map(|field| {
             if field.flags().literal() {
                 if let Some(constant) = field.constant() {
@@ -214,45 +582,44 @@ impl Struct {
                     }
                 }
 
-                if self.is_typedef {
+                let value = if self.is_typedef {
                     let index = Literal::u32_unsuffixed(index as u32);
-
-                    Some(quote! {
-                        .field(#name, &format_args!("{:?}", self.#index))
-                    })
+                    self.gen_field_value(&quote! { self }, &quote! { #index })
                 } else {
                     let name_ident = format_ident(&name);
+                    self.gen_field_value(&quote! { self }, &quote! { #name_ident })
+                };
 
-                    Some(quote! {
-                        .field(#name, &format_args!("{:?}", self.#name_ident))
-                    })
-                }
+                Some(quote! {
+                    .field(#name, &format_args!("{:?}", #value))
+                })
             });
 
         let compare_fields = if self.fields.is_empty() {
             quote! { true }
         } else {
             let fields = self.fields.iter().enumerate().map(|(index, (name, t))| {
-                let name_ident = format_ident(&name);
+                let field = if self.is_typedef {
+                    let index = Literal::u32_unsuffixed(index as u32);
+                    quote! { #index }
+                } else {
+                    let name_ident = format_ident(&name);
+                    quote! { #name_ident }
+                };
+
+                let value = self.gen_field_value(&quote! { self }, &field);
+                let other = self.gen_field_value(&quote! { other }, &field);
 
                 if let TypeKind::Delegate(name) = &t.kind {
                     if !name.def.is_winrt() {
                         return quote! {
-                            self.#name_ident.map(|f| f as usize) == other.#name_ident.map(|f| f as usize)
+                            #value.map(|f| f as usize) == #other.map(|f| f as usize)
                         };
                     }
                 }
 
-                if self.is_typedef {
-                    let index = Literal::u32_unsuffixed(index as u32);
-
-                    quote! {
-                        self.#index == other.#index
-                    }
-                } else {
-                    quote! {
-                        self.#name_ident == other.#name_ident
-                    }
+                quote! {
+                    #value == #other
                 }
             });
 
@@ -261,7 +628,40 @@ impl Struct {
             }
         };
 
-        let abi = self.fields.iter().map(|field| field.1.gen_abi());
+        let compare = if self.is_comparable() {
+            quote! {
+                impl ::std::cmp::PartialEq for #name {
+                    fn eq(&self, other: &Self) -> bool {
+                        #compare_fields
+                    }
+                }
+                impl ::std::cmp::Eq for #name {}
+            }
+        } else {
+            quote! {}
+        };
+
+        let repr = self.gen_repr();
+
+        let abi = if self.is_blittable() {
+            quote! {
+                unsafe impl ::windows::Abi for #name {
+                    type Abi = Self;
+                }
+            }
+        } else {
+            let abi_ident = format_ident!("{}_abi", self.name.name);
+            let abi = self.fields.iter().map(|field| field.1.gen_abi());
+
+            quote! {
+                #repr
+                #[doc(hidden)]
+                pub struct #abi_ident(#(#abi),*);
+                unsafe impl ::windows::Abi for #name {
+                    type Abi = #abi_ident;
+                }
+            }
+        };
 
         let runtime_type = if self.signature.is_empty() {
             TokenStream::new()
@@ -276,7 +676,7 @@ impl Struct {
             }
         };
 
-        let copy = if self.is_typedef {
+        let copy = if self.is_typedef || self.is_copy() {
             quote! {
                 impl ::std::marker::Copy for #name {}
             }
@@ -284,21 +684,69 @@ impl Struct {
             quote! {}
         };
 
+        let hash = if self.is_hashable() {
+            quote! { #[derive(Hash)] }
+        } else {
+            quote! {}
+        };
+
+        let ord = if self.is_numeric() {
+            quote! { #[derive(PartialOrd, Ord)] }
+        } else {
+            quote! {}
+        };
+
+        let serde = if GenOptions::get().serde && self.is_serializable() {
+            quote! { #[derive(::serde::Serialize, ::serde::Deserialize)] }
+        } else {
+            quote! {}
+        };
+
+        // Plain data may be viewed as bytes, such as to upload vertices or write to a pipe.
+        let bytemuck = if GenOptions::get().bytemuck && self.is_pod() {
+            quote! {
+                unsafe impl ::bytemuck::Zeroable for #name {}
+                unsafe impl ::bytemuck::Pod for #name {}
+            }
+        } else {
+            quote! {}
+        };
+
         let debug_name = self.name.name;
 
+        // Handles are printed in hex, as debuggers show them.
+        let debug = if self.is_handle() {
+            quote! {
+                write!(fmt, "{}({:#x})", #debug_name, self.0)
+            }
+        } else {
+            quote! {
+                fmt.debug_struct(#debug_name)
+                    #(#debug_fields)*
+                    .finish()
+            }
+        };
+
+        let typedef = self.gen_typedef();
+        let free = self.gen_free();
+        let builder = self.gen_builder();
+        let layout = self.gen_layout_assertions();
+        let doc = self.name.gen_doc("struct");
+        let deprecated = gen_deprecated(self.name.def.attributes());
+
         quote! {
-            #[repr(C)]
+            #doc
+            #deprecated
+            #repr
             #[allow(non_snake_case)]
+            #hash
+            #ord
+            #serde
             pub struct #name #body
             impl #name {
                 #(#constants)*
             }
-            #[repr(C)]
-            #[doc(hidden)]
-            pub struct #abi_ident(#(#abi),*);
-            unsafe impl ::windows::Abi for #name {
-                type Abi = #abi_ident;
-            }
+            #abi
             impl ::std::default::Default for #name {
                 fn default() -> Self {
                     #defaults
@@ -306,24 +754,534 @@ impl Struct {
             }
             impl ::std::fmt::Debug for #name {
                 fn fmt(&self, fmt: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-                    fmt.debug_struct(#debug_name)
-                        #(#debug_fields)*
-                        .finish()
+                    #debug
                 }
             }
-            impl ::std::clone::Clone for #name {
-                fn clone(&self) -> Self {
-                    #clones
+            #clone
+            #compare
+            #copy
+            #bytemuck
+            #typedef
+            #free
+            #builder
+            #runtime_type
+            #layout
+        }
+    }
+
+    // There's no telling which field of a union holds a value, and the bytes past that field may
+    // not be initialized, so unions aren't compared and are printed without their fields. They
+    // default to all zeroes, which also zeroes the largest field. For the same reason fields
+    // holding references are wrapped in `ManuallyDrop`, leaving it to the caller to drop
+    // whichever one is set, and such unions are cloned bit for bit rather than being `Copy`.
+    fn gen_union(&self) -> TokenStream {
+        let name = self.name.gen();
+        let debug_name = self.name.name;
+        let is_copy = self.is_copy();
+
+        let fields = self.fields.iter().map(|(name, t)| {
+            let name = format_ident(&name);
+            let kind = t.gen_field();
+
+            if t.is_copy() {
+                quote! {
+                    pub #name: #kind
+                }
+            } else {
+                quote! {
+                    pub #name: ::std::mem::ManuallyDrop<#kind>
                 }
             }
-            impl ::std::cmp::PartialEq for #name {
-                fn eq(&self, other: &Self) -> bool {
-                    #compare_fields
+        });
+
+        let repr = self.gen_repr();
+        let layout = self.gen_layout_assertions();
+        let deprecated = gen_deprecated(self.name.def.attributes());
+
+        let doc = self.name.gen_doc("union");
+
+        let references = if is_copy {
+            TokenStream::new()
+        } else {
+            quote! {
+                #[doc = ""]
+                #[doc = "The fields that hold references are wrapped in `ManuallyDrop`, since the union can't tell which field is set. Read them through `Deref`, as in `&*value.field`, and release the one that's set with `ManuallyDrop::drop` or `ManuallyDrop::into_inner` when the value is no longer needed. They aren't `Clone`, since a clone would have to add a reference to whichever field is set."]
+            }
+        };
+
+        let clone = if is_copy {
+            quote! {
+                impl ::std::clone::Clone for #name {
+                    fn clone(&self) -> Self {
+                        *self
+                    }
                 }
+                impl ::std::marker::Copy for #name {}
             }
-            impl ::std::cmp::Eq for #name {}
-            #copy
-            #runtime_type
+        } else {
+            TokenStream::new()
+        };
+
+        quote! {
+            #doc
+            #references
+            #deprecated
+            #repr
+            #[allow(non_snake_case)]
+            pub union #name { #(#fields),* }
+            unsafe impl ::windows::Abi for #name {
+                type Abi = Self;
+            }
+            impl ::std::default::Default for #name {
+                fn default() -> Self {
+                    unsafe { ::std::mem::zeroed() }
+                }
+            }
+            impl ::std::fmt::Debug for #name {
+                fn fmt(&self, fmt: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    fmt.debug_struct(#debug_name).finish()
+                }
+            }
+            #clone
+            #layout
+        }
+    }
+
+    fn gen_repr(&self) -> TokenStream {
+        match self.packing {
+            Some(packing) => {
+                let packing = Literal::u32_unsuffixed(packing as u32);
+                quote! {
+                    #[repr(C)]
+                    #[cfg_attr(target_pointer_width = "32", repr(packed(#packing)))]
+                }
+            }
+            None => quote! { #[repr(C)] },
+        }
+    }
+
+    // Fields of packed structs may not be aligned, so they're copied out rather than borrowed.
+    // The copy is never dropped, leaving the field itself to own any references it holds.
+    fn gen_field_value(&self, this: &TokenStream, field: &TokenStream) -> TokenStream {
+        if self.packing.is_some() {
+            quote! {
+                (*::std::mem::ManuallyDrop::new(unsafe { ::std::ptr::read_unaligned(::std::ptr::addr_of!(#this.#field)) }))
+            }
+        } else {
+            quote! { #this.#field }
         }
     }
+
+    fn gen_clone(&self, t: &Type, field: &TokenStream) -> TokenStream {
+        if self.packing.is_some() {
+            let kind = t.gen_field();
+            let value = self.gen_field_value(&quote! { self }, field);
+
+            quote! {
+                <#kind as std::clone::Clone>::clone(&#value)
+            }
+        } else {
+            t.gen_clone(field)
+        }
+    }
+}
+
+// The names Win32 gives to a struct's size field, as they appear once converted to snake case.
+const SIZE_FIELDS: &[&str] = &[
+    "cb_size",
+    "dw_size",
+    "cb_struct",
+    "n_length",
+    "l_struct_size",
+    "cb",
+];
+
+// Pointer-sized typedefs that hold values rather than handles.
+const VALUE_TYPEDEFS: &[&str] = &["LPARAM", "WPARAM"];
+
+const RAII_FREE: (&str, &str) = ("Windows.Win32.Interop", "RAIIFreeAttribute");
+
+// The signature of the function that frees a handle, relative to the handle's namespace.
+fn free_signature(namespace: &'static str, function: &Function) -> Signature {
+    Signature::new(&function.signature.method, &[], namespace)
+}
+
+fn round_up(value: usize, align: usize) -> usize {
+    (value + align - 1) & !(align - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn layout(
+        (namespace, type_name): (&str, &str),
+        pointer_width: usize,
+    ) -> Option<(usize, usize)> {
+        let reader = &winmd::TypeReader::get();
+        let def = reader.expect_type_def((namespace, type_name));
+
+        match TypeDefinition::from_type_def(&def) {
+            TypeDefinition::Struct(t) => t.layout(pointer_width),
+            _ => panic!("TypeDefinition not a struct"),
+        }
+    }
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(layout(("Windows.Foundation", "Point"), 8), Some((8, 4)));
+        assert_eq!(layout(("Windows.Foundation", "DateTime"), 8), Some((8, 8)));
+
+        assert_eq!(
+            layout(("Windows.Win32.DisplayDevices", "RECT"), 4),
+            Some((16, 4))
+        );
+        assert_eq!(
+            layout(("Windows.Win32.WindowsAndMessaging", "CHOOSECOLORW"), 4),
+            Some((36, 1))
+        );
+        assert_eq!(
+            layout(("Windows.Win32.WindowsAndMessaging", "CHOOSECOLORW"), 8),
+            Some((72, 8))
+        );
+    }
+
+    #[test]
+    fn test_arrays() {
+        let reader = &winmd::TypeReader::get();
+        let def = reader.expect_type_def(("Windows.Win32.Dxgi", "DXGI_ADAPTER_DESC1"));
+        let tokens = TypeDefinition::from_type_def(&def).gen().into_string();
+
+        // Arrays longer than 32 elements, which the standard library doesn't implement `Default`
+        // for, are filled with the element's default instead.
+        assert!(tokens.contains("pub description : [ u16 ;128 ] ,"));
+        assert!(tokens.contains("description : [ 0 ;128 ] ,"));
+        assert!(tokens.contains("self . description == other . description"));
+        assert_eq!(
+            layout(("Windows.Win32.Dxgi", "DXGI_ADAPTER_DESC1"), 8),
+            Some((312, 8))
+        );
+
+        assert_eq!(
+            layout(("Windows.Win32.Shell", "LOGFONTW"), 8),
+            Some((92, 4))
+        );
+    }
+
+    #[test]
+    fn test_packing() {
+        let tokens = |name| {
+            let reader = &winmd::TypeReader::get();
+            let def = reader.expect_type_def(name);
+            TypeDefinition::from_type_def(&def).gen().into_string()
+        };
+
+        let packed = "# [ repr ( C ) ] # [ cfg_attr ( target_pointer_width = \"32\" , repr ( packed ( 1 ) ) ) ]";
+
+        let tokens = tokens(("Windows.Win32.Multimedia", "WAVEFORMATEX"));
+        assert!(tokens.contains(packed));
+        assert!(tokens.contains(":: std :: ptr :: read_unaligned"));
+        assert!(!tokens.contains("derive ( Hash )"));
+
+        assert_eq!(
+            layout(("Windows.Win32.Multimedia", "WAVEFORMATEX"), 4),
+            Some((18, 1))
+        );
+
+        // Natural alignment still applies to 64-bit targets.
+        assert_eq!(
+            layout(("Windows.Win32.Multimedia", "WAVEFORMATEX"), 8),
+            Some((20, 4))
+        );
+    }
+
+    #[test]
+    fn test_handle() {
+        let tokens = |name| {
+            let reader = &winmd::TypeReader::get();
+            let def = reader.expect_type_def(name);
+            TypeDefinition::from_type_def(&def).gen().into_string()
+        };
+
+        let hwnd = tokens(("Windows.Win32.WindowsAndMessaging", "HWND"));
+        assert!(hwnd.contains("impl :: std :: convert :: From < isize > for HWND"));
+        assert!(hwnd.contains("pub const fn is_invalid ( & self ) -> bool"));
+        assert!(hwnd.contains("{:#x}"));
+
+        // Values convert like handles but aren't checked or printed as handles.
+        let lparam = tokens(("Windows.Win32.WindowsAndMessaging", "LPARAM"));
+        assert!(lparam.contains("impl :: std :: convert :: From < LPARAM > for isize"));
+        assert!(lparam.contains("# [ derive ( PartialOrd , Ord ) ]"));
+        assert!(!lparam.contains("is_invalid"));
+        assert!(!lparam.contains("{:#x}"));
+
+        // Handles aren't ordered.
+        assert!(!hwnd.contains("PartialOrd"));
+    }
+
+    #[test]
+    fn test_free() {
+        let tokens = |name| {
+            let reader = &winmd::TypeReader::get();
+            let def = reader.expect_type_def(name);
+            TypeDefinition::from_type_def(&def).gen().into_string()
+        };
+
+        let hkey = tokens(("Windows.Win32.WindowsProgramming", "HKEY"));
+        assert!(hkey.contains("impl :: windows :: Free for HKEY"));
+        assert!(hkey
+            .contains("fn RegCloseKey ( handle : HKEY ) -> super :: system_services :: LSTATUS ;"));
+
+        // `FindClose` is declared to take a `HANDLE` but frees a `FindFileHandle`, so the handle
+        // is converted to the type the function takes.
+        let find = tokens(("Windows.Win32.FileSystem", "FindFileHandle"));
+        assert!(find.contains(
+            "fn FindClose ( handle : super :: system_services :: HANDLE ) -> :: windows :: BOOL ;"
+        ));
+        assert!(find.contains("FindClose ( :: std :: mem :: transmute ( self . 0 ) )"));
+
+        // The metadata doesn't name a function to free an `HWND`.
+        let hwnd = tokens(("Windows.Win32.WindowsAndMessaging", "HWND"));
+        assert!(!hwnd.contains("Free"));
+    }
+
+    #[test]
+    fn test_builder() {
+        let tokens = |name| {
+            let reader = &winmd::TypeReader::get();
+            let def = reader.expect_type_def(name);
+            TypeDefinition::from_type_def(&def).gen().into_string()
+        };
+
+        let class = tokens(("Windows.Win32.WindowsAndMessaging", "WNDCLASSEXW"));
+        assert!(class.contains("pub fn with_style ( mut self , value : u32 ) -> Self"));
+        assert!(!class.contains("with_cb_size"));
+
+        let desc = ("Windows.Win32.Dxgi", "DXGI_SWAP_CHAIN_DESC1");
+        assert!(!tokens(desc).contains("with_width"));
+
+        GenOptions {
+            builders: vec!["DXGI_SWAP_CHAIN_DESC1".to_string()],
+            ..GenOptions::default()
+        }
+        .set();
+
+        assert!(tokens(desc).contains("pub fn with_width ( mut self , value : u32 ) -> Self"));
+
+        GenOptions::default().set();
+    }
+
+    #[test]
+    fn test_union() {
+        let reader = &winmd::TypeReader::get();
+
+        let union = |name| {
+            let def = reader.expect_type_def(name);
+            match TypeDefinition::from_type_def(&def) {
+                TypeDefinition::Struct(t) => t,
+                _ => panic!("TypeDefinition not a struct"),
+            }
+        };
+
+        let value = union((
+            "Windows.Win32.BackgroundIntelligentTransferService",
+            "BITS_JOB_PROPERTY_VALUE",
+        ));
+
+        assert!(value.is_union());
+        assert!(value.is_blittable());
+        assert_eq!(value.layout(4), Some((16, 8)));
+        assert_eq!(value.layout(8), Some((16, 8)));
+
+        let tokens = value.gen().into_string();
+        assert!(tokens.contains(
+            "pub union BITS_JOB_PROPERTY_VALUE { pub dword : u32 , pub cls_id : :: windows :: Guid"
+        ));
+        assert!(!tokens.contains("PartialEq"));
+        assert!(tokens.contains(":: std :: mem :: zeroed ( )"));
+
+        // Structs holding unions are still structs.
+        let credentials = union((
+            "Windows.Win32.BackgroundIntelligentTransferService",
+            "BG_AUTH_CREDENTIALS",
+        ));
+
+        assert!(!credentials.is_union());
+        assert!(credentials.is_copy());
+        assert!(!credentials.is_comparable());
+        assert_eq!(credentials.layout(8), Some((24, 8)));
+
+        // Unions of references wrap those fields in `ManuallyDrop` and aren't `Copy` or `Clone`,
+        // and neither are the structs holding them.
+        let variant = union((
+            "Windows.Win32.StructuredStorage",
+            "PROPVARIANT_Anonymous_Anonymous_Anonymous",
+        ));
+        assert!(variant.is_union());
+        assert!(!variant.is_copy());
+        assert_eq!(variant.layout(8), Some((16, 8)));
+
+        let tokens = variant.gen().into_string();
+        assert!(tokens.contains("pub c_val : i8 ,"));
+        assert!(tokens.contains(
+            "pub punk_val : :: std :: mem :: ManuallyDrop < :: std :: option :: Option < :: windows :: IUnknown > >"
+        ));
+        assert!(!tokens.contains(":: std :: clone :: Clone"));
+        assert!(!tokens.contains(":: std :: marker :: Copy"));
+        assert!(!variant.is_cloneable());
+
+        let propvariant = union(("Windows.Win32.StructuredStorage", "PROPVARIANT"));
+        assert_eq!(propvariant.layout(8), Some((24, 8)));
+        assert!(!propvariant.is_cloneable());
+        assert!(!propvariant
+            .gen()
+            .into_string()
+            .contains(":: std :: clone :: Clone"));
+    }
+
+    fn is_blittable((namespace, type_name): (&str, &str)) -> bool {
+        let reader = &winmd::TypeReader::get();
+        let def = reader.expect_type_def((namespace, type_name));
+
+        match TypeDefinition::from_type_def(&def) {
+            TypeDefinition::Struct(t) => t.is_blittable(),
+            _ => panic!("TypeDefinition not a struct"),
+        }
+    }
+
+    #[test]
+    fn test_blittable() {
+        assert!(is_blittable(("Windows.Foundation", "Point")));
+        assert!(is_blittable(("Windows.Win32.DisplayDevices", "RECT")));
+
+        // Nested structs are blittable if their own fields are.
+        assert!(is_blittable(("Windows.Win32.WindowsAndMessaging", "MSG")));
+
+        // References are not, since their ABI type is a raw pointer.
+        assert!(!is_blittable(("Windows.Web.Http", "HttpProgress")));
+        assert!(!is_blittable((
+            "Windows.Win32.WindowsAndMessaging",
+            "CHOOSECOLORW"
+        )));
+    }
+
+    fn is_hashable((namespace, type_name): (&str, &str)) -> bool {
+        let reader = &winmd::TypeReader::get();
+        let def = reader.expect_type_def((namespace, type_name));
+
+        match TypeDefinition::from_type_def(&def) {
+            TypeDefinition::Struct(t) => t.is_hashable(),
+            _ => panic!("TypeDefinition not a struct"),
+        }
+    }
+
+    #[test]
+    fn test_serde() {
+        GenOptions {
+            serde: true,
+            ..GenOptions::default()
+        }
+        .set();
+
+        let tokens = |name| {
+            let reader = &winmd::TypeReader::get();
+            let def = reader.expect_type_def(name);
+            TypeDefinition::from_type_def(&def).gen().into_string()
+        };
+
+        let derive = "# [ derive ( :: serde :: Serialize , :: serde :: Deserialize ) ]";
+        assert!(tokens(("Windows.Win32.DisplayDevices", "RECT")).contains(derive));
+        assert!(tokens(("Windows.Foundation", "Point")).contains(derive));
+        assert!(
+            tokens(("Windows.Win32.DisplayDevices", "DISPLAYCONFIG_SOURCE_MODE")).contains(derive)
+        );
+        assert!(tokens(("Windows.Foundation", "AsyncStatus")).contains(derive));
+
+        // Pointers and unions can't be serialized.
+        assert!(!tokens(("Windows.Win32.WindowsAndMessaging", "CHOOSECOLORW")).contains(derive));
+        assert!(
+            !tokens(("Windows.Win32.DisplayDevices", "DISPLAYCONFIG_PATH_INFO")).contains(derive)
+        );
+
+        GenOptions::default().set();
+        assert!(!tokens(("Windows.Win32.DisplayDevices", "RECT")).contains(derive));
+    }
+
+    #[test]
+    fn test_pod() {
+        let is_pod = |name| {
+            let reader = &winmd::TypeReader::get();
+            let def = reader.expect_type_def(name);
+            Struct::from_type_name(TypeName::from_type_def(&def, name.0)).is_pod()
+        };
+
+        assert!(is_pod(("Windows.Win32.DisplayDevices", "RECT")));
+        assert!(is_pod(("Windows.Foundation", "Point")));
+        assert!(is_pod((
+            "Windows.Win32.DisplayDevices",
+            "DISPLAYCONFIG_SOURCE_MODE"
+        )));
+
+        // Pointers, padding, packing, and unions aren't plain data.
+        assert!(!is_pod((
+            "Windows.Win32.WindowsAndMessaging",
+            "CHOOSECOLORW"
+        )));
+        assert!(!is_pod(("Windows.Win32.WindowsAndMessaging", "MSG")));
+        assert!(!is_pod(("Windows.Win32.Multimedia", "WAVEFORMATEX")));
+        assert!(!is_pod((
+            "Windows.Win32.BackgroundIntelligentTransferService",
+            "BITS_JOB_PROPERTY_VALUE"
+        )));
+
+        GenOptions {
+            bytemuck: true,
+            ..GenOptions::default()
+        }
+        .set();
+
+        let reader = &winmd::TypeReader::get();
+        let def = reader.expect_type_def(("Windows.Win32.DisplayDevices", "RECT"));
+        let tokens = TypeDefinition::from_type_def(&def).gen().into_string();
+        assert!(tokens.contains("unsafe impl :: bytemuck :: Pod for RECT { }"));
+
+        GenOptions::default().set();
+    }
+
+    #[test]
+    fn test_hashable() {
+        assert!(is_hashable(("Windows.Win32.DisplayDevices", "RECT")));
+        assert!(is_hashable(("Windows.Win32.WindowsAndMessaging", "MSG")));
+        assert!(is_hashable(("Windows.Foundation", "DateTime")));
+
+        // Floating point values don't implement `Hash`.
+        assert!(!is_hashable(("Windows.Foundation", "Point")));
+    }
+
+    #[test]
+    fn test_nested() {
+        let reader = &winmd::TypeReader::get();
+        let def = reader.expect_type_def(("Windows.Win32.KeyboardAndMouseInput", "INPUT"));
+
+        let tokens = TypeDefinition::from_type_def(&def).gen().into_string();
+        assert!(tokens.contains("pub anonymous : INPUT_Anonymous }"));
+
+        // Nested types are named after the fields that hold them and live alongside the type
+        // that encloses them.
+        let def =
+            reader.expect_type_def(("Windows.Win32.KeyboardAndMouseInput", "INPUT_Anonymous"));
+        let tokens = TypeDefinition::from_type_def(&def).gen().into_string();
+        assert!(tokens.contains(
+            "pub union INPUT_Anonymous { pub mi : MOUSEINPUT , pub ki : KEYBDINPUT , pub hi : HARDWAREINPUT }"
+        ));
+
+        assert!(reader.contains_type((
+            "Windows.Win32.StructuredStorage",
+            "PROPVARIANT_Anonymous_Anonymous_Anonymous"
+        )));
+        assert_eq!(
+            layout(("Windows.Win32.KeyboardAndMouseInput", "INPUT"), 8),
+            Some((40, 8))
+        );
+    }
 }
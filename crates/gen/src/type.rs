@@ -1,5 +1,5 @@
 use crate::*;
-use squote::{quote, TokenStream};
+use squote::{quote, Literal, TokenStream};
 use winmd::Decode;
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord)]
@@ -39,6 +39,7 @@ pub enum TypeKind {
     IUnknown,
     ErrorCode,
     Bool32,
+    NtStatus,
     Matrix3x2,
     Class(TypeName),
     Interface(TypeName),
@@ -74,6 +75,8 @@ impl Type {
             pointers += 1;
         }
 
+        let mut array = None;
+
         let kind = match blob.read_unsigned() {
             0x01 => TypeKind::Void,
             0x02 => TypeKind::Bool,
@@ -96,8 +99,9 @@ impl Type {
                 let def =
                     winmd::TypeDefOrRef::decode(blob.reader, blob.read_unsigned(), blob.file_index);
 
+                // Nested types are given a namespace by the reader, so a type without one is a
+                // reference to a nested type that the metadata doesn't define.
                 if def.name().0.is_empty() {
-                    // TODO: handle nested types
                     TypeKind::NotYetSupported
                 } else {
                     TypeKind::from_type_def_or_ref(&def, generics, calling_namespace)
@@ -105,12 +109,28 @@ impl Type {
             }
             0x13 => generics[blob.read_unsigned() as usize].clone(),
             0x14 => {
-                // TODO: handle win32 arrays
-                // type
-                // rank (dimensions)
-                // bounds count
-                // bound
-                TypeKind::NotYetSupported
+                // Fixed-size arrays, such as `WCHAR szFaceName[32]`, give the element type followed
+                // by their rank, sizes, and lower bounds. Arrays with more than one dimension are
+                // laid out as one, and pointers to arrays aren't supported yet.
+                let element =
+                    Self::from_blob(blob, None, generics, calling_namespace, false).unwrap();
+
+                let rank = blob.read_unsigned();
+                let sizes: Vec<u32> = (0..blob.read_unsigned())
+                    .map(|_| blob.read_unsigned())
+                    .collect();
+
+                for _ in 0..blob.read_unsigned() {
+                    blob.read_unsigned();
+                }
+
+                if pointers > 0 || element.array.is_some() || sizes.len() != rank as usize {
+                    TypeKind::NotYetSupported
+                } else {
+                    pointers = element.pointers;
+                    array = Some(sizes.iter().product::<u32>() as usize);
+                    element.kind
+                }
             }
             0x15 => TypeKind::from_type_name(TypeName::from_type_spec_blob(
                 blob,
@@ -155,7 +175,7 @@ impl Type {
             by_ref,
             kind,
             pointers,
-            array: None,
+            array,
             modifiers,
             param,
             name,
@@ -172,6 +192,44 @@ impl Type {
         Self::from_blob(&mut blob, None, &Vec::new(), calling_namespace, false).unwrap()
     }
 
+    /// Returns the size and alignment of the field for the given pointer width, or `None`
+    /// if the layout cannot be determined from metadata alone.
+    pub fn layout(&self, pointer_width: usize) -> Option<(usize, usize)> {
+        if self.pointers > 0 {
+            return Some((pointer_width, pointer_width));
+        }
+
+        let layout = match &self.kind {
+            TypeKind::Bool | TypeKind::I8 | TypeKind::U8 => (1, 1),
+            TypeKind::Char | TypeKind::I16 | TypeKind::U16 => (2, 2),
+            TypeKind::I32
+            | TypeKind::U32
+            | TypeKind::F32
+            | TypeKind::ErrorCode
+            | TypeKind::Bool32
+            | TypeKind::NtStatus
+            | TypeKind::Enum(_) => (4, 4),
+            TypeKind::I64 | TypeKind::U64 | TypeKind::F64 => (8, 8),
+            TypeKind::ISize
+            | TypeKind::USize
+            | TypeKind::String
+            | TypeKind::Object
+            | TypeKind::IUnknown
+            | TypeKind::Class(_)
+            | TypeKind::Interface(_)
+            | TypeKind::Delegate(_) => (pointer_width, pointer_width),
+            TypeKind::Guid => (16, 4),
+            TypeKind::Matrix3x2 => (24, 4),
+            TypeKind::Struct(name) => Struct::from_type_name(name.clone()).layout(pointer_width)?,
+            TypeKind::Void | TypeKind::Generic(_) | TypeKind::NotYetSupported => return None,
+        };
+
+        match self.array {
+            Some(len) => Some((layout.0 * len, layout.1)),
+            None => Some(layout),
+        }
+    }
+
     pub fn gen_field(&self) -> TokenStream {
         let mut tokens = TokenStream::new();
 
@@ -195,7 +253,43 @@ impl Type {
             _ => tokens.combine(&kind),
         };
 
-        tokens
+        self.gen_array(tokens)
+    }
+
+    fn gen_array(&self, element: TokenStream) -> TokenStream {
+        match self.array {
+            Some(len) => {
+                let len = Literal::u32_unsuffixed(len as u32);
+                quote! { [#element; #len] }
+            }
+            None => element,
+        }
+    }
+
+    /// Generates the type of a function or method parameter, which is the same as that of a
+    /// field except that a pointer to data that is only read, as with an `[In]` parameter that
+    /// isn't also `[Out]`, is a `*const` pointer.
+    pub fn gen_param(&self) -> TokenStream {
+        if self.pointers == 0 || self.is_const || !self.is_in_only() {
+            return self.gen_field();
+        }
+
+        // Only the outermost pointer is known to be const, and a `*mut` argument still
+        // coerces to it.
+        let mut pointee = self.clone();
+        pointee.pointers -= 1;
+        let pointee = pointee.gen_field();
+
+        quote! { *const #pointee }
+    }
+
+    /// Returns whether the parameter is only read by the callee, as with an `[In]` parameter that
+    /// isn't also `[Out]`.
+    pub fn is_in_only(&self) -> bool {
+        match &self.param {
+            Some(param) => self.is_input && param.flags().input() && !param.flags().output(),
+            None => false,
+        }
     }
 
     pub fn gen_clone(&self, name: &TokenStream) -> TokenStream {
@@ -238,26 +332,269 @@ impl Type {
         let mut tokens = self.gen_abi_pointer_part();
 
         tokens.combine(&self.kind.gen_abi());
-        tokens
+        self.gen_array(tokens)
     }
 
     pub fn gen_full_abi(&self) -> TokenStream {
         let mut tokens = self.gen_abi_pointer_part();
 
         tokens.combine(&self.kind.gen_full_abi());
-        tokens
+        self.gen_array(tokens)
+    }
+
+    /// Returns whether the type has the same representation in Rust as in the ABI. Pointers are
+    /// always blittable, whatever they point to.
+    pub fn is_blittable(&self) -> bool {
+        self.pointers > 0 || self.kind.is_blittable()
+    }
+
+    /// Returns whether the type implements `Hash`, which rules out floating point values and
+    /// references other than raw pointers.
+    pub fn is_hashable(&self) -> bool {
+        self.pointers > 0 || self.kind.is_hashable()
+    }
+
+    /// Returns whether the type implements `Copy`, as the fields of a union must.
+    pub fn is_copy(&self) -> bool {
+        self.pointers > 0 || self.kind.is_copy()
+    }
+
+    /// Returns whether the type implements `PartialEq`.
+    pub fn is_comparable(&self) -> bool {
+        self.pointers > 0 || self.kind.is_comparable()
+    }
+
+    /// Returns whether the type implements `Clone`.
+    pub fn is_cloneable(&self) -> bool {
+        self.pointers > 0 || self.kind.is_cloneable()
+    }
+
+    /// Returns whether the type implements `serde::Serialize` and `serde::Deserialize`. Serde
+    /// only implements them for arrays of up to 32 elements.
+    pub fn is_serializable(&self) -> bool {
+        self.pointers == 0
+            && !matches!(self.array, Some(len) if len > 32)
+            && self.kind.is_serializable()
+    }
+
+    /// Returns whether the type implements `bytemuck::Pod`, which leaves out pointers since
+    /// bytemuck doesn't consider them to be plain data.
+    pub fn is_pod(&self) -> bool {
+        self.pointers == 0 && self.kind.is_pod()
     }
 
     pub fn gen_default(&self) -> TokenStream {
-        if self.pointers > 0 {
+        let element = if self.pointers > 0 {
             quote! { ::std::ptr::null_mut() }
         } else {
             self.kind.gen_default()
+        };
+
+        // The standard library only implements `Default` for arrays of up to 32 elements, so
+        // arrays are filled with the element's default instead, which is zeroed for elements
+        // like references that can't be repeated.
+        match self.array {
+            Some(len) if self.is_copy() => {
+                let len = Literal::u32_unsuffixed(len as u32);
+                quote! { [#element; #len] }
+            }
+            Some(_) => quote! { unsafe { ::std::mem::zeroed() } },
+            None => element,
         }
     }
 }
 
 impl TypeKind {
+    /// Returns whether the type has the same representation in Rust as in the ABI, which is
+    /// true of primitives, enums, and structs whose fields are all blittable.
+    pub fn is_blittable(&self) -> bool {
+        match self {
+            Self::Bool
+            | Self::Char
+            | Self::I8
+            | Self::U8
+            | Self::I16
+            | Self::U16
+            | Self::I32
+            | Self::U32
+            | Self::I64
+            | Self::U64
+            | Self::F32
+            | Self::F64
+            | Self::ISize
+            | Self::USize
+            | Self::Guid
+            | Self::ErrorCode
+            | Self::Bool32
+            | Self::NtStatus
+            | Self::Matrix3x2
+            | Self::Enum(_) => true,
+            Self::Struct(name) => Struct::from_type_name(name.clone()).is_blittable(),
+            _ => false,
+        }
+    }
+
+    /// Returns whether the type implements `Copy`, which is true of primitives, enums, function
+    /// pointers, and structs whose fields are all `Copy`, but not of strings or references.
+    pub fn is_copy(&self) -> bool {
+        match self {
+            Self::Bool
+            | Self::Char
+            | Self::I8
+            | Self::U8
+            | Self::I16
+            | Self::U16
+            | Self::I32
+            | Self::U32
+            | Self::I64
+            | Self::U64
+            | Self::F32
+            | Self::F64
+            | Self::ISize
+            | Self::USize
+            | Self::Guid
+            | Self::ErrorCode
+            | Self::Bool32
+            | Self::NtStatus
+            | Self::Matrix3x2
+            | Self::Enum(_) => true,
+            Self::Delegate(name) => !name.def.is_winrt(),
+            Self::Struct(name) => Struct::from_type_name(name.clone()).is_copy(),
+            _ => false,
+        }
+    }
+
+    /// Returns whether the type implements `PartialEq`, which is true of everything but unions and
+    /// the structs that hold them.
+    pub fn is_comparable(&self) -> bool {
+        match self {
+            Self::Struct(name) => Struct::from_type_name(name.clone()).is_comparable(),
+            _ => true,
+        }
+    }
+
+    /// Returns whether the type implements `Clone`, which is true of all but structs holding
+    /// unions of references.
+    pub fn is_cloneable(&self) -> bool {
+        match self {
+            Self::Struct(name) => Struct::from_type_name(name.clone()).is_cloneable(),
+            _ => true,
+        }
+    }
+
+    /// Returns whether the type implements `Hash`, which is true of integers, enums, and structs
+    /// whose fields are all hashable.
+    pub fn is_hashable(&self) -> bool {
+        match self {
+            Self::Bool
+            | Self::Char
+            | Self::I8
+            | Self::U8
+            | Self::I16
+            | Self::U16
+            | Self::I32
+            | Self::U32
+            | Self::I64
+            | Self::U64
+            | Self::ISize
+            | Self::USize
+            | Self::Guid
+            | Self::ErrorCode
+            | Self::Bool32
+            | Self::NtStatus
+            | Self::Enum(_) => true,
+            Self::Struct(name) => Struct::from_type_name(name.clone()).is_hashable(),
+            _ => false,
+        }
+    }
+
+    /// Returns whether the type implements `serde::Serialize` and `serde::Deserialize`, which is
+    /// true of numbers, enums, the types of the same name in the `windows` crate, and structs
+    /// whose fields are all serializable.
+    pub fn is_serializable(&self) -> bool {
+        match self {
+            Self::Bool
+            | Self::Char
+            | Self::I8
+            | Self::U8
+            | Self::I16
+            | Self::U16
+            | Self::I32
+            | Self::U32
+            | Self::I64
+            | Self::U64
+            | Self::F32
+            | Self::F64
+            | Self::ISize
+            | Self::USize
+            | Self::Guid
+            | Self::ErrorCode
+            | Self::Bool32
+            | Self::NtStatus
+            | Self::Enum(_) => true,
+            Self::Struct(name) => Struct::from_type_name(name.clone()).is_serializable(),
+            _ => false,
+        }
+    }
+
+    /// Returns whether the type implements `bytemuck::Pod`, which is true of numbers, enums, the
+    /// types of the same name in the `windows` crate, and structs without padding whose fields
+    /// are all plain data. Booleans are left out since only two of their bit patterns are valid.
+    pub fn is_pod(&self) -> bool {
+        match self {
+            Self::Char
+            | Self::I8
+            | Self::U8
+            | Self::I16
+            | Self::U16
+            | Self::I32
+            | Self::U32
+            | Self::I64
+            | Self::U64
+            | Self::F32
+            | Self::F64
+            | Self::ISize
+            | Self::USize
+            | Self::Guid
+            | Self::ErrorCode
+            | Self::Bool32
+            | Self::NtStatus
+            | Self::Enum(_) => true,
+            Self::Struct(name) => Struct::from_type_name(name.clone()).is_pod(),
+            _ => false,
+        }
+    }
+
+    /// Returns whether the type is a signed or unsigned integer of any width.
+    pub fn is_integer(&self) -> bool {
+        matches!(
+            self,
+            Self::I8
+                | Self::U8
+                | Self::I16
+                | Self::U16
+                | Self::I32
+                | Self::U32
+                | Self::I64
+                | Self::U64
+                | Self::ISize
+                | Self::USize
+        )
+    }
+
+    /// Returns the value type `T` if this is a `Windows.Foundation.IReference<T>`, the boxed form
+    /// WinRT uses for optional values.
+    pub fn reference_value(&self) -> Option<&TypeKind> {
+        match self {
+            Self::Interface(name)
+                if name.namespace == "Windows.Foundation" && name.name == "IReference`1" =>
+            {
+                name.generics.first()
+            }
+            _ => None,
+        }
+    }
+
     pub fn signature(&self) -> String {
         match self {
             Self::Bool => "b1".to_owned(),
@@ -333,6 +670,7 @@ impl TypeKind {
             ("Windows.Foundation", "HResult") => Self::ErrorCode,
             ("Windows.Win32.Com", "HRESULT") => Self::ErrorCode,
             ("Windows.Win32.SystemServices", "BOOL") => Self::Bool32,
+            ("Windows.Win32.SystemServices", "NTSTATUS") => Self::NtStatus,
             // TODO: workaround for https://github.com/microsoft/win32metadata/issues/181
             ("Windows.Win32.SystemServices", "LARGE_INTEGER") => Self::I64,
             ("Windows.Win32.SystemServices", "ULARGE_INTEGER") => Self::U64,
@@ -400,6 +738,7 @@ impl TypeKind {
             Self::IUnknown => quote! { ::windows::IUnknown },
             Self::ErrorCode => quote! { ::windows::ErrorCode },
             Self::Bool32 => quote! { ::windows::BOOL },
+            Self::NtStatus => quote! { ::windows::NTSTATUS },
             Self::Matrix3x2 => quote! { ::windows::foundation::numerics::Matrix3x2 },
             Self::Class(name) => name.gen(),
             Self::Interface(name) => name.gen(),
@@ -437,6 +776,7 @@ impl TypeKind {
             Self::IUnknown => quote! { ::windows::IUnknown },
             Self::ErrorCode => quote! { ::windows::ErrorCode },
             Self::Bool32 => quote! { ::windows::BOOL },
+            Self::NtStatus => quote! { ::windows::NTSTATUS },
             Self::Matrix3x2 => quote! { ::windows::foundation::numerics::Matrix3x2 },
             Self::Class(name) => name.gen_full(),
             Self::Interface(name) => name.gen_full(),
@@ -471,6 +811,7 @@ impl TypeKind {
             Self::Guid => quote! { ::windows::Guid },
             Self::ErrorCode => quote! { ::windows::ErrorCode },
             Self::Bool32 => quote! { ::windows::BOOL },
+            Self::NtStatus => quote! { ::windows::NTSTATUS },
             Self::Matrix3x2 => quote! { ::windows::foundation::numerics::Matrix3x2 },
             Self::String
             | Self::Object
@@ -485,6 +826,7 @@ impl TypeKind {
                 quote! { <#name as ::windows::Abi>::Abi }
             }
             Self::Enum(name) => name.gen(),
+            Self::Struct(name) if self.is_blittable() => name.gen(),
             Self::Struct(name) => name.gen_abi(),
             Self::NotYetSupported => quote!(::windows::NOT_YET_SUPPORTED_TYPE),
         }
@@ -510,6 +852,7 @@ impl TypeKind {
             Self::Guid => quote! { ::windows::Guid },
             Self::ErrorCode => quote! { ::windows::ErrorCode },
             Self::Bool32 => quote! { ::windows::BOOL },
+            Self::NtStatus => quote! { ::windows::NTSTATUS },
             Self::Matrix3x2 => quote! { ::windows::foundation::numerics::Matrix3x2 },
             Self::String
             | Self::Object
@@ -524,6 +867,7 @@ impl TypeKind {
                 quote! { <#name as ::windows::Abi>::Abi }
             }
             Self::Enum(name) => name.gen_full(),
+            Self::Struct(name) if self.is_blittable() => name.gen_full(),
             Self::Struct(name) => name.gen_full_abi(),
             Self::NotYetSupported => quote!(::windows::NOT_YET_SUPPORTED_TYPE),
         }
@@ -102,6 +102,7 @@ impl TypeDefinition {
             Self::Delegate(t) => t.dependencies(),
             Self::ComInterface(t) => t.dependencies(),
             Self::Callback(t) => t.dependencies(),
+            Self::Constant(t) => t.dependencies(),
             Self::Function(t) => t.dependencies(),
             _ => Vec::new(),
         }
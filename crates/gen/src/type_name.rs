@@ -270,6 +270,52 @@ impl TypeName {
         gen_format(&self.name, Some(&namespace), &self.generics, format_ident)
     }
 
+    /// The type's name as it appears in documentation, without the generic arity suffix
+    ///
+    /// For example: `IVector` rather than ``IVector`1``
+    pub fn doc_name(&self) -> &'static str {
+        self.name.split('`').next().unwrap()
+    }
+
+    /// Returns the URL of the type's documentation, or that of one of its members
+    ///
+    /// WinRT types have canonical pages on docs.microsoft.com, such as
+    /// `https://docs.microsoft.com/uwp/api/windows.foundation.uri`, whereas Win32 types are
+    /// linked to through a search.
+    pub fn doc_url(&self, member: Option<&str>) -> String {
+        if self.def.is_winrt() {
+            let mut url = format!(
+                "https://docs.microsoft.com/uwp/api/{}.{}",
+                self.namespace,
+                self.name.replace('`', "-")
+            );
+
+            if let Some(member) = member {
+                url.push('.');
+                url.push_str(member);
+            }
+
+            url.to_lowercase()
+        } else if let Some(member) = member {
+            search_doc_url(&format!("{}::{}", self.name, member))
+        } else {
+            search_doc_url(self.name)
+        }
+    }
+
+    /// Create a doc comment describing the type as the given kind of item
+    ///
+    /// For example: ``#[doc = "The [`Uri`](https://docs.microsoft.com/uwp/api/windows.foundation.uri) class from `Windows.Foundation`."]``
+    pub fn gen_doc(&self, kind: &str) -> TokenStream {
+        gen_doc(&format!(
+            "The [`{}`]({}) {} from `{}`.",
+            self.doc_name(),
+            self.doc_url(None),
+            kind,
+            self.namespace
+        ))
+    }
+
     pub fn gen_full(&self) -> TokenStream {
         let namespace = gen_full_namespace(&self.namespace);
         gen_format(&self.name, Some(&namespace), &self.generics, format_ident)
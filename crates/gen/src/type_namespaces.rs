@@ -5,11 +5,26 @@ use std::collections::*;
 pub struct TypeNamespaces(pub BTreeMap<&'static str, crate::type_tree::TypeTree>);
 
 impl TypeNamespaces {
-    pub fn gen<'a>(&'a self) -> impl Iterator<Item = TokenStream> + 'a {
-        self.0.iter().map(|(name, tree)| {
+    /// Generates a module for each namespace nested within `parent`, the full name of the
+    /// namespace the modules are generated in.
+    pub fn gen<'a>(&'a self, parent: String) -> impl Iterator<Item = TokenStream> + 'a {
+        let options = crate::GenOptions::get();
+
+        self.0.iter().map(move |(name, tree)| {
+            let namespace = if parent.is_empty() {
+                name.to_string()
+            } else {
+                format!("{}.{}", parent, name)
+            };
+
+            let feature = match crate::feature_name(&namespace) {
+                Some(feature) if options.features => quote! { #[cfg(feature = #feature)] },
+                _ => TokenStream::new(),
+            };
+
             let name = crate::to_snake(name);
             let name = crate::format_ident(&name);
-            let tokens = tree.gen().collect::<Vec<_>>();
+            let tokens = tree.gen_namespace(namespace).collect::<Vec<_>>();
 
             let foundation = if tree.include_foundation {
                 quote! { pub use ::windows::*; }
@@ -17,10 +32,21 @@ impl TypeNamespaces {
                 TokenStream::new()
             };
 
+            let allow = if options.allow.is_empty() {
+                TokenStream::new()
+            } else {
+                let lints = options
+                    .allow
+                    .iter()
+                    .map(|lint| squote::Ident::new(lint.clone()));
+                quote! { #[allow(#(#lints),*)] }
+            };
+
             quote! {
-                // TODO: remove this allowance when https://github.com/microsoft/windows-rs/issues/212 is fixed
-                #[allow(unused_variables)]
-                #[allow(non_upper_case_globals)]
+                // TODO: `unused_variables` and `non_upper_case_globals` may be dropped from the
+                // default allowances once https://github.com/microsoft/windows-rs/issues/212 is fixed
+                #feature
+                #allow
                 pub mod #name {
                     #(#tokens)*
                     #foundation
@@ -7,18 +7,30 @@ pub struct TypeTree {
     pub types: Vec<TypeDefinition>,
     pub namespaces: TypeNamespaces,
     pub include_foundation: bool,
+    /// The names of the types and functions left out by [`GenOptions::exclude_deprecated`] and
+    /// [`GenOptions::exclude_ansi`]
+    pub excluded: Vec<String>,
 }
 
 impl TypeTree {
     pub fn from_limits(reader: &'static winmd::TypeReader, limits: &TypeLimits) -> Self {
         let mut tree = TypeTree::default();
         let mut set = std::collections::BTreeSet::new();
+        let mut excluded = Vec::new();
+        let exclude_deprecated = GenOptions::get().exclude_deprecated;
+        let exclude_ansi = GenOptions::get().exclude_ansi;
 
         for limit in limits.limits() {
             match &limit.limit {
                 TypeLimit::All => {
                     for def in reader.namespace_types(&limit.namespace) {
-                        tree.insert_if(reader, &mut set, &def);
+                        if (exclude_deprecated && is_deprecated(reader, limit.namespace, &def))
+                            || (exclude_ansi && is_ansi(reader, limit.namespace, &def))
+                        {
+                            excluded.push(def);
+                        } else {
+                            tree.insert_if(reader, &mut set, &def);
+                        }
                     }
                 }
                 TypeLimit::Some(types) => {
@@ -33,6 +45,22 @@ impl TypeTree {
             }
         }
 
+        // Deprecated and ANSI types that other types depend on are still generated.
+        tree.excluded = excluded
+            .iter()
+            .filter_map(|t| match t {
+                winmd::Type::TypeDef(def) if !set.contains(def) => {
+                    let (namespace, name) = def.name();
+                    Some(format!("{}.{}", namespace, name))
+                }
+                winmd::Type::MethodDef((def, method)) => {
+                    Some(format!("{}.{}", def.name().0, method.name()))
+                }
+                _ => None,
+            })
+            .collect();
+
+        tree.excluded.sort();
         tree
     }
 
@@ -103,6 +131,29 @@ impl TypeTree {
         }
     }
 
+    /// Returns the tree for the namespace, if it has any types
+    pub fn get(&self, namespace: &str) -> Option<&TypeTree> {
+        if let Some(pos) = namespace.find('.') {
+            self.namespaces
+                .0
+                .get(&namespace[..pos])
+                .and_then(|tree| tree.get(&namespace[pos + 1..]))
+        } else {
+            self.namespaces.0.get(namespace)
+        }
+    }
+
+    /// The number of types in the tree, including those in nested namespaces
+    pub fn type_count(&self) -> usize {
+        self.types.len()
+            + self
+                .namespaces
+                .0
+                .values()
+                .map(|tree| tree.type_count())
+                .sum::<usize>()
+    }
+
     pub fn reexport(&mut self) {
         self.namespaces
             .0
@@ -113,10 +164,45 @@ impl TypeTree {
 
     /// Turn the tree into a token stream for code generation
     pub fn gen<'a>(&'a self) -> impl Iterator<Item = TokenStream> + 'a {
+        self.gen_namespace(String::new())
+    }
+
+    pub(crate) fn gen_namespace<'a>(
+        &'a self,
+        namespace: String,
+    ) -> impl Iterator<Item = TokenStream> + 'a {
         self.types
             .iter()
             .map(|t| t.gen())
-            .chain(self.namespaces.gen())
+            .chain(self.namespaces.gen(namespace))
+    }
+}
+
+// Types marked deprecated, and ANSI functions and structs like `MessageBoxA` and `WNDCLASSA`
+// that sit alongside wide versions like `MessageBoxW` and `WNDCLASSW`.
+fn is_deprecated(reader: &winmd::TypeReader, namespace: &str, t: &winmd::Type) -> bool {
+    match t {
+        winmd::Type::TypeDef(def)
+            if def.has_attribute(("Windows.Foundation.Metadata", "DeprecatedAttribute")) =>
+        {
+            true
+        }
+        _ => is_ansi(reader, namespace, t),
+    }
+}
+
+// ANSI functions and structs are those named with an `A` suffix that have a wide counterpart
+// named with a `W` suffix, such as `MessageBoxA` and `WNDCLASSA`.
+fn is_ansi(reader: &winmd::TypeReader, namespace: &str, t: &winmd::Type) -> bool {
+    let name = match t {
+        winmd::Type::TypeDef(def) => def.name().1,
+        winmd::Type::MethodDef((_, method)) => method.name(),
+        winmd::Type::Field(_) => return false,
+    };
+
+    match name.strip_suffix('A') {
+        Some(base) => reader.contains_type((namespace, &format!("{}W", base))),
+        None => false,
     }
 }
 
@@ -194,4 +280,68 @@ mod tests {
                 == false
         );
     }
+
+    #[test]
+    fn test_exclusions() {
+        let reader = winmd::TypeReader::get();
+        let mut limits = TypeLimits::new(reader);
+        limits
+            .insert(NamespaceTypes {
+                namespace: "windows.devices.sms",
+                limit: TypeLimit::All,
+            })
+            .unwrap();
+        limits
+            .insert(NamespaceTypes {
+                namespace: "windows.win32.windowsandmessaging",
+                limit: TypeLimit::All,
+            })
+            .unwrap();
+
+        // Nothing is left out by default.
+        assert!(gen_exclusions(reader, &limits).is_empty());
+
+        // Both options leave out ANSI functions and structs, but only `exclude_deprecated` leaves
+        // out types marked deprecated.
+        let options = [
+            (
+                GenOptions {
+                    exclude_deprecated: true,
+                    ..GenOptions::default()
+                },
+                true,
+            ),
+            (
+                GenOptions {
+                    exclude_ansi: true,
+                    ..GenOptions::default()
+                },
+                false,
+            ),
+        ];
+
+        for (options, sms_excluded) in options.iter() {
+            options.clone().set();
+
+            let excluded = gen_exclusions(reader, &limits);
+            assert_eq!(
+                excluded.contains("Windows.Devices.Sms.SmsDevice\n"),
+                *sms_excluded
+            );
+            assert!(excluded.contains("Windows.Win32.WindowsAndMessaging.MessageBoxA\n"));
+            assert!(excluded.contains("Windows.Win32.WindowsAndMessaging.WNDCLASSA\n"));
+            assert!(!excluded.contains("MessageBoxW"));
+
+            let tree = TypeTree::from_limits(reader, &limits);
+            let messaging = tree.get("Windows.Win32.WindowsAndMessaging").unwrap();
+            let tokens = messaging
+                .gen()
+                .fold(String::new(), |accum, t| accum + &t.into_string());
+            assert!(tokens.contains("fn MessageBoxW"));
+            assert!(!tokens.contains("fn MessageBoxA"));
+            assert!(!tokens.contains("pub struct WNDCLASSA"));
+        }
+
+        GenOptions::default().set();
+    }
 }
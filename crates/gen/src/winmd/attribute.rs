@@ -61,16 +61,13 @@ impl Attribute {
                                 .expect_type_def((&name[0..index], &name[index + 1..])),
                         )
                     } else {
-                        let def = match type_def_or_ref {
-                            TypeDefOrRef::TypeRef(value) => {
-                                self.reader.expect_type_def(value.name())
-                            }
-                            TypeDefOrRef::TypeDef(value) => value,
+                        let name = match type_def_or_ref {
+                            TypeDefOrRef::TypeRef(value) => value.name(),
+                            TypeDefOrRef::TypeDef(value) => value.name(),
                             TypeDefOrRef::TypeSpec(_) => panic!("Unsupported underlying type"),
                         };
 
-                        let underlying_type = def.underlying_type();
-                        read_enum(&underlying_type, &mut values)
+                        read_enum(&self.enum_underlying_type(name), &mut values)
                     }
                 }
                 _ => panic!("Unexpected fixed attribute argument type"),
@@ -89,9 +86,20 @@ impl Attribute {
                 "A NamedArg must start with an id of 0x53 (Field) or 0x54 (Property)"
             );
             let arg_type = values.read_u8();
+
+            // Enum arguments are followed by the name of the enum.
+            let underlying_type = if arg_type == 0x55 {
+                let name = values.read_str();
+                let index = name.rfind('.').unwrap();
+                Some(self.enum_underlying_type((&name[0..index], &name[index + 1..])))
+            } else {
+                None
+            };
+
             let name = values.read_str().to_string();
             let arg = match arg_type {
                 0x02 => AttributeArg::Bool(values.read_u8() != 0),
+                0x06 => AttributeArg::I16(values.read_i16()),
                 0x08 => AttributeArg::I32(values.read_i32()),
                 0x0E => AttributeArg::String(values.read_str().to_string()),
                 0x50 => {
@@ -102,6 +110,7 @@ impl Attribute {
                             .expect_type_def((&name[0..index], &name[index + 1..])),
                     )
                 }
+                0x55 => read_enum(&underlying_type.unwrap(), &mut values),
                 _ => panic!("Unexpected named attribute argument type"),
             };
             args.push((name, arg));
@@ -109,6 +118,16 @@ impl Attribute {
 
         args
     }
+
+    // Enums defined outside the metadata, like `System.Runtime.InteropServices.UnmanagedType`,
+    // are 32-bit as they are in .NET.
+    fn enum_underlying_type(&self, name: (&str, &str)) -> ElementType {
+        if self.reader.contains_type(name) {
+            self.reader.expect_type_def(name).underlying_type()
+        } else {
+            ElementType::I32
+        }
+    }
 }
 
 fn read_enum(element_type: &ElementType, blob: &mut Blob) -> AttributeArg {
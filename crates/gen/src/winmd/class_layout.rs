@@ -0,0 +1,13 @@
+macros::table!(ClassLayout);
+
+impl ClassLayout {
+    /// The alignment, in bytes, that the type's fields are packed to, or zero if the fields are
+    /// naturally aligned.
+    pub fn packing_size(&self) -> u32 {
+        self.reader.u32(self.row, 0)
+    }
+
+    pub fn class_size(&self) -> u32 {
+        self.reader.u32(self.row, 1)
+    }
+}
@@ -31,4 +31,21 @@ impl ConstantValue {
             ConstantValue::String(value) => quote! { &'static str = #value },
         }
     }
+
+    /// Generates the value alone, without its type.
+    pub fn gen_literal(&self) -> TokenStream {
+        match self {
+            ConstantValue::U8(value) => quote! { #value },
+            ConstantValue::I8(value) => quote! { #value },
+            ConstantValue::U16(value) => quote! { #value },
+            ConstantValue::I16(value) => quote! { #value },
+            ConstantValue::U32(value) => quote! { #value },
+            ConstantValue::I32(value) => quote! { #value },
+            ConstantValue::U64(value) => quote! { #value },
+            ConstantValue::I64(value) => quote! { #value },
+            ConstantValue::F32(value) => quote! { #value },
+            ConstantValue::F64(value) => quote! { #value },
+            ConstantValue::String(value) => quote! { #value },
+        }
+    }
 }
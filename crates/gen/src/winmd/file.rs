@@ -8,6 +8,36 @@ pub struct TableData {
     pub columns: [(u32, u32); 6],
 }
 
+/// The error returned when a file cannot be read as a Windows Metadata File
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InvalidFile(pub &'static str);
+
+impl std::fmt::Display for InvalidFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid file: {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidFile {}
+
+/// The error returned when metadata cannot be loaded, naming the file or path at fault
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LoadError(pub String);
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<InvalidFile> for LoadError {
+    fn from(error: InvalidFile) -> Self {
+        Self(error.to_string())
+    }
+}
+
 /// A Windows Metadata File
 #[derive(Default)]
 pub struct File {
@@ -18,7 +48,7 @@ pub struct File {
     /// The index of the blobs data
     pub(crate) blobs: u32,
     /// The table data
-    pub(crate) tables: [TableData; 16],
+    pub(crate) tables: [TableData; 17],
 }
 
 /// A well-known index of data into the winmd tables array
@@ -41,6 +71,7 @@ pub enum TableIndex {
     NestedClass,
     Module,
     AssemblyRef,
+    ClassLayout,
 }
 
 impl TableData {
@@ -72,100 +103,137 @@ impl TableData {
         }
     }
 
-    fn set_data(&mut self, data: &mut u32) {
+    fn set_data(&mut self, data: &mut u32) -> Result<(), InvalidFile> {
         if self.row_count != 0 {
-            let next = *data + self.row_count * self.row_size;
+            let size = self
+                .row_count
+                .checked_mul(self.row_size)
+                .ok_or(InvalidFile("table is too large"))?;
+            let next = checked_add(*data, size)?;
             self.data = *data;
             *data = next;
         }
+
+        Ok(())
     }
 }
 
 impl File {
-    pub(crate) fn from_bytes(bytes: Vec<u8>) -> Self {
+    /// The size of the file in bytes
+    pub fn size(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// A 64-bit FNV-1a hash of the file's bytes, identifying exactly which metadata was read
+    pub fn fingerprint(&self) -> u64 {
+        self.bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ *byte as u64).wrapping_mul(0x0100_0000_01b3)
+        })
+    }
+
+    /// Parses the file's headers and table layout, returning an error rather than panicking
+    /// if the bytes are not a well-formed winmd file.
+    pub fn try_from_bytes(bytes: Vec<u8>) -> Result<Self, InvalidFile> {
         let mut file = Self {
             bytes,
             ..Default::default()
         };
 
-        let dos = file.bytes.view_as::<ImageDosHeader>(0);
+        let dos = file.bytes.try_view_as::<ImageDosHeader>(0)?;
 
         if dos.signature != IMAGE_DOS_SIGNATURE {
-            panic!("Invalid file: file does not appear to be a winmd file");
+            return Err(InvalidFile("file does not appear to be a winmd file"));
         }
 
-        let pe = file.bytes.view_as::<ImageNtHeader>(dos.lfanew as u32);
+        let pe = file.bytes.try_view_as::<ImageNtHeader>(dos.lfanew as u32)?;
 
         let (com_virtual_address, sections) = match pe.optional_header.magic {
             MAGIC_PE32 => (
                 pe.optional_header.data_directory[IMAGE_DIRECTORY_ENTRY_COM_DESCRIPTOR as usize]
                     .virtual_address,
-                file.bytes.view_as_slice_of::<ImageSectionHeader>(
-                    dos.lfanew as u32 + sizeof::<ImageNtHeader>(),
+                file.bytes.try_view_as_slice_of::<ImageSectionHeader>(
+                    checked_add(dos.lfanew as u32, sizeof::<ImageNtHeader>())?,
                     pe.file_header.number_of_sections as u32,
-                ),
+                )?,
             ),
             MAGIC_PE32PLUS => (
                 file.bytes
-                    .view_as::<ImageNtHeaderPlus>(dos.lfanew as u32)
+                    .try_view_as::<ImageNtHeaderPlus>(dos.lfanew as u32)?
                     .optional_header
                     .data_directory[IMAGE_DIRECTORY_ENTRY_COM_DESCRIPTOR as usize]
                     .virtual_address,
-                file.bytes.view_as_slice_of::<ImageSectionHeader>(
-                    dos.lfanew as u32 + sizeof::<ImageNtHeaderPlus>(),
+                file.bytes.try_view_as_slice_of::<ImageSectionHeader>(
+                    checked_add(dos.lfanew as u32, sizeof::<ImageNtHeaderPlus>())?,
                     pe.file_header.number_of_sections as u32,
-                ),
+                )?,
             ),
-            _ => panic!("Invalid file: invalid magic"),
+            _ => return Err(InvalidFile("invalid magic")),
         };
 
-        let cli = file.bytes.view_as::<ImageCorHeader>(offset_from_rva(
-            section_from_rva(sections, com_virtual_address),
+        let cli = file.bytes.try_view_as::<ImageCorHeader>(offset_from_rva(
+            section_from_rva(sections, com_virtual_address)?,
             com_virtual_address,
-        ));
+        )?)?;
 
         if cli.cb != sizeof::<ImageCorHeader>() {
-            panic!("Invalid file: invalid ImageCorHeader");
+            return Err(InvalidFile("invalid ImageCorHeader"));
         }
 
         let cli_offset = offset_from_rva(
-            section_from_rva(sections, cli.meta_data.virtual_address),
+            section_from_rva(sections, cli.meta_data.virtual_address)?,
             cli.meta_data.virtual_address,
-        );
+        )?;
 
-        if file.bytes.copy_as::<u32>(cli_offset) != STORAGE_MAGIC_SIG {
-            panic!("Invalid file: invalid STORAGE_MAGIC_SIG");
+        if file.bytes.try_copy_as::<u32>(cli_offset)? != STORAGE_MAGIC_SIG {
+            return Err(InvalidFile("invalid STORAGE_MAGIC_SIG"));
         }
 
-        let version_length = file.bytes.copy_as::<u32>(cli_offset + 12);
-        let mut view = cli_offset + version_length + 20;
-        let mut tables_data: (u32, u32) = (0, 0);
-
-        for _ in 0..file.bytes.copy_as::<u16>(cli_offset + version_length + 18) {
-            let stream_offset = file.bytes.copy_as::<u32>(view);
-            let stream_size = file.bytes.copy_as::<u32>(view + 4);
-            let stream_name = file.bytes.view_as_str(view + 8);
+        let version_length = file
+            .bytes
+            .try_copy_as::<u32>(checked_add(cli_offset, 12)?)?;
+        let mut view = checked_add(checked_add(cli_offset, version_length)?, 20)?;
+        let mut tables_data: Option<(u32, u32)> = None;
+
+        let stream_count = file
+            .bytes
+            .try_copy_as::<u16>(checked_add(checked_add(cli_offset, version_length)?, 18)?)?;
+
+        for _ in 0..stream_count {
+            let stream_offset = file.bytes.try_copy_as::<u32>(view)?;
+            let stream_size = file.bytes.try_copy_as::<u32>(checked_add(view, 4)?)?;
+            let stream_name = file.bytes.try_view_as_str(checked_add(view, 8)?)?;
+            let stream_offset = checked_add(cli_offset, stream_offset)?;
             match stream_name {
-                b"#Strings" => file.strings = cli_offset + stream_offset,
-                b"#Blob" => file.blobs = cli_offset + stream_offset,
-                b"#~" => tables_data = (cli_offset + stream_offset, stream_size),
+                b"#Strings" => file.strings = stream_offset,
+                b"#Blob" => file.blobs = stream_offset,
+                b"#~" => tables_data = Some((stream_offset, stream_size)),
                 b"#GUID" => {}
                 b"#US" => {}
-                _ => panic!("Invalid file: invalid stream name"),
+                _ => return Err(InvalidFile("invalid stream name")),
             }
             let mut padding = 4 - stream_name.len() % 4;
             if padding == 0 {
                 padding = 4;
             }
-            view += (8 + stream_name.len() + padding) as u32;
+            view = checked_add(view, (8 + stream_name.len() + padding) as u32)?;
         }
 
-        let heap_sizes = file.bytes.copy_as::<u8>(tables_data.0 + 6);
+        let tables_data = tables_data.ok_or(InvalidFile("missing tables stream"))?;
+
+        if file.strings as usize >= file.bytes.len() || file.blobs as usize >= file.bytes.len() {
+            return Err(InvalidFile("missing or invalid heap streams"));
+        }
+
+        let heap_sizes = file
+            .bytes
+            .try_copy_as::<u8>(checked_add(tables_data.0, 6)?)?;
         let string_index_size = if (heap_sizes & 1) == 1 { 4 } else { 2 };
         let guid_index_size = if (heap_sizes >> 1 & 1) == 1 { 4 } else { 2 };
         let blob_index_size = if (heap_sizes >> 2 & 1) == 1 { 4 } else { 2 };
-        let valid_bits = file.bytes.copy_as::<u64>(tables_data.0 + 8);
-        view = tables_data.0 + 24;
+        let valid_bits = file
+            .bytes
+            .try_copy_as::<u64>(checked_add(tables_data.0, 8)?)?;
+        view = checked_add(tables_data.0, 24)?;
 
         // These tables are unused by WinRT, but needed temporarily to calculate sizes and offsets for subsequent tables.
         let unused_empty = TableData::default();
@@ -174,7 +242,6 @@ impl File {
         let mut unused_assembly_processor = TableData::default();
         let mut unused_assembly_ref_os = TableData::default();
         let mut unused_assembly_ref_processor = TableData::default();
-        let mut unused_class_layout = TableData::default();
         let mut unused_decl_security = TableData::default();
         let mut unused_event = TableData::default();
         let mut unused_event_map = TableData::default();
@@ -197,8 +264,8 @@ impl File {
                 continue;
             }
 
-            let row_count = file.bytes.copy_as::<u32>(view);
-            view += 4;
+            let row_count = file.bytes.try_copy_as::<u32>(view)?;
+            view = checked_add(view, 4)?;
 
             match i {
                 0x00 => file.tables[TableIndex::Module as usize].row_count = row_count,
@@ -213,7 +280,7 @@ impl File {
                 0x0c => file.tables[TableIndex::CustomAttribute as usize].row_count = row_count,
                 0x0d => unused_field_marshal.row_count = row_count,
                 0x0e => unused_decl_security.row_count = row_count,
-                0x0f => unused_class_layout.row_count = row_count,
+                0x0f => file.tables[TableIndex::ClassLayout as usize].row_count = row_count,
                 0x10 => unused_field_layout.row_count = row_count,
                 0x11 => unused_standalone_sig.row_count = row_count,
                 0x12 => unused_event_map.row_count = row_count,
@@ -239,7 +306,7 @@ impl File {
                 0x2a => file.tables[TableIndex::GenericParam as usize].row_count = row_count,
                 0x2b => unused_method_spec.row_count = row_count,
                 0x2c => unused_generic_param_constraint.row_count = row_count,
-                _ => unreachable!(),
+                _ => return Err(InvalidFile("invalid table")),
             };
         }
 
@@ -370,7 +437,7 @@ impl File {
             0,
             0,
         );
-        unused_class_layout.set_columns(
+        file.tables[TableIndex::ClassLayout as usize].set_columns(
             2,
             4,
             file.tables[TableIndex::TypeDef as usize].index_size(),
@@ -550,51 +617,155 @@ impl File {
         );
         file.tables[TableIndex::TypeSpec as usize].set_columns(blob_index_size, 0, 0, 0, 0, 0);
 
-        file.tables[TableIndex::Module as usize].set_data(&mut view);
-        file.tables[TableIndex::TypeRef as usize].set_data(&mut view);
-        file.tables[TableIndex::TypeDef as usize].set_data(&mut view);
-        file.tables[TableIndex::Field as usize].set_data(&mut view);
-        file.tables[TableIndex::MethodDef as usize].set_data(&mut view);
-        file.tables[TableIndex::Param as usize].set_data(&mut view);
-        file.tables[TableIndex::InterfaceImpl as usize].set_data(&mut view);
-        file.tables[TableIndex::MemberRef as usize].set_data(&mut view);
-        file.tables[TableIndex::Constant as usize].set_data(&mut view);
-        file.tables[TableIndex::CustomAttribute as usize].set_data(&mut view);
-        unused_field_marshal.set_data(&mut view);
-        unused_decl_security.set_data(&mut view);
-        unused_class_layout.set_data(&mut view);
-        unused_field_layout.set_data(&mut view);
-        unused_standalone_sig.set_data(&mut view);
-        unused_event_map.set_data(&mut view);
-        unused_event.set_data(&mut view);
-        unused_property_map.set_data(&mut view);
-        unused_property.set_data(&mut view);
-        unused_method_semantics.set_data(&mut view);
-        unused_method_impl.set_data(&mut view);
-        file.tables[TableIndex::ModuleRef as usize].set_data(&mut view);
-        file.tables[TableIndex::TypeSpec as usize].set_data(&mut view);
-        file.tables[TableIndex::ImplMap as usize].set_data(&mut view);
-        unused_field_rva.set_data(&mut view);
-        unused_assembly.set_data(&mut view);
-        unused_assembly_processor.set_data(&mut view);
-        unused_assembly_os.set_data(&mut view);
-        file.tables[TableIndex::AssemblyRef as usize].set_data(&mut view);
-        unused_assembly_ref_processor.set_data(&mut view);
-        unused_assembly_ref_os.set_data(&mut view);
-        unused_file.set_data(&mut view);
-        unused_exported_type.set_data(&mut view);
-        unused_manifest_resource.set_data(&mut view);
-        file.tables[TableIndex::NestedClass as usize].set_data(&mut view);
-        file.tables[TableIndex::GenericParam as usize].set_data(&mut view);
-
-        file
-    }
-
-    pub(crate) fn new<P: AsRef<std::path::Path>>(filename: P) -> Self {
-        let bytes = std::fs::read(filename.as_ref())
-            .unwrap_or_else(|e| panic!("Could not read file {:?}: {:?}", filename.as_ref(), e));
-
-        Self::from_bytes(bytes)
+        file.tables[TableIndex::Module as usize].set_data(&mut view)?;
+        file.tables[TableIndex::TypeRef as usize].set_data(&mut view)?;
+        file.tables[TableIndex::TypeDef as usize].set_data(&mut view)?;
+        file.tables[TableIndex::Field as usize].set_data(&mut view)?;
+        file.tables[TableIndex::MethodDef as usize].set_data(&mut view)?;
+        file.tables[TableIndex::Param as usize].set_data(&mut view)?;
+        file.tables[TableIndex::InterfaceImpl as usize].set_data(&mut view)?;
+        file.tables[TableIndex::MemberRef as usize].set_data(&mut view)?;
+        file.tables[TableIndex::Constant as usize].set_data(&mut view)?;
+        file.tables[TableIndex::CustomAttribute as usize].set_data(&mut view)?;
+        unused_field_marshal.set_data(&mut view)?;
+        unused_decl_security.set_data(&mut view)?;
+        file.tables[TableIndex::ClassLayout as usize].set_data(&mut view)?;
+        unused_field_layout.set_data(&mut view)?;
+        unused_standalone_sig.set_data(&mut view)?;
+        unused_event_map.set_data(&mut view)?;
+        unused_event.set_data(&mut view)?;
+        unused_property_map.set_data(&mut view)?;
+        unused_property.set_data(&mut view)?;
+        unused_method_semantics.set_data(&mut view)?;
+        unused_method_impl.set_data(&mut view)?;
+        file.tables[TableIndex::ModuleRef as usize].set_data(&mut view)?;
+        file.tables[TableIndex::TypeSpec as usize].set_data(&mut view)?;
+        file.tables[TableIndex::ImplMap as usize].set_data(&mut view)?;
+        unused_field_rva.set_data(&mut view)?;
+        unused_assembly.set_data(&mut view)?;
+        unused_assembly_processor.set_data(&mut view)?;
+        unused_assembly_os.set_data(&mut view)?;
+        file.tables[TableIndex::AssemblyRef as usize].set_data(&mut view)?;
+        unused_assembly_ref_processor.set_data(&mut view)?;
+        unused_assembly_ref_os.set_data(&mut view)?;
+        unused_file.set_data(&mut view)?;
+        unused_exported_type.set_data(&mut view)?;
+        unused_manifest_resource.set_data(&mut view)?;
+        file.tables[TableIndex::NestedClass as usize].set_data(&mut view)?;
+        file.tables[TableIndex::GenericParam as usize].set_data(&mut view)?;
+
+        if view as usize > file.bytes.len() {
+            return Err(InvalidFile("tables extend past the end of the file"));
+        }
+
+        Ok(file)
+    }
+
+    /// Reads the `.winmd` file at the given path, or compiles the `.idl` file at the given path
+    /// to metadata, returning an error naming the file if it can't be read.
+    pub fn try_new<P: AsRef<std::path::Path>>(filename: P) -> Result<Self, LoadError> {
+        let filename = filename.as_ref();
+
+        // MIDL 3.0 files are compiled to metadata as they're loaded.
+        let bytes = if filename
+            .extension()
+            .and_then(|extension| extension.to_str())
+            == Some("idl")
+        {
+            let source = std::fs::read_to_string(filename)
+                .map_err(|e| LoadError(format!("Could not read file {:?}: {:?}", filename, e)))?;
+
+            let module_name = filename.with_extension("winmd");
+            let module_name = module_name.file_name().unwrap().to_string_lossy();
+
+            compile_idl(&module_name, &source)
+                .map_err(|e| LoadError(format!("Could not compile {:?}: {}", filename, e)))?
+        } else {
+            std::fs::read(filename)
+                .map_err(|e| LoadError(format!("Could not read file {:?}: {:?}", filename, e)))?
+        };
+
+        Self::try_from_bytes(bytes)
+            .map_err(|e| LoadError(format!("Could not load {:?}: {}", filename, e)))
+    }
+
+    /// The number of rows in the given table
+    pub fn row_count(&self, table: TableIndex) -> u32 {
+        self.tables[table as usize].row_count
+    }
+
+    /// Reads the value in a column of a table row, returning an error rather than panicking if
+    /// the row or column is out of range or the value lies past the end of the file.
+    pub fn try_u32(&self, table: TableIndex, row: u32, column: u32) -> Result<u32, InvalidFile> {
+        let table = &self.tables[table as usize];
+
+        if row >= table.row_count {
+            return Err(InvalidFile("row index is out of range"));
+        }
+
+        let (column_offset, column_size) = *table
+            .columns
+            .get(column as usize)
+            .ok_or(InvalidFile("column index is out of range"))?;
+
+        let offset = checked_add(
+            checked_add(table.data, row * table.row_size)?,
+            column_offset,
+        )?;
+
+        match column_size {
+            1 => self
+                .bytes
+                .try_copy_as::<u8>(offset)
+                .map(|value| value as u32),
+            2 => self
+                .bytes
+                .try_copy_as::<u16>(offset)
+                .map(|value| value as u32),
+            4 => self.bytes.try_copy_as::<u32>(offset),
+            _ => Err(InvalidFile("column index is out of range")),
+        }
+    }
+
+    /// Reads a string from the strings heap at the index held in a column of a table row.
+    pub fn try_str(&self, table: TableIndex, row: u32, column: u32) -> Result<&str, InvalidFile> {
+        let offset = checked_add(self.strings, self.try_u32(table, row, column)?)?;
+        let bytes = self.bytes.try_view_as_str(offset)?;
+        std::str::from_utf8(bytes).map_err(|_| InvalidFile("string is not valid UTF-8"))
+    }
+
+    /// Finds the blob at the index held in a column of a table row, returning the offset of its
+    /// data and its size in bytes.
+    pub(crate) fn try_blob(
+        &self,
+        table: TableIndex,
+        row: u32,
+        column: u32,
+    ) -> Result<(usize, usize), InvalidFile> {
+        let offset = checked_add(self.blobs, self.try_u32(table, row, column)?)? as usize;
+        let initial_byte = *self
+            .bytes
+            .get(offset)
+            .ok_or(InvalidFile("blob offset is past the end of the file"))?;
+        let (blob_size, blob_size_bytes) = match initial_byte >> 5 {
+            0..=3 => (initial_byte & 0x7f, 1),
+            4..=5 => (initial_byte & 0x3f, 2),
+            6 => (initial_byte & 0x1f, 4),
+            _ => return Err(InvalidFile("blob size is not a valid compressed integer")),
+        };
+        let size_bytes = self
+            .bytes
+            .get(offset + 1..offset + blob_size_bytes)
+            .ok_or(InvalidFile("blob size is past the end of the file"))?;
+        let mut blob_size = blob_size as usize;
+        for byte in size_bytes {
+            blob_size = (blob_size << 8) + (*byte as usize);
+        }
+        let offset = offset + blob_size_bytes;
+        if offset + blob_size > self.bytes.len() {
+            return Err(InvalidFile("blob extends past the end of the file"));
+        }
+        Ok((offset, blob_size))
     }
 
     pub(crate) fn type_def_table(&self) -> &TableData {
@@ -606,17 +777,27 @@ impl File {
     // }
 }
 
-fn section_from_rva(sections: &[ImageSectionHeader], rva: u32) -> &ImageSectionHeader {
+fn section_from_rva(
+    sections: &[ImageSectionHeader],
+    rva: u32,
+) -> Result<&ImageSectionHeader, InvalidFile> {
     sections
         .iter()
         .find(|&s| {
-            rva >= s.virtual_address && rva < s.virtual_address + s.physical_address_or_virtual_size
+            rva >= s.virtual_address
+                && (rva as u64)
+                    < s.virtual_address as u64 + s.physical_address_or_virtual_size as u64
         })
-        .expect("Invalid file")
+        .ok_or(InvalidFile("address does not fall within any section"))
 }
 
-fn offset_from_rva(section: &ImageSectionHeader, rva: u32) -> u32 {
-    rva - section.virtual_address + section.pointer_to_raw_data
+fn offset_from_rva(section: &ImageSectionHeader, rva: u32) -> Result<u32, InvalidFile> {
+    checked_add(rva - section.virtual_address, section.pointer_to_raw_data)
+}
+
+fn checked_add(a: u32, b: u32) -> Result<u32, InvalidFile> {
+    a.checked_add(b)
+        .ok_or(InvalidFile("offset overflows the file"))
 }
 
 fn sizeof<T>() -> u32 {
@@ -653,49 +834,48 @@ fn composite_index_size(tables: &[&TableData]) -> u32 {
     }
 }
 
-macro_rules! assert_proper_length {
-    ($self:expr, $t:ty, $cli_offset:expr, $size:expr) => {
-        let enough_room = $cli_offset + $size <= $self.len() as u32;
-        assert!(
-            enough_room,
-            "Invalid file: not enough bytes at offset {} to represent T",
-            $cli_offset
-        );
-    };
+fn check_length(bytes: &[u8], cli_offset: u32, size: u64) -> Result<(), InvalidFile> {
+    if cli_offset as u64 + size <= bytes.len() as u64 {
+        Ok(())
+    } else {
+        Err(InvalidFile("not enough bytes at offset to represent T"))
+    }
 }
 
-macro_rules! assert_proper_length_and_alignment {
-    ($self:expr, $t:ty, $cli_offset:expr, $size:expr) => {{
-        assert_proper_length!($self, $t, $cli_offset, $size);
+fn check_length_and_alignment<T>(
+    bytes: &[u8],
+    cli_offset: u32,
+    size: u64,
+) -> Result<*const T, InvalidFile> {
+    check_length(bytes, cli_offset, size)?;
 
-        let ptr = &$self[$cli_offset as usize] as *const u8 as *const $t;
+    let ptr = bytes[cli_offset as usize..].as_ptr() as *const T;
 
-        let properly_aligned = ptr.align_offset(std::mem::align_of::<$t>()) == 0;
-
-        assert!(
-            properly_aligned,
-            "Invalid file: offset {} is not properly aligned to T",
-            $cli_offset
-        );
-        ptr
-    }};
+    if ptr.align_offset(std::mem::align_of::<T>()) == 0 {
+        Ok(ptr)
+    } else {
+        Err(InvalidFile("offset is not properly aligned to T"))
+    }
 }
 
 impl View for [u8] {
-    fn view_as<T: Pod>(&self, cli_offset: u32) -> &T {
-        let ptr = assert_proper_length_and_alignment!(self, T, cli_offset, sizeof::<T>());
+    fn try_view_as<T: Pod>(&self, cli_offset: u32) -> Result<&T, InvalidFile> {
+        let ptr = check_length_and_alignment::<T>(self, cli_offset, sizeof::<T>() as u64)?;
 
-        unsafe { &*ptr }
+        // Safety: the length and alignment have been checked and any bit pattern is a valid `Pod`.
+        unsafe { Ok(&*ptr) }
     }
 
-    fn view_as_slice_of<T: Pod>(&self, cli_offset: u32, len: u32) -> &[T] {
-        let ptr = assert_proper_length_and_alignment!(self, T, cli_offset, sizeof::<T>() * len);
+    fn try_view_as_slice_of<T: Pod>(&self, cli_offset: u32, len: u32) -> Result<&[T], InvalidFile> {
+        let ptr =
+            check_length_and_alignment::<T>(self, cli_offset, sizeof::<T>() as u64 * len as u64)?;
 
-        unsafe { std::slice::from_raw_parts(ptr, len as usize) }
+        // Safety: the length and alignment have been checked and any bit pattern is a valid `Pod`.
+        unsafe { Ok(std::slice::from_raw_parts(ptr, len as usize)) }
     }
 
-    fn copy_as<T: CopyPod>(&self, cli_offset: u32) -> T {
-        assert_proper_length!(self, T, cli_offset, sizeof::<T>());
+    fn try_copy_as<T: CopyPod>(&self, cli_offset: u32) -> Result<T, InvalidFile> {
+        check_length(self, cli_offset, sizeof::<T>() as u64)?;
 
         unsafe {
             let mut data = std::mem::MaybeUninit::zeroed().assume_init();
@@ -706,17 +886,19 @@ impl View for [u8] {
                 std::mem::size_of::<T>(),
             );
 
-            data
+            Ok(data)
         }
     }
 
-    fn view_as_str(&self, cli_offset: u32) -> &[u8] {
-        let buffer = &self[cli_offset as usize..];
+    fn try_view_as_str(&self, cli_offset: u32) -> Result<&[u8], InvalidFile> {
+        let buffer = self
+            .get(cli_offset as usize..)
+            .ok_or(InvalidFile("string offset is past the end of the file"))?;
         let index = buffer
             .iter()
             .position(|c| *c == b'\0')
-            .expect("Invalid file");
-        &self[cli_offset as usize..cli_offset as usize + index]
+            .ok_or(InvalidFile("string is not null terminated"))?;
+        Ok(&buffer[..index])
     }
 }
 
@@ -915,7 +1097,7 @@ mod tests {
     fn view_bytes_as_type() {
         let bytes = [1u8, 3, 48, 90];
 
-        let foo = bytes.view_as::<Foo>(0);
+        let foo = bytes.try_view_as::<Foo>(0).unwrap();
         assert_eq!(foo.bar, 0x0301);
         assert_eq!(foo.baz, 48)
     }
@@ -925,8 +1107,8 @@ mod tests {
     fn panic_on_unaligned_bytes() {
         let bytes = [1u8, 3, 48, 90, 90];
 
-        let _ = bytes.view_as::<Foo>(0);
-        let _ = bytes.view_as::<Foo>(1);
+        let _ = bytes.try_view_as::<Foo>(0).unwrap();
+        let _ = bytes.try_view_as::<Foo>(1).unwrap();
     }
 
     #[test]
@@ -934,14 +1116,60 @@ mod tests {
     fn panic_on_too_few_bytes() {
         let bytes = [1u8, 3];
 
-        let _ = bytes.view_as::<Foo>(0);
+        let _ = bytes.try_view_as::<Foo>(0).unwrap();
     }
 
     #[test]
     fn copy_bytes_as_type() {
         let bytes = [1u8, 3];
 
-        let foo = bytes.copy_as::<u16>(0);
+        let foo = bytes.try_copy_as::<u16>(0).unwrap();
         assert_eq!(foo, 0x0301);
     }
+
+    #[test]
+    fn reject_offset_overflow() {
+        let bytes = [1u8, 3];
+
+        assert!(bytes.try_copy_as::<u16>(u32::MAX).is_err());
+        assert!(bytes.try_view_as_slice_of::<Foo>(0, u32::MAX).is_err());
+        assert!(bytes.try_view_as_str(3).is_err());
+    }
+
+    #[test]
+    fn reject_malformed_files() {
+        let bytes = include_bytes!("../../default/Windows.WinRT.winmd");
+        assert!(File::try_from_bytes(bytes.to_vec()).is_ok());
+
+        assert!(File::try_from_bytes(Vec::new()).is_err());
+        assert!(File::try_from_bytes(vec![0xFF; 1024]).is_err());
+
+        for len in (0..4096).step_by(7) {
+            assert!(File::try_from_bytes(bytes[..len].to_vec()).is_err());
+        }
+
+        let mut corrupt = bytes.to_vec();
+        corrupt[0x3C..0x40].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(File::try_from_bytes(corrupt).is_err());
+    }
+
+    #[test]
+    fn reject_out_of_range_reads() {
+        let bytes = include_bytes!("../../default/Windows.WinRT.winmd");
+        let file = File::try_from_bytes(bytes.to_vec()).unwrap();
+
+        assert_eq!(file.try_str(TableIndex::Module, 0, 1), Ok("Windows.winmd"));
+        assert!(file.try_blob(TableIndex::TypeSpec, 0, 0).is_ok());
+
+        let rows = file.row_count(TableIndex::TypeDef);
+        assert!(file.try_u32(TableIndex::TypeDef, rows, 0).is_err());
+        assert!(file.try_u32(TableIndex::TypeDef, 0, 6).is_err());
+        assert!(file.try_u32(TableIndex::ClassLayout, 0, 5).is_err());
+
+        let mut corrupt = file;
+        corrupt.strings = u32::MAX;
+        assert!(corrupt.try_str(TableIndex::Module, 0, 1).is_err());
+        corrupt.blobs = corrupt.bytes.len() as u32;
+        assert!(corrupt.try_blob(TableIndex::TypeSpec, 0, 0).is_err());
+    }
 }
@@ -25,6 +25,9 @@ impl TypeFlags {
     pub fn explicit(&self) -> bool {
         self.0 & 0b1_0000 != 0
     }
+    pub fn sealed(&self) -> bool {
+        self.0 & 0b1_0000_0000 != 0
+    }
 }
 
 impl ParamFlags {
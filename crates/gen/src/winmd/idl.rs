@@ -0,0 +1,1226 @@
+use super::*;
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::TryFrom;
+
+/// The error returned when a MIDL 3.0 file cannot be compiled to metadata
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidIdl {
+    /// The one-based line on which the error was found
+    pub line: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for InvalidIdl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid IDL on line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for InvalidIdl {}
+
+/// Compiles MIDL 3.0 describing WinRT interfaces, delegates, enums, and structs to the bytes of
+/// an equivalent `.winmd` file, so that SDKs that ship IDL rather than metadata can be read
+/// alongside any other metadata.
+///
+/// Named types are resolved by name once the metadata is read, so they may be defined in the
+/// same file or in any other metadata. Unqualified names refer to the enclosing namespace.
+/// Interfaces and delegates must be given a `[uuid(...)]` since runtime classes, which MIDL
+/// would otherwise derive them from, are not supported.
+///
+/// ```
+/// use windows_gen::winmd::*;
+///
+/// let bytes = compile_idl(
+///     "Contoso.winmd",
+///     r#"
+///     namespace Contoso
+///     {
+///         [uuid(5a6e2f0c-7c39-4d55-8e8b-2c6f0e8d9c11)]
+///         interface IWidget
+///         {
+///             String Name;
+///             Int32 Measure(Double scale, out Boolean clamped);
+///         };
+///     }
+///     "#,
+/// )
+/// .unwrap();
+///
+/// assert!(File::try_from_bytes(bytes).is_ok());
+/// ```
+pub fn compile_idl(module_name: &str, source: &str) -> Result<Vec<u8>, InvalidIdl> {
+    let mut parser = Parser {
+        chars: source.chars().collect(),
+        position: 0,
+        line: 1,
+    };
+
+    let items = parser.parse_file()?;
+    Ok(Emitter::new(module_name, &items).emit(&items))
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(u64),
+    String(String),
+    Punct(char),
+    End,
+}
+
+#[derive(Clone, Debug)]
+enum Kind {
+    Void,
+    Element(u8),
+    Named(String, String, Vec<Kind>),
+}
+
+#[derive(Clone, Debug)]
+struct IdlType {
+    kind: Kind,
+    is_array: bool,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Direction {
+    In,
+    Out,
+    Ref,
+}
+
+struct Param {
+    direction: Direction,
+    t: IdlType,
+    name: String,
+}
+
+enum Member {
+    Method {
+        name: String,
+        overload: Option<String>,
+        return_type: IdlType,
+        params: Vec<Param>,
+    },
+    Property {
+        name: String,
+        t: IdlType,
+        get: bool,
+        set: bool,
+    },
+    Event {
+        name: String,
+        t: IdlType,
+    },
+}
+
+enum Item {
+    Enum {
+        name: String,
+        flags: bool,
+        values: Vec<(String, u32)>,
+    },
+    Struct {
+        name: String,
+        fields: Vec<(IdlType, String)>,
+    },
+    Interface {
+        name: String,
+        guid: [u8; 16],
+        requires: Vec<IdlType>,
+        members: Vec<Member>,
+    },
+    Delegate {
+        name: String,
+        guid: [u8; 16],
+        return_type: IdlType,
+        params: Vec<Param>,
+    },
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+    line: usize,
+}
+
+impl Parser {
+    fn error<T>(&self, message: String) -> Result<T, InvalidIdl> {
+        Err(InvalidIdl {
+            line: self.line,
+            message,
+        })
+    }
+
+    fn skip_trivia(&mut self) {
+        loop {
+            match self.chars.get(self.position) {
+                Some('\n') => {
+                    self.line += 1;
+                    self.position += 1;
+                }
+                Some(c) if c.is_whitespace() => self.position += 1,
+                Some('/') if self.chars.get(self.position + 1) == Some(&'/') => {
+                    while !matches!(self.chars.get(self.position), Some('\n') | None) {
+                        self.position += 1;
+                    }
+                }
+                Some('/') if self.chars.get(self.position + 1) == Some(&'*') => {
+                    self.position += 2;
+
+                    while self.position < self.chars.len()
+                        && !(self.chars[self.position] == '*'
+                            && self.chars.get(self.position + 1) == Some(&'/'))
+                    {
+                        if self.chars[self.position] == '\n' {
+                            self.line += 1;
+                        }
+
+                        self.position += 1;
+                    }
+
+                    self.position += 2;
+                }
+                // Preprocessor directives such as `#include` don't affect the types described.
+                Some('#') => {
+                    while !matches!(self.chars.get(self.position), Some('\n') | None) {
+                        self.position += 1;
+                    }
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn next(&mut self) -> Result<Token, InvalidIdl> {
+        self.skip_trivia();
+
+        let c = match self.chars.get(self.position) {
+            Some(c) => *c,
+            None => return Ok(Token::End),
+        };
+
+        let start = self.position;
+
+        if c.is_alphabetic() || c == '_' {
+            while matches!(self.chars.get(self.position), Some(c) if c.is_alphanumeric() || *c == '_')
+            {
+                self.position += 1;
+            }
+
+            Ok(Token::Ident(
+                self.chars[start..self.position].iter().collect(),
+            ))
+        } else if c.is_ascii_digit() {
+            while matches!(self.chars.get(self.position), Some(c) if c.is_ascii_alphanumeric()) {
+                self.position += 1;
+            }
+
+            let literal: String = self.chars[start..self.position].iter().collect();
+
+            let value = match literal
+                .strip_prefix("0x")
+                .or_else(|| literal.strip_prefix("0X"))
+            {
+                Some(hex) => u64::from_str_radix(hex, 16),
+                None => literal.parse(),
+            };
+
+            match value {
+                Ok(value) => Ok(Token::Number(value)),
+                Err(_) => self.error(format!("invalid number `{}`", literal)),
+            }
+        } else if c == '"' {
+            self.position += 1;
+
+            while !matches!(self.chars.get(self.position), Some('"') | Some('\n') | None) {
+                self.position += 1;
+            }
+
+            if self.chars.get(self.position) != Some(&'"') {
+                return self.error("unterminated string".to_string());
+            }
+
+            self.position += 1;
+            Ok(Token::String(
+                self.chars[start + 1..self.position - 1].iter().collect(),
+            ))
+        } else {
+            self.position += 1;
+            Ok(Token::Punct(c))
+        }
+    }
+
+    fn peek(&mut self) -> Result<Token, InvalidIdl> {
+        let (position, line) = (self.position, self.line);
+        let token = self.next();
+        self.position = position;
+        self.line = line;
+        token
+    }
+
+    fn eat(&mut self, token: Token) -> Result<bool, InvalidIdl> {
+        if self.peek()? == token {
+            self.next()?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn eat_punct(&mut self, c: char) -> Result<bool, InvalidIdl> {
+        self.eat(Token::Punct(c))
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> Result<bool, InvalidIdl> {
+        self.eat(Token::Ident(keyword.to_string()))
+    }
+
+    fn expect_punct(&mut self, c: char) -> Result<(), InvalidIdl> {
+        match self.next()? {
+            Token::Punct(found) if found == c => Ok(()),
+            found => self.error(format!("expected `{}` but found {:?}", c, found)),
+        }
+    }
+
+    fn expect_ident(&mut self) -> Result<String, InvalidIdl> {
+        match self.next()? {
+            Token::Ident(ident) => Ok(ident),
+            found => self.error(format!("expected a name but found {:?}", found)),
+        }
+    }
+
+    fn expect_dotted(&mut self) -> Result<String, InvalidIdl> {
+        let mut name = self.expect_ident()?;
+
+        while self.eat_punct('.')? {
+            name.push('.');
+            name.push_str(&self.expect_ident()?);
+        }
+
+        Ok(name)
+    }
+
+    // Returns the source text up to the closing parenthesis, which lets a `uuid` be given
+    // with or without quotes.
+    fn raw_argument(&mut self) -> Result<String, InvalidIdl> {
+        let start = self.position;
+
+        while !matches!(self.chars.get(self.position), Some(')') | None) {
+            if self.chars[self.position] == '\n' {
+                self.line += 1;
+            }
+
+            self.position += 1;
+        }
+
+        let raw: String = self.chars[start..self.position].iter().collect();
+        self.expect_punct(')')?;
+        Ok(raw.trim().trim_matches('"').to_string())
+    }
+
+    fn parse_attributes(&mut self) -> Result<BTreeMap<String, String>, InvalidIdl> {
+        let mut attributes = BTreeMap::new();
+
+        while self.eat_punct('[')? {
+            loop {
+                let name = self.expect_ident()?;
+
+                let value = if self.eat_punct('(')? {
+                    self.raw_argument()?
+                } else {
+                    String::new()
+                };
+
+                attributes.insert(name, value);
+
+                if !self.eat_punct(',')? {
+                    break;
+                }
+            }
+
+            self.expect_punct(']')?;
+        }
+
+        Ok(attributes)
+    }
+
+    fn parse_file(&mut self) -> Result<Vec<(String, Item)>, InvalidIdl> {
+        let mut items = Vec::new();
+
+        loop {
+            match self.next()? {
+                Token::End => return Ok(items),
+                Token::Ident(keyword) if keyword == "import" => {
+                    self.next()?;
+                    self.expect_punct(';')?;
+                }
+                Token::Ident(keyword) if keyword == "namespace" => {
+                    let namespace = self.expect_dotted()?;
+                    self.parse_namespace(&namespace, &mut items)?;
+                }
+                found => return self.error(format!("expected a namespace but found {:?}", found)),
+            }
+        }
+    }
+
+    fn parse_namespace(
+        &mut self,
+        namespace: &str,
+        items: &mut Vec<(String, Item)>,
+    ) -> Result<(), InvalidIdl> {
+        self.expect_punct('{')?;
+
+        while !self.eat_punct('}')? {
+            let attributes = self.parse_attributes()?;
+
+            let item = match self.expect_ident()?.as_str() {
+                "namespace" => {
+                    let nested = format!("{}.{}", namespace, self.expect_dotted()?);
+                    self.parse_namespace(&nested, items)?;
+                    continue;
+                }
+                "enum" => self.parse_enum(attributes.contains_key("flags"))?,
+                "struct" => self.parse_struct(namespace)?,
+                "interface" => self.parse_interface(namespace, &attributes)?,
+                "delegate" => self.parse_delegate(namespace, &attributes)?,
+                "runtimeclass" | "unsealed" | "static" => {
+                    return self.error("runtime classes are not supported".to_string())
+                }
+                keyword => return self.error(format!("unexpected `{}`", keyword)),
+            };
+
+            self.eat_punct(';')?;
+            items.push((namespace.to_string(), item));
+        }
+
+        Ok(())
+    }
+
+    fn parse_enum(&mut self, flags: bool) -> Result<Item, InvalidIdl> {
+        let name = self.expect_ident()?;
+        let mut values = Vec::new();
+        let mut next = 0i64;
+
+        self.expect_punct('{')?;
+
+        while !self.eat_punct('}')? {
+            let value_name = self.expect_ident()?;
+
+            if self.eat_punct('=')? {
+                let negative = self.eat_punct('-')?;
+
+                next = match self.next()? {
+                    Token::Number(value) if negative => -(value as i64),
+                    Token::Number(value) => value as i64,
+                    found => return self.error(format!("expected a value but found {:?}", found)),
+                };
+            }
+
+            // Enums are 32-bit, signed unless they're flags.
+            let value = if flags {
+                u32::try_from(next).ok()
+            } else {
+                i32::try_from(next).ok().map(|value| value as u32)
+            };
+
+            match value {
+                Some(value) => values.push((value_name, value)),
+                None => return self.error(format!("`{}` is out of range", value_name)),
+            }
+
+            next += 1;
+
+            if !self.eat_punct(',')? {
+                self.expect_punct('}')?;
+                break;
+            }
+        }
+
+        Ok(Item::Enum {
+            name,
+            flags,
+            values,
+        })
+    }
+
+    fn parse_struct(&mut self, namespace: &str) -> Result<Item, InvalidIdl> {
+        let name = self.expect_ident()?;
+        let mut fields = Vec::new();
+
+        self.expect_punct('{')?;
+
+        while !self.eat_punct('}')? {
+            let t = self.parse_type(namespace)?;
+            fields.push((t, self.expect_ident()?));
+            self.expect_punct(';')?;
+        }
+
+        Ok(Item::Struct { name, fields })
+    }
+
+    fn parse_guid(&self, attributes: &BTreeMap<String, String>) -> Result<[u8; 16], InvalidIdl> {
+        match attributes.get("uuid") {
+            Some(uuid) => match parse_guid(uuid) {
+                Some(guid) => Ok(guid),
+                None => self.error(format!("invalid uuid `{}`", uuid)),
+            },
+            None => self.error("interfaces and delegates require a uuid".to_string()),
+        }
+    }
+
+    fn parse_interface(
+        &mut self,
+        namespace: &str,
+        attributes: &BTreeMap<String, String>,
+    ) -> Result<Item, InvalidIdl> {
+        let guid = self.parse_guid(attributes)?;
+        let name = self.expect_ident()?;
+        let mut requires = Vec::new();
+
+        if self.peek()? == Token::Punct('<') {
+            return self.error("generic interfaces are not supported".to_string());
+        }
+
+        if self.eat_keyword("requires")? {
+            loop {
+                requires.push(self.parse_type(namespace)?);
+
+                if !self.eat_punct(',')? {
+                    break;
+                }
+            }
+        }
+
+        let mut members = Vec::new();
+        self.expect_punct('{')?;
+
+        while !self.eat_punct('}')? {
+            let attributes = self.parse_attributes()?;
+
+            if self.eat_keyword("event")? {
+                let t = self.parse_type(namespace)?;
+                let name = self.expect_ident()?;
+                self.expect_punct(';')?;
+                members.push(Member::Event { name, t });
+                continue;
+            }
+
+            let t = self.parse_type(namespace)?;
+            let name = self.expect_ident()?;
+
+            if self.eat_punct('(')? {
+                let params = self.parse_params(namespace)?;
+                self.expect_punct(';')?;
+
+                members.push(Member::Method {
+                    name,
+                    overload: attributes.get("method_name").cloned(),
+                    return_type: t,
+                    params,
+                });
+            } else if self.eat_punct('{')? {
+                let (mut get, mut set) = (false, false);
+
+                while !self.eat_punct('}')? {
+                    match self.expect_ident()?.as_str() {
+                        "get" => get = true,
+                        "set" => set = true,
+                        accessor => return self.error(format!("unexpected `{}`", accessor)),
+                    }
+
+                    self.expect_punct(';')?;
+                }
+
+                self.eat_punct(';')?;
+                members.push(Member::Property { name, t, get, set });
+            } else {
+                self.expect_punct(';')?;
+                members.push(Member::Property {
+                    name,
+                    t,
+                    get: true,
+                    set: true,
+                });
+            }
+        }
+
+        Ok(Item::Interface {
+            name,
+            guid,
+            requires,
+            members,
+        })
+    }
+
+    fn parse_delegate(
+        &mut self,
+        namespace: &str,
+        attributes: &BTreeMap<String, String>,
+    ) -> Result<Item, InvalidIdl> {
+        let guid = self.parse_guid(attributes)?;
+        let return_type = self.parse_type(namespace)?;
+        let name = self.expect_ident()?;
+        self.expect_punct('(')?;
+        let params = self.parse_params(namespace)?;
+
+        Ok(Item::Delegate {
+            name,
+            guid,
+            return_type,
+            params,
+        })
+    }
+
+    fn parse_params(&mut self, namespace: &str) -> Result<Vec<Param>, InvalidIdl> {
+        let mut params = Vec::new();
+
+        if self.eat_punct(')')? {
+            return Ok(params);
+        }
+
+        loop {
+            let direction = if self.eat_keyword("out")? {
+                Direction::Out
+            } else if self.eat_keyword("ref")? {
+                Direction::Ref
+            } else {
+                Direction::In
+            };
+
+            let t = self.parse_type(namespace)?;
+
+            if direction == Direction::Ref && !t.is_array {
+                return self.error("only arrays may be passed by `ref`".to_string());
+            }
+
+            params.push(Param {
+                direction,
+                t,
+                name: self.expect_ident()?,
+            });
+
+            if !self.eat_punct(',')? {
+                self.expect_punct(')')?;
+                return Ok(params);
+            }
+        }
+    }
+
+    fn parse_type(&mut self, namespace: &str) -> Result<IdlType, InvalidIdl> {
+        let kind = self.parse_kind(namespace)?;
+
+        let is_array = if self.eat_punct('[')? {
+            self.expect_punct(']')?;
+            true
+        } else {
+            false
+        };
+
+        Ok(IdlType { kind, is_array })
+    }
+
+    fn parse_kind(&mut self, namespace: &str) -> Result<Kind, InvalidIdl> {
+        let name = self.expect_dotted()?;
+
+        let kind = match name.as_str() {
+            "void" => Kind::Void,
+            "Boolean" => Kind::Element(0x02),
+            "Char" => Kind::Element(0x03),
+            "Int8" => Kind::Element(0x04),
+            "UInt8" => Kind::Element(0x05),
+            "Int16" => Kind::Element(0x06),
+            "UInt16" => Kind::Element(0x07),
+            "Int32" => Kind::Element(0x08),
+            "UInt32" => Kind::Element(0x09),
+            "Int64" => Kind::Element(0x0A),
+            "UInt64" => Kind::Element(0x0B),
+            "Single" => Kind::Element(0x0C),
+            "Double" => Kind::Element(0x0D),
+            "String" => Kind::Element(0x0E),
+            "Object" => Kind::Element(0x1C),
+            "Guid" => Kind::Named("System".to_string(), "Guid".to_string(), Vec::new()),
+            _ => {
+                let (namespace, type_name) = match name.rfind('.') {
+                    Some(index) => (name[..index].to_string(), name[index + 1..].to_string()),
+                    None => (namespace.to_string(), name),
+                };
+
+                let mut generics = Vec::new();
+
+                if self.eat_punct('<')? {
+                    loop {
+                        generics.push(self.parse_kind(&namespace)?);
+
+                        if !self.eat_punct(',')? {
+                            break;
+                        }
+                    }
+
+                    self.expect_punct('>')?;
+                }
+
+                // Generic types are named with their arity in metadata.
+                let type_name = if generics.is_empty() {
+                    type_name
+                } else {
+                    format!("{}`{}", type_name, generics.len())
+                };
+
+                Kind::Named(namespace, type_name, generics)
+            }
+        };
+
+        Ok(kind)
+    }
+}
+
+fn parse_guid(value: &str) -> Option<[u8; 16]> {
+    let parts: Vec<&str> = value.split('-').collect();
+
+    let lengths: Vec<usize> = parts.iter().map(|part| part.len()).collect();
+
+    if lengths != [8, 4, 4, 4, 12] {
+        return None;
+    }
+
+    let a = u32::from_str_radix(parts[0], 16).ok()?;
+    let b = u16::from_str_radix(parts[1], 16).ok()?;
+    let c = u16::from_str_radix(parts[2], 16).ok()?;
+    let rest = format!("{}{}", parts[3], parts[4]);
+
+    let mut guid = [0; 16];
+    guid[0..4].copy_from_slice(&a.to_le_bytes());
+    guid[4..6].copy_from_slice(&b.to_le_bytes());
+    guid[6..8].copy_from_slice(&c.to_le_bytes());
+
+    for (index, byte) in guid[8..].iter_mut().enumerate() {
+        *byte = u8::from_str_radix(rest.get(index * 2..index * 2 + 2)?, 16).ok()?;
+    }
+
+    Some(guid)
+}
+
+struct Emitter {
+    writer: Writer,
+    type_refs: BTreeMap<(String, String), u32>,
+    value_types: BTreeSet<(String, String)>,
+    guid_attribute: u32,
+    overload_attribute: u32,
+}
+
+impl Emitter {
+    fn new(module_name: &str, items: &[(String, Item)]) -> Self {
+        let mut emitter = Self {
+            writer: Writer::new(module_name),
+            type_refs: BTreeMap::new(),
+            value_types: BTreeSet::new(),
+            guid_attribute: 0,
+            overload_attribute: 0,
+        };
+
+        emitter
+            .value_types
+            .insert(("System".to_string(), "Guid".to_string()));
+
+        for (namespace, item) in items {
+            if let Item::Enum { name, .. } | Item::Struct { name, .. } = item {
+                emitter
+                    .value_types
+                    .insert((namespace.clone(), name.clone()));
+            }
+        }
+
+        emitter.guid_attribute = emitter.attribute_constructor(
+            "GuidAttribute",
+            &[
+                0x09, 0x07, 0x07, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+            ],
+        );
+
+        emitter.overload_attribute = emitter.attribute_constructor("OverloadAttribute", &[0x0E]);
+        emitter
+    }
+
+    fn attribute_constructor(&mut self, name: &str, params: &[u8]) -> u32 {
+        let attribute = self.type_ref("Windows.Foundation.Metadata", name);
+        let parent = CodedIndex::MemberRefParent.encode(MetadataTable::TypeRef, attribute);
+        let ctor = self.writer.string(".ctor");
+
+        let mut signature = vec![0x20];
+        compress_unsigned(params.len() as u32, &mut signature);
+        signature.push(0x01);
+        signature.extend_from_slice(params);
+        let signature = self.writer.blob(&signature);
+
+        let ctor = self
+            .writer
+            .push(MetadataTable::MemberRef, &[parent, ctor, signature]);
+
+        CodedIndex::CustomAttributeType.encode(MetadataTable::MemberRef, ctor)
+    }
+
+    // Types are always referenced by name, even those defined in the same file, so that
+    // they resolve the same way as types defined in other metadata.
+    fn type_ref(&mut self, namespace: &str, name: &str) -> u32 {
+        let key = (namespace.to_string(), name.to_string());
+
+        if let Some(row) = self.type_refs.get(&key) {
+            return *row;
+        }
+
+        let row = self.writer.type_ref(namespace, name);
+        self.type_refs.insert(key, row);
+        row
+    }
+
+    fn encode_kind(&mut self, kind: &Kind, signature: &mut Vec<u8>) {
+        match kind {
+            Kind::Void => signature.push(0x01),
+            Kind::Element(code) => signature.push(*code),
+            Kind::Named(namespace, name, generics) => {
+                let row = self.type_ref(namespace, name);
+                let code = CodedIndex::TypeDefOrRef.encode(MetadataTable::TypeRef, row);
+
+                let element = if self
+                    .value_types
+                    .contains(&(namespace.clone(), name.clone()))
+                {
+                    0x11
+                } else {
+                    0x12
+                };
+
+                if generics.is_empty() {
+                    signature.push(element);
+                    compress_unsigned(code, signature);
+                } else {
+                    signature.extend_from_slice(&[0x15, element]);
+                    compress_unsigned(code, signature);
+                    compress_unsigned(generics.len() as u32, signature);
+
+                    for generic in generics {
+                        self.encode_kind(generic, signature);
+                    }
+                }
+            }
+        }
+    }
+
+    fn encode_type(&mut self, t: &IdlType, by_ref: bool, signature: &mut Vec<u8>) {
+        if by_ref {
+            signature.push(0x10);
+        }
+
+        if t.is_array {
+            signature.push(0x1D);
+        }
+
+        self.encode_kind(&t.kind, signature);
+    }
+
+    fn type_def_or_ref(&mut self, t: &IdlType) -> u32 {
+        match &t.kind {
+            Kind::Named(namespace, name, generics) if generics.is_empty() => {
+                let row = self.type_ref(namespace, name);
+                CodedIndex::TypeDefOrRef.encode(MetadataTable::TypeRef, row)
+            }
+            kind => {
+                let mut signature = Vec::new();
+                self.encode_kind(kind, &mut signature);
+                let signature = self.writer.blob(&signature);
+                let row = self.writer.push(MetadataTable::TypeSpec, &[signature]);
+                CodedIndex::TypeDefOrRef.encode(MetadataTable::TypeSpec, row)
+            }
+        }
+    }
+
+    fn type_def(&mut self, flags: u32, namespace: &str, name: &str, extends: Option<&str>) -> u32 {
+        let extends = match extends {
+            Some(extends) => {
+                let row = self.type_ref("System", extends);
+                CodedIndex::TypeDefOrRef.encode(MetadataTable::TypeRef, row)
+            }
+            None => 0,
+        };
+
+        let name = self.writer.string(name);
+        let namespace = self.writer.string(namespace);
+        let fields = self.writer.row_count(MetadataTable::Field) + 1;
+        let methods = self.writer.row_count(MetadataTable::MethodDef) + 1;
+
+        self.writer.push(
+            MetadataTable::TypeDef,
+            &[flags, name, namespace, extends, fields, methods],
+        )
+    }
+
+    fn field(&mut self, flags: u32, name: &str, t: &IdlType) -> u32 {
+        let mut signature = vec![0x06];
+        self.encode_type(t, false, &mut signature);
+        let signature = self.writer.blob(&signature);
+        let name = self.writer.string(name);
+
+        self.writer
+            .push(MetadataTable::Field, &[flags, name, signature])
+    }
+
+    fn guid(&mut self, def: u32, guid: &[u8; 16]) {
+        let mut args = vec![
+            ConstantValue::U32(u32::from_le_bytes([guid[0], guid[1], guid[2], guid[3]])),
+            ConstantValue::U16(u16::from_le_bytes([guid[4], guid[5]])),
+            ConstantValue::U16(u16::from_le_bytes([guid[6], guid[7]])),
+        ];
+
+        args.extend(guid[8..].iter().map(|byte| ConstantValue::U8(*byte)));
+
+        self.writer.attribute(
+            CodedIndex::HasCustomAttribute.encode(MetadataTable::TypeDef, def),
+            self.guid_attribute,
+            &args,
+        );
+    }
+
+    fn method(&mut self, flags: u32, name: &str, return_type: &IdlType, params: &[Param]) -> u32 {
+        let mut signature = vec![0x20];
+        compress_unsigned(params.len() as u32, &mut signature);
+        self.encode_type(return_type, false, &mut signature);
+
+        let first_param = self.writer.row_count(MetadataTable::Param) + 1;
+
+        for (sequence, param) in params.iter().enumerate() {
+            // A `ref` array is allocated by the caller and filled by the callee, so only an
+            // `out` parameter is passed by reference.
+            self.encode_type(&param.t, param.direction == Direction::Out, &mut signature);
+
+            let flags = if param.direction == Direction::In {
+                0x0001
+            } else {
+                0x0002
+            };
+
+            let name = self.writer.string(&param.name);
+            self.writer
+                .push(MetadataTable::Param, &[flags, sequence as u32 + 1, name]);
+        }
+
+        let signature = self.writer.blob(&signature);
+        let name = self.writer.string(name);
+
+        // Delegate methods are implemented by the runtime.
+        let impl_flags = if flags & 0x0400 == 0 { 0x0003 } else { 0 };
+
+        self.writer.push(
+            MetadataTable::MethodDef,
+            &[0, impl_flags, flags, name, signature, first_param],
+        )
+    }
+
+    fn emit(mut self, items: &[(String, Item)]) -> Vec<u8> {
+        const INTERFACE_METHOD: u32 = 0x05C6;
+        const SPECIAL_NAME: u32 = 0x0800;
+
+        for (namespace, item) in items {
+            match item {
+                Item::Enum {
+                    name,
+                    flags,
+                    values,
+                } => {
+                    self.type_def(0x4101, namespace, name, Some("Enum"));
+                    let underlying = if *flags { 0x09 } else { 0x08 };
+
+                    let underlying_type = IdlType {
+                        kind: Kind::Element(underlying),
+                        is_array: false,
+                    };
+
+                    self.field(0x0606, "value__", &underlying_type);
+
+                    let enum_type = IdlType {
+                        kind: Kind::Named(namespace.clone(), name.clone(), Vec::new()),
+                        is_array: false,
+                    };
+
+                    for (value_name, value) in values {
+                        let field = self.field(0x8056, value_name, &enum_type);
+                        let parent = CodedIndex::HasConstant.encode(MetadataTable::Field, field);
+                        let value = self.writer.blob(&value.to_le_bytes());
+
+                        self.writer
+                            .push(MetadataTable::Constant, &[underlying as u32, parent, value]);
+                    }
+                }
+                Item::Struct { name, fields } => {
+                    self.type_def(0x4109, namespace, name, Some("ValueType"));
+
+                    for (t, field_name) in fields {
+                        self.field(0x0006, field_name, t);
+                    }
+                }
+                Item::Interface {
+                    name,
+                    guid,
+                    requires,
+                    members,
+                } => {
+                    let def = self.type_def(0x40A1, namespace, name, None);
+                    self.guid(def, guid);
+
+                    for required in requires {
+                        let interface = self.type_def_or_ref(required);
+                        self.writer
+                            .push(MetadataTable::InterfaceImpl, &[def, interface]);
+                    }
+
+                    for member in members {
+                        self.member(member, INTERFACE_METHOD, SPECIAL_NAME);
+                    }
+                }
+                Item::Delegate {
+                    name,
+                    guid,
+                    return_type,
+                    params,
+                } => {
+                    let def = self.type_def(0x4101, namespace, name, Some("MulticastDelegate"));
+                    self.guid(def, guid);
+
+                    let object = IdlType {
+                        kind: Kind::Element(0x1C),
+                        is_array: false,
+                    };
+
+                    let method = IdlType {
+                        kind: Kind::Element(0x18),
+                        is_array: false,
+                    };
+
+                    let ctor_params = [
+                        Param {
+                            direction: Direction::In,
+                            t: object,
+                            name: "object".to_string(),
+                        },
+                        Param {
+                            direction: Direction::In,
+                            t: method,
+                            name: "method".to_string(),
+                        },
+                    ];
+
+                    let void = IdlType {
+                        kind: Kind::Void,
+                        is_array: false,
+                    };
+
+                    self.method(0x1886, ".ctor", &void, &ctor_params);
+                    self.method(0x01C6, "Invoke", return_type, params);
+                }
+            }
+        }
+
+        self.writer.into_bytes()
+    }
+
+    fn member(&mut self, member: &Member, flags: u32, special_name: u32) {
+        let void = IdlType {
+            kind: Kind::Void,
+            is_array: false,
+        };
+
+        match member {
+            Member::Method {
+                name,
+                overload,
+                return_type,
+                params,
+            } => {
+                let method = self.method(flags, name, return_type, params);
+
+                if let Some(overload) = overload {
+                    self.writer.attribute(
+                        CodedIndex::HasCustomAttribute.encode(MetadataTable::MethodDef, method),
+                        self.overload_attribute,
+                        &[ConstantValue::String(overload.clone())],
+                    );
+                }
+            }
+            Member::Property { name, t, get, set } => {
+                if *get {
+                    self.method(flags | special_name, &format!("get_{}", name), t, &[]);
+                }
+
+                if *set {
+                    let value = Param {
+                        direction: Direction::In,
+                        t: t.clone(),
+                        name: "value".to_string(),
+                    };
+
+                    self.method(
+                        flags | special_name,
+                        &format!("put_{}", name),
+                        &void,
+                        &[value],
+                    );
+                }
+            }
+            Member::Event { name, t } => {
+                let token = IdlType {
+                    kind: Kind::Named(
+                        "Windows.Foundation".to_string(),
+                        "EventRegistrationToken".to_string(),
+                        Vec::new(),
+                    ),
+                    is_array: false,
+                };
+
+                self.value_types.insert((
+                    "Windows.Foundation".to_string(),
+                    "EventRegistrationToken".to_string(),
+                ));
+
+                let handler = Param {
+                    direction: Direction::In,
+                    t: t.clone(),
+                    name: "handler".to_string(),
+                };
+
+                self.method(
+                    flags | special_name,
+                    &format!("add_{}", name),
+                    &token,
+                    &[handler],
+                );
+
+                let token_param = Param {
+                    direction: Direction::In,
+                    t: token,
+                    name: "token".to_string(),
+                };
+
+                self.method(
+                    flags | special_name,
+                    &format!("remove_{}", name),
+                    &void,
+                    &[token_param],
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    const IDL: &str = r#"
+        import "Windows.Foundation.idl";
+
+        namespace Contoso.Widgets
+        {
+            [flags]
+            enum Features
+            {
+                None = 0,
+                Spin = 0x1,
+                Glow = 0x2,
+            };
+
+            struct Size
+            {
+                Int32 Width;
+                Int32 Height;
+            };
+
+            [uuid("0c5e4ad9-5a4b-4f0e-9a16-1c4fd4b7f0a3")]
+            delegate void MeasuredHandler(IWidget sender, Size size);
+
+            [uuid(5a6e2f0c-7c39-4d55-8e8b-2c6f0e8d9c11)]
+            interface IWidget requires Windows.Foundation.IStringable
+            {
+                String Name;
+                Features Features { get; };
+                event MeasuredHandler Measured;
+                Size Measure(Double scale, out Boolean clamped);
+                [method_name("MeasureAll")] void Measure(Size[] sizes, ref Int32[] results, out String[] names);
+            };
+        }
+    "#;
+
+    fn reader() -> &'static TypeReader {
+        let file = File::try_from_bytes(compile_idl("Contoso.winmd", IDL).unwrap()).unwrap();
+
+        let winrt =
+            File::try_from_bytes(include_bytes!("../../default/Windows.WinRT.winmd").to_vec())
+                .unwrap();
+
+        Box::leak(Box::new(
+            TypeReader::try_from_files(vec![winrt, file]).unwrap(),
+        ))
+    }
+
+    #[test]
+    fn compile() {
+        let reader = reader();
+
+        let features = reader.expect_type_def(("Contoso.Widgets", "Features"));
+        assert!(features.category() == TypeCategory::Enum);
+        assert!(matches!(features.underlying_type(), ElementType::U32));
+
+        let size = reader.expect_type_def(("Contoso.Widgets", "Size"));
+        assert!(size.category() == TypeCategory::Struct);
+
+        let handler = reader.expect_type_def(("Contoso.Widgets", "MeasuredHandler"));
+        assert!(handler.category() == TypeCategory::Delegate);
+
+        let widget = reader.expect_type_def(("Contoso.Widgets", "IWidget"));
+        assert!(widget.category() == TypeCategory::Interface);
+        assert!(widget.is_winrt());
+
+        let methods: Vec<&str> = widget.methods().map(|method| method.name()).collect();
+        assert_eq!(
+            methods,
+            [
+                "get_Name",
+                "put_Name",
+                "get_Features",
+                "add_Measured",
+                "remove_Measured",
+                "Measure",
+                "Measure"
+            ]
+        );
+
+        let tokens = TypeDefinition::from_type_def(&widget).gen().into_string();
+        assert!(tokens.contains("pub fn measure_all"));
+        assert!(tokens.contains("pub fn set_name"));
+        assert!(tokens.contains("pub fn measured"));
+
+        let tokens = TypeDefinition::from_type_def(&features).gen().into_string();
+        assert!(tokens.contains("Glow"));
+    }
+
+    #[test]
+    fn reject() {
+        let error = compile_idl(
+            "Test.winmd",
+            "namespace Test\n{\n    interface IMissing {};\n}",
+        )
+        .unwrap_err();
+
+        assert_eq!(error.line, 3);
+
+        let error =
+            compile_idl("Test.winmd", "namespace Test { runtimeclass Foo {} }").unwrap_err();
+
+        assert_eq!(error.message, "runtime classes are not supported");
+
+        assert!(compile_idl(
+            "Test.winmd",
+            "namespace Test { enum E { A = 0x100000000 } }"
+        )
+        .is_err());
+    }
+}
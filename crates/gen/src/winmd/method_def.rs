@@ -58,6 +58,10 @@ impl MethodDef {
             })
     }
 
+    pub fn has_attribute(&self, name: (&str, &str)) -> bool {
+        self.attributes().any(|attribute| attribute.name() == name)
+    }
+
     pub fn impl_map(&self) -> Option<ImplMap> {
         self.reader
             .equal_range(
@@ -1,6 +1,7 @@
 mod assembly_ref;
 mod attribute;
 mod blob;
+mod class_layout;
 mod codes;
 mod constant;
 mod constant_value;
@@ -9,6 +10,7 @@ mod field;
 mod file;
 mod flags;
 mod generic_param;
+mod idl;
 mod impl_map;
 mod interface_impl;
 mod member_ref;
@@ -24,10 +26,12 @@ mod type_reader;
 mod type_ref;
 mod type_spec;
 mod workspace;
+mod writer;
 
 pub use assembly_ref::*;
 pub use attribute::*;
 pub use blob::*;
+pub use class_layout::*;
 pub use codes::*;
 pub use constant::*;
 pub use constant_value::*;
@@ -36,6 +40,7 @@ pub use field::*;
 pub use file::*;
 pub use flags::*;
 pub use generic_param::*;
+pub use idl::*;
 pub use impl_map::*;
 pub use interface_impl::*;
 pub use member_ref::*;
@@ -51,3 +56,4 @@ pub use type_reader::*;
 pub use type_ref::*;
 pub use type_spec::*;
 pub use workspace::*;
+pub use writer::*;
@@ -28,10 +28,10 @@ unsafe impl CopyPod for i32 {}
 unsafe impl CopyPod for i64 {}
 
 pub(crate) trait View {
-    fn view_as<T: Pod>(&self, cli_offset: u32) -> &T;
-    fn view_as_slice_of<T: Pod>(&self, cli_offset: u32, len: u32) -> &[T];
-    fn copy_as<T: Copy + CopyPod>(&self, cli_offset: u32) -> T;
-    fn view_as_str(&self, cli_offset: u32) -> &[u8];
+    fn try_view_as<T: Pod>(&self, cli_offset: u32) -> Result<&T, InvalidFile>;
+    fn try_view_as_slice_of<T: Pod>(&self, cli_offset: u32, len: u32) -> Result<&[T], InvalidFile>;
+    fn try_copy_as<T: Copy + CopyPod>(&self, cli_offset: u32) -> Result<T, InvalidFile>;
+    fn try_view_as_str(&self, cli_offset: u32) -> Result<&[u8], InvalidFile>;
 }
 
 pub trait Decode {
@@ -6,8 +6,13 @@ impl TypeDef {
         TypeFlags(self.reader.u32(self.row, 0))
     }
 
+    /// The type's namespace and name, which for a nested type are those the [`TypeReader`] gives
+    /// it, such as `INPUT_Anonymous` in `INPUT`'s namespace
     pub fn name(&self) -> (&'static str, &'static str) {
-        (self.reader.str(self.row, 2), self.reader.str(self.row, 1))
+        match self.reader.nested.get(&self.row) {
+            Some((namespace, name)) => (namespace, name),
+            None => (self.reader.str(self.row, 2), self.reader.str(self.row, 1)),
+        }
     }
 
     pub fn extends(&self) -> TypeDefOrRef {
@@ -60,6 +65,21 @@ impl TypeDef {
             })
     }
 
+    pub fn class_layout(&self) -> Option<ClassLayout> {
+        self.reader
+            .equal_range(
+                self.row.file_index,
+                TableIndex::ClassLayout,
+                2,
+                self.row.index + 1,
+            )
+            .map(move |row| ClassLayout {
+                reader: self.reader,
+                row,
+            })
+            .next()
+    }
+
     pub fn attributes(&self) -> impl Iterator<Item = Attribute> + '_ {
         self.reader
             .equal_range(
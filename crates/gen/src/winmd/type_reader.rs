@@ -14,6 +14,11 @@ pub struct TypeReader {
     /// that namespace. The keys are the namespace and the values is a mapping
     /// of type names to type definitions
     types: BTreeMap<String, BTreeMap<String, TypeRow>>,
+    /// The namespace and name given to each nested type, which the metadata leaves without a
+    /// namespace and with a name that's only unique within the type that encloses it
+    pub(crate) nested: BTreeMap<Row, (String, String)>,
+    /// The types nested within each type that has any
+    nested_types: BTreeMap<Row, Vec<Row>>,
     // TODO: store Row objects and turn them into TypeDef on request.
     // When turning into TypeDef they add the &'static TypeReader
 }
@@ -52,41 +57,98 @@ impl Type {
 
 impl TypeReader {
     pub fn get() -> &'static Self {
+        Self::try_get().unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Loads the build's metadata the first time it's called, returning an error rather than
+    /// panicking if the metadata can't be found or a file can't be read.
+    pub fn try_get() -> Result<&'static Self, LoadError> {
         use std::{mem::MaybeUninit, sync::Once};
         static ONCE: Once = Once::new();
-        static mut VALUE: MaybeUninit<TypeReader> = MaybeUninit::uninit();
+        static mut VALUE: MaybeUninit<Result<TypeReader, LoadError>> = MaybeUninit::uninit();
 
         ONCE.call_once(|| {
             // This is safe because `Once` provides thread-safe one-time initialization
-            unsafe { VALUE = MaybeUninit::new(Self::from_iter(winmd_paths())) }
+            unsafe { VALUE = MaybeUninit::new(try_winmd_paths().and_then(Self::try_from_iter)) }
         });
 
         // This is safe because `call_once` has already been called.
-        unsafe { &*VALUE.as_ptr() }
+        unsafe { (*VALUE.as_ptr()).as_ref().map_err(|error| error.clone()) }
     }
 
     /// Insert WinRT metadata at the given paths
+    fn try_from_iter<I: IntoIterator<Item = PathBuf>>(files: I) -> Result<Self, LoadError> {
+        Self::try_from_files(
+            files
+                .into_iter()
+                .map(File::try_new)
+                .collect::<Result<_, _>>()?,
+        )
+    }
+
+    /// Creates a reader over the metadata at the given paths rather than the build's metadata,
+    /// such as to compare two versions of the metadata. Each path may be a `.winmd` or `.idl`
+    /// file or a directory containing them.
     ///
     /// # Panics
     ///
-    /// This function panics if the if the files where the windows metadata are stored cannot be read.
-    fn from_iter<I: IntoIterator<Item = PathBuf>>(files: I) -> Self {
-        let mut files: Vec<File> = files.into_iter().map(|file| File::new(file)).collect();
+    /// This function panics if no metadata is found or if a file cannot be read.
+    pub fn from_paths<I: IntoIterator<Item = PathBuf>>(paths: I) -> Self {
+        Self::try_from_paths(paths).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Creates a reader over the metadata at the given paths like [`TypeReader::from_paths`],
+    /// returning an error if no metadata is found or if a file cannot be read.
+    pub fn try_from_paths<I: IntoIterator<Item = PathBuf>>(paths: I) -> Result<Self, LoadError> {
+        let mut files = Vec::new();
+
+        for path in paths {
+            if path.is_dir() {
+                push_winmd_paths(path, &mut files);
+            } else {
+                files.push(path);
+            }
+        }
 
         if files.is_empty() {
-            files.push(File::from_bytes(
+            return Err(LoadError("No metadata found".to_string()));
+        }
+
+        Self::try_from_iter(files)
+    }
+
+    /// Create a reader over metadata that has already been loaded, falling back to the
+    /// metadata bundled with the crate if no files are provided. This is how the build's
+    /// metadata is loaded, so a malformed file is rejected with an error here rather than
+    /// panicking the build.
+    pub fn try_from_files(mut files: Vec<File>) -> Result<Self, LoadError> {
+        if files.is_empty() {
+            files.push(File::try_from_bytes(
                 include_bytes!("../../default/Windows.Win32.winmd").to_vec(),
-            ));
-            files.push(File::from_bytes(
+            )?);
+            files.push(File::try_from_bytes(
                 include_bytes!("../../default/Windows.WinRT.winmd").to_vec(),
-            ));
+            )?);
         }
 
-        let reader = Self {
+        let mut reader = Self {
             files,
             types: BTreeMap::default(),
+            nested: BTreeMap::default(),
+            nested_types: BTreeMap::default(),
         };
 
+        let enclosing = reader.enclosing_types()?;
+        reader.nested = reader.nested_names(&enclosing)?;
+
+        for (nested, enclosing) in &enclosing {
+            reader
+                .nested_types
+                .entry(*enclosing)
+                .or_default()
+                .push(*nested);
+        }
+
         let mut types = BTreeMap::<String, BTreeMap<String, TypeRow>>::default();
 
         for (index, file) in reader.files.iter().enumerate() {
@@ -94,8 +156,14 @@ impl TypeReader {
 
             for row in 0..row_count {
                 let def = Row::new(row, TableIndex::TypeDef, index as u16);
-                let namespace = reader.str(def, 2).to_string();
-                let name = reader.str(def, 1).to_string();
+
+                let (namespace, name) = match reader.nested.get(&def) {
+                    Some(nested) => nested.clone(),
+                    None => (
+                        reader.try_str(def, 2)?.to_string(),
+                        reader.try_str(def, 1)?.to_string(),
+                    ),
+                };
 
                 types
                     .entry(namespace.to_string())
@@ -103,26 +171,32 @@ impl TypeReader {
                     .entry(name.to_string())
                     .or_insert(TypeRow::TypeDef(def));
 
-                let flags = TypeFlags(reader.u32(def, 0));
+                let flags = TypeFlags(reader.try_u32(def, 0)?);
 
                 if flags.interface() || flags.windows_runtime() {
                     continue;
                 }
 
-                let extends = reader.u32(def, 3);
+                let extends = reader.try_u32(def, 3)?;
 
-                if extends == 0 {
+                // Only a reference to another file's type can be to `System.Object`.
+                if extends & 0x3 != 1 {
                     continue;
                 }
 
-                let extends = Row::new((extends >> 2) - 1, TableIndex::TypeRef, index as u16);
+                let extends = match (extends >> 2).checked_sub(1) {
+                    Some(extends) => Row::new(extends, TableIndex::TypeRef, index as u16),
+                    None => continue,
+                };
 
-                if (reader.str(extends, 2), reader.str(extends, 1)) != ("System", "Object") {
+                if (reader.try_str(extends, 2)?, reader.try_str(extends, 1)?)
+                    != ("System", "Object")
+                {
                     continue;
                 }
 
-                for field in reader.list(def, TableIndex::Field, 4) {
-                    let name = reader.str(field, 1);
+                for field in reader.try_list(def, TableIndex::Field, 4)? {
+                    let name = reader.try_str(field, 1)?;
 
                     types
                         .entry(namespace.to_string())
@@ -131,8 +205,8 @@ impl TypeReader {
                         .or_insert(TypeRow::Field((def, field)));
                 }
 
-                for method in reader.list(def, TableIndex::MethodDef, 5) {
-                    let name = reader.str(method, 3);
+                for method in reader.try_list(def, TableIndex::MethodDef, 5)? {
+                    let name = reader.try_str(method, 3)?;
 
                     types
                         .entry(namespace.to_string())
@@ -155,6 +229,7 @@ impl TypeReader {
         remove_excluded_type(&mut types, ("Windows.Foundation", "HResult"));
         remove_excluded_type(&mut types, ("Windows.Win32.Com", "IUnknown"));
         remove_excluded_type(&mut types, ("Windows.Win32.SystemServices", "BOOL"));
+        remove_excluded_type(&mut types, ("Windows.Win32.SystemServices", "NTSTATUS"));
         remove_excluded_type(&mut types, ("Windows.Win32.Direct2D", "D2D_MATRIX_3X2_F"));
 
         remove_excluded_type(
@@ -175,12 +250,214 @@ impl TypeReader {
             ("Windows.Win32", "CFunctionDiscoveryNotificationWrapper"),
         );
 
-        Self {
+        // Types nested within excluded types are excluded along with them.
+        for (nested, name) in &reader.nested {
+            let mut outer = *nested;
+
+            while let Some(enclosing) = enclosing.get(&outer) {
+                outer = *enclosing;
+            }
+
+            let (namespace, outer) = (reader.try_str(outer, 2)?, reader.try_str(outer, 1)?);
+
+            if !matches!(types.get(namespace), Some(types) if types.contains_key(outer)) {
+                remove_excluded_type(&mut types, (&name.0, &name.1));
+            }
+        }
+
+        Ok(Self {
             files: reader.files,
             types,
+            nested: reader.nested,
+            nested_types: reader.nested_types,
+        })
+    }
+
+    /// Names each nested type after the type that encloses it and the field of that type whose
+    /// type it is, such as `INPUT_Anonymous` for the union behind `INPUT`'s `Anonymous` field,
+    /// and places it in the enclosing type's namespace. A nested type that no field refers to
+    /// keeps its own name after the enclosing type's, so the names don't depend on row order.
+    fn nested_names(
+        &self,
+        enclosing: &BTreeMap<Row, Row>,
+    ) -> Result<BTreeMap<Row, (String, String)>, LoadError> {
+        let mut names = BTreeMap::new();
+
+        for nested in enclosing.keys() {
+            self.nested_name(*nested, enclosing, &mut names)?;
+        }
+
+        Ok(names)
+    }
+
+    /// Maps each nested type to the type that encloses it
+    fn enclosing_types(&self) -> Result<BTreeMap<Row, Row>, LoadError> {
+        let mut enclosing = BTreeMap::new();
+
+        for (index, file) in self.files.iter().enumerate() {
+            for row in 0..file.tables[TableIndex::NestedClass as usize].row_count {
+                let row = Row::new(row, TableIndex::NestedClass, index as u16);
+                let nested = self.try_index(row, 0, TableIndex::TypeDef)?;
+                let parent = self.try_index(row, 1, TableIndex::TypeDef)?;
+                enclosing.insert(nested, parent);
+            }
+        }
+
+        // Every chain of enclosing types must end, or naming the types within it never would.
+        for nested in enclosing.keys() {
+            let mut outer = *nested;
+
+            for _ in 0..=enclosing.len() {
+                match enclosing.get(&outer) {
+                    Some(enclosing) => outer = *enclosing,
+                    None => break,
+                }
+            }
+
+            if enclosing.contains_key(&outer) {
+                return Err(LoadError(format!(
+                    "{} reading {:?}",
+                    InvalidFile("nested types enclose each other"),
+                    nested
+                )));
+            }
+        }
+
+        Ok(enclosing)
+    }
+
+    fn nested_name(
+        &self,
+        nested: Row,
+        enclosing: &BTreeMap<Row, Row>,
+        names: &mut BTreeMap<Row, (String, String)>,
+    ) -> Result<(String, String), LoadError> {
+        if let Some(name) = names.get(&nested) {
+            return Ok(name.clone());
+        }
+
+        let parent = enclosing[&nested];
+
+        let (namespace, parent_name) = if enclosing.contains_key(&parent) {
+            self.nested_name(parent, enclosing, names)?
+        } else {
+            (
+                self.try_str(parent, 2)?.to_string(),
+                self.try_str(parent, 1)?.to_string(),
+            )
+        };
+
+        let mut member = None;
+
+        for field in self.try_list(parent, TableIndex::Field, 4)? {
+            if self.field_is_nested_type(field, nested)? {
+                member = Some(self.try_str(field, 1)?);
+                break;
+            }
+        }
+
+        let member = match member {
+            Some(member) => member,
+            None => self.try_str(nested, 1)?,
+        };
+
+        let name = (namespace, format!("{}_{}", parent_name, member));
+        names.insert(nested, name.clone());
+        Ok(name)
+    }
+
+    /// Whether a field's type, looking through any pointers and arrays, is the given nested type
+    fn field_is_nested_type(&self, field: Row, nested: Row) -> Result<bool, LoadError> {
+        let file = &self.files[field.file_index as usize];
+        let (offset, size) = file
+            .try_blob(field.table_index, field.index, 2)
+            .map_err(|error| invalid(error, field, 2))?;
+        let mut bytes = &file.bytes[offset..offset + size];
+
+        fn try_read_unsigned(bytes: &mut &[u8]) -> Result<u32, InvalidFile> {
+            let truncated = InvalidFile("signature extends past the end of its blob");
+            let first = *bytes.first().ok_or(truncated)?;
+
+            let (value, size) = if first & 0x80 == 0 {
+                (first as u32, 1)
+            } else if first & 0xC0 == 0x80 {
+                let bytes = bytes.get(..2).ok_or(truncated)?;
+                ((((bytes[0] & 0x3F) as u32) << 8) | bytes[1] as u32, 2)
+            } else {
+                let bytes = bytes.get(..4).ok_or(truncated)?;
+                (
+                    (((bytes[0] & 0x1F) as u32) << 24)
+                        | (bytes[1] as u32) << 16
+                        | (bytes[2] as u32) << 8
+                        | bytes[3] as u32,
+                    4,
+                )
+            };
+
+            *bytes = &bytes[size..];
+            Ok(value)
+        }
+
+        let read_unsigned =
+            |bytes: &mut &[u8]| try_read_unsigned(bytes).map_err(|error| invalid(error, field, 2));
+
+        // The field signature's leading byte.
+        read_unsigned(&mut bytes)?;
+
+        loop {
+            match read_unsigned(&mut bytes)? {
+                // Custom modifiers and the types they apply to.
+                0x1F | 0x20 => {
+                    read_unsigned(&mut bytes)?;
+                }
+                // Pointers and arrays, followed by their element type.
+                0x0F | 0x10 | 0x14 | 0x1D => {}
+                0x11 | 0x12 => {
+                    let code = read_unsigned(&mut bytes)?;
+
+                    let row = match (code >> 2).checked_sub(1) {
+                        Some(row) => row,
+                        None => return Ok(false),
+                    };
+
+                    return match code & 0x3 {
+                        0 => Ok(Row::new(row, TableIndex::TypeDef, field.file_index) == nested),
+                        // A reference to a nested type is scoped by a reference to the type that
+                        // encloses it, and only the types nested within the field's own type
+                        // share its name.
+                        1 => {
+                            let type_ref = Row::new(row, TableIndex::TypeRef, field.file_index);
+                            Ok(self.try_u32(type_ref, 0)? & 0x3 == 3
+                                && self.try_str(type_ref, 1)? == self.try_str(nested, 1)?)
+                        }
+                        _ => Ok(false),
+                    };
+                }
+                _ => return Ok(false),
+            }
         }
     }
 
+    /// Finds the type with the given metadata name nested within `enclosing`, or failing that
+    /// in the same namespace as `enclosing`, as some references scoped by an interface are to
+    /// types that aren't actually nested within it
+    pub(crate) fn find_nested_type(
+        &'static self,
+        enclosing: TypeDef,
+        name: &str,
+    ) -> Option<TypeDef> {
+        let nested = self
+            .nested_types
+            .get(&enclosing.row)
+            .and_then(|nested| nested.iter().find(|row| self.str(**row, 1) == name))
+            .map(|row| TypeDef {
+                reader: self,
+                row: *row,
+            });
+
+        nested.or_else(|| self.find_type_def((enclosing.name().0, name)))
+    }
+
     pub fn find_lowercase_namespace(&'static self, lowercase: &str) -> Option<&'static str> {
         self.types
             .keys()
@@ -204,6 +481,22 @@ impl TypeReader {
             .map(move |row| Type::new(self, *row))
     }
 
+    /// Returns whether a type, function, or constant with the given name is in the namespace
+    pub fn contains_type(&self, (namespace, type_name): (&str, &str)) -> bool {
+        matches!(self.types.get(namespace), Some(types) if types.contains_key(type_name))
+    }
+
+    /// Finds a function by name in whichever namespace declares it
+    pub fn find_function(&'static self, name: &str) -> Option<(TypeDef, MethodDef)> {
+        self.types.values().find_map(|types| match types.get(name) {
+            Some(row @ TypeRow::MethodDef(_)) => match Type::new(self, *row) {
+                Type::MethodDef(function) => Some(function),
+                _ => None,
+            },
+            _ => None,
+        })
+    }
+
     pub fn expect_type(&'static self, (namespace, type_name): (&str, &str)) -> Type {
         if let Some(types) = self.types.get(namespace) {
             if let Some(row) = types.get(type_name) {
@@ -215,40 +508,62 @@ impl TypeReader {
     }
 
     pub fn expect_type_def(&'static self, (namespace, type_name): (&str, &str)) -> TypeDef {
-        if let Some(types) = self.types.get(namespace) {
-            if let Some(TypeRow::TypeDef(row)) = types.get(type_name) {
-                return TypeDef {
-                    reader: self,
-                    row: *row,
-                };
-            }
-        }
+        self.find_type_def((namespace, type_name))
+            .unwrap_or_else(|| panic!("Could not find type def `{}.{}`", namespace, type_name))
+    }
 
-        panic!("Could not find type def `{}.{}`", namespace, type_name);
+    pub(crate) fn find_type_def(
+        &'static self,
+        (namespace, type_name): (&str, &str),
+    ) -> Option<TypeDef> {
+        match self.types.get(namespace)?.get(type_name)? {
+            TypeRow::TypeDef(row) => Some(TypeDef {
+                reader: self,
+                row: *row,
+            }),
+            _ => None,
+        }
     }
 
     /// Read a [`u32`] value from a specific [`Row`] and column
     pub fn u32(&self, row: Row, column: u32) -> u32 {
-        let file = &self.files[row.file_index as usize];
-        let table = &file.tables[row.table_index as usize];
-        let offset = table.data + row.index * table.row_size + table.columns[column as usize].0;
-        match table.columns[column as usize].1 {
-            1 => file.bytes.copy_as::<u8>(offset) as u32,
-            2 => file.bytes.copy_as::<u16>(offset) as u32,
-            4 => file.bytes.copy_as::<u32>(offset) as u32,
-            _ => file.bytes.copy_as::<u64>(offset) as u32,
-        }
+        self.try_u32(row, column)
+            .unwrap_or_else(|error| panic!("{}", error))
     }
 
     /// Read a [`&str`] value from a specific [`Row`] and column
     pub fn str(&self, row: Row, column: u32) -> &str {
-        let file = &self.files[row.file_index as usize];
-        let offset = (file.strings + self.u32(row, column)) as usize;
-        let last = file.bytes[offset..]
-            .iter()
-            .position(|c| *c == b'\0')
-            .unwrap();
-        std::str::from_utf8(&file.bytes[offset..offset + last]).unwrap()
+        self.try_str(row, column)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    fn try_u32(&self, row: Row, column: u32) -> Result<u32, LoadError> {
+        self.files[row.file_index as usize]
+            .try_u32(row.table_index, row.index, column)
+            .map_err(|error| invalid(error, row, column))
+    }
+
+    fn try_str(&self, row: Row, column: u32) -> Result<&str, LoadError> {
+        self.files[row.file_index as usize]
+            .try_str(row.table_index, row.index, column)
+            .map_err(|error| invalid(error, row, column))
+    }
+
+    /// Reads the one-based index of a row of the given table, which must not be zero
+    fn try_index(&self, row: Row, column: u32, table: TableIndex) -> Result<Row, LoadError> {
+        match self.try_u32(row, column)?.checked_sub(1) {
+            Some(index) => Ok(Row::new(index, table, row.file_index)),
+            None => Err(invalid(
+                InvalidFile("row index is out of range"),
+                row,
+                column,
+            )),
+        }
+    }
+
+    /// The module name of the metadata file at the given index, such as `Windows.WinRT.winmd`
+    pub fn file_name(&self, file_index: usize) -> &str {
+        self.str(Row::new(0, TableIndex::Module, file_index as u16), 1)
     }
 
     /// Read a `T: Decode` value from a specific [`Row`] and column
@@ -262,38 +577,38 @@ impl TypeReader {
         table: TableIndex,
         column: u32,
     ) -> impl Iterator<Item = Row> {
+        self.try_list(row, table, column)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    fn try_list(
+        &self,
+        row: Row,
+        table: TableIndex,
+        column: u32,
+    ) -> Result<impl Iterator<Item = Row>, LoadError> {
         let file = &self.files[row.file_index as usize];
-        let first = self.u32(row, column) - 1;
+        let first = self.try_u32(row, column)?.saturating_sub(1);
 
         let last = if row.index + 1 < file.tables[row.table_index as usize].row_count {
-            self.u32(row.next(), column) - 1
+            self.try_u32(row.next(), column)?.saturating_sub(1)
         } else {
             file.tables[table as usize].row_count
         };
 
-        (first..last).map(move |value| Row::new(value, table, row.file_index))
+        Ok((first..last).map(move |value| Row::new(value, table, row.file_index)))
     }
 
     /// Read a blob for a given row and column
     pub fn blob(&'static self, row: Row, column: u32) -> Blob {
-        let file = &self.files[row.file_index as usize];
-        let offset = (file.blobs + self.u32(row, column)) as usize;
-        let initial_byte = file.bytes[offset];
-        let (blob_size, blob_size_bytes) = match initial_byte >> 5 {
-            0..=3 => (initial_byte & 0x7f, 1),
-            4..=5 => (initial_byte & 0x3f, 2),
-            6 => (initial_byte & 0x1f, 4),
-            _ => panic!("Invalid blob size"),
-        };
-        let mut blob_size = blob_size as usize;
-        for byte in &file.bytes[offset + 1..offset + blob_size_bytes] {
-            blob_size = blob_size.checked_shl(8).unwrap_or(0) + (*byte as usize);
-        }
+        let (offset, size) = self.files[row.file_index as usize]
+            .try_blob(row.table_index, row.index, column)
+            .unwrap_or_else(|error| panic!("{}", invalid(error, row, column)));
         Blob {
             reader: self,
             file_index: row.file_index,
-            offset: offset + blob_size_bytes,
-            size: blob_size,
+            offset,
+            size,
         }
     }
 
@@ -401,13 +716,80 @@ impl TypeReader {
     }
 }
 
-fn winmd_paths() -> Vec<std::path::PathBuf> {
-    let mut windows_path = workspace_windows_dir();
-    windows_path.push("winmd");
+/// Returns the metadata files the build reads, as selected by the `WINDOWS_WINMD_PATHS` and
+/// `WINDOWS_WINMD_VERSION` environment variables. The list is empty if the build reads the
+/// metadata bundled with the crate.
+pub fn winmd_paths() -> Vec<std::path::PathBuf> {
+    try_winmd_paths().unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Returns the metadata files the build reads like [`winmd_paths`], returning an error if a
+/// path or version the environment variables select doesn't exist.
+pub fn try_winmd_paths() -> Result<Vec<std::path::PathBuf>, LoadError> {
+    let version = metadata_version();
+
+    let roots = match metadata_paths() {
+        Some(roots) => {
+            for root in &roots {
+                if !root.exists() {
+                    return Err(LoadError(format!(
+                        "Metadata path {:?} from WINDOWS_WINMD_PATHS does not exist",
+                        root
+                    )));
+                }
+            }
+
+            roots
+        }
+        None => {
+            let mut windows_path = workspace_windows_dir();
+            windows_path.push("winmd");
+            vec![windows_path]
+        }
+    };
 
     let mut paths = vec![];
-    push_winmd_paths(windows_path, &mut paths);
-    paths
+
+    for root in roots {
+        if root.is_file() {
+            paths.push(root);
+        } else {
+            paths.append(&mut version_winmd_paths(root, version.as_deref())?);
+        }
+    }
+
+    Ok(paths)
+}
+
+// A build may pin the metadata it reads to one of the versions kept in subdirectories of the
+// winmd directory, such as `.windows/winmd/10.0.19041`, or to the metadata bundled with the crate.
+fn version_winmd_paths(
+    mut windows_path: std::path::PathBuf,
+    version: Option<&str>,
+) -> Result<Vec<std::path::PathBuf>, LoadError> {
+    let mut paths = vec![];
+
+    match version {
+        None => push_winmd_paths(windows_path, &mut paths),
+        Some("bundled") => {}
+        Some(version) => {
+            windows_path.push(version);
+            push_winmd_paths(windows_path.clone(), &mut paths);
+
+            if paths.is_empty() {
+                return Err(LoadError(format!(
+                    "No metadata found for version `{}` in {:?}",
+                    version, windows_path
+                )));
+            }
+        }
+    }
+
+    Ok(paths)
+}
+
+fn invalid(error: InvalidFile, row: Row, column: u32) -> LoadError {
+    LoadError(format!("{} reading {:?} column {}", error, row, column))
 }
 
 fn push_winmd_paths(dir: std::path::PathBuf, paths: &mut Vec<std::path::PathBuf>) {
@@ -416,7 +798,8 @@ fn push_winmd_paths(dir: std::path::PathBuf, paths: &mut Vec<std::path::PathBuf>
             if let Ok(file_type) = file.file_type() {
                 if file_type.is_file() {
                     let path = file.path();
-                    if let Some("winmd") = path.extension().and_then(|extension| extension.to_str())
+                    if let Some("winmd") | Some("idl") =
+                        path.extension().and_then(|extension| extension.to_str())
                     {
                         paths.push(file.path());
                     }
@@ -425,3 +808,56 @@ fn push_winmd_paths(dir: std::path::PathBuf, paths: &mut Vec<std::path::PathBuf>
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metadata_versions() {
+        let mut dir = std::env::temp_dir();
+        dir.push(format!("windows-winmd-versions-{}", std::process::id()));
+        let mut version = dir.clone();
+        version.push("1.2.3");
+        std::fs::create_dir_all(&version).unwrap();
+
+        dir.push("Latest.winmd");
+        std::fs::write(&dir, []).unwrap();
+        dir.pop();
+        version.push("Pinned.winmd");
+        std::fs::write(&version, []).unwrap();
+
+        assert_eq!(
+            version_winmd_paths(dir.clone(), None).unwrap(),
+            [dir.join("Latest.winmd")]
+        );
+        assert_eq!(
+            version_winmd_paths(dir.clone(), Some("1.2.3")).unwrap(),
+            [version]
+        );
+        assert!(version_winmd_paths(dir.clone(), Some("bundled"))
+            .unwrap()
+            .is_empty());
+        assert!(version_winmd_paths(dir.clone(), Some("4.5.6")).is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn reject_malformed_metadata() {
+        fn load(nested_class: &[u32]) -> Result<TypeReader, LoadError> {
+            let mut writer = Writer::new("Test.winmd");
+            let object = writer.type_ref("System", "Object");
+            let extends = CodedIndex::TypeDefOrRef.encode(MetadataTable::TypeRef, object);
+            let name = writer.string("Sample");
+            let namespace = writer.string("Test");
+            writer.push(MetadataTable::TypeDef, &[0, name, namespace, extends, 1, 1]);
+            writer.push(MetadataTable::NestedClass, nested_class);
+            TypeReader::try_from_files(vec![File::try_from_bytes(writer.into_bytes())?])
+        }
+
+        assert!(load(&[0, 1]).is_err());
+        assert!(load(&[1, 2]).is_err());
+        assert!(load(&[1, 1]).is_err());
+    }
+}
@@ -6,11 +6,42 @@ impl TypeRef {
         self.reader.decode(self.row, 0)
     }
 
+    /// The type's namespace and name, which for a nested type are those of its definition, or
+    /// without a namespace if the definition can't be found
     pub fn name(&self) -> (&'static str, &'static str) {
-        (self.reader.str(self.row, 2), self.reader.str(self.row, 1))
+        match self.scope() {
+            ResolutionScope::TypeRef(_) => self
+                .resolve_nested()
+                .map_or(("", self.reader.str(self.row, 1)), |def| def.name()),
+            _ => (self.reader.str(self.row, 2), self.reader.str(self.row, 1)),
+        }
     }
 
+    /// Finds the type definition, which for a nested type is found within the definition of
+    /// the type that its scope refers to
     pub fn resolve(&self) -> TypeDef {
-        self.reader.expect_type_def(self.name())
+        match self.scope() {
+            ResolutionScope::TypeRef(_) => self.resolve_nested().unwrap_or_else(|| {
+                panic!(
+                    "Could not find nested type def `{}`",
+                    self.reader.str(self.row, 1)
+                )
+            }),
+            _ => self.reader.expect_type_def(self.name()),
+        }
+    }
+
+    fn resolve_nested(&self) -> Option<TypeDef> {
+        match self.scope() {
+            ResolutionScope::TypeRef(enclosing) => match enclosing.scope() {
+                ResolutionScope::TypeRef(_) => enclosing.resolve_nested(),
+                _ => self.reader.find_type_def(enclosing.name()),
+            }
+            .and_then(|enclosing| {
+                self.reader
+                    .find_nested_type(enclosing, self.reader.str(self.row, 1))
+            }),
+            _ => None,
+        }
     }
 }
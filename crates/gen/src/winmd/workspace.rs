@@ -5,6 +5,27 @@ pub fn workspace_windows_dir() -> std::path::PathBuf {
     path
 }
 
+/// Returns the metadata version selected by the `WINDOWS_WINMD_VERSION` environment variable, if any.
+///
+/// The version names a subdirectory of the build's `.windows/winmd` directory holding the metadata
+/// to read, or is `bundled` to read the metadata bundled with the crate.
+pub fn metadata_version() -> Option<String> {
+    std::env::var("WINDOWS_WINMD_VERSION")
+        .ok()
+        .filter(|version| !version.is_empty())
+}
+
+/// Returns the metadata paths listed by the `WINDOWS_WINMD_PATHS` environment variable, if any.
+///
+/// The paths are separated like those of the `PATH` environment variable, and each may be a
+/// `.winmd` or `.idl` file or a directory containing them. They replace the build's
+/// `.windows/winmd` directory.
+pub fn metadata_paths() -> Option<Vec<std::path::PathBuf>> {
+    std::env::var_os("WINDOWS_WINMD_PATHS")
+        .filter(|paths| !paths.is_empty())
+        .map(|paths| std::env::split_paths(&paths).collect())
+}
+
 fn workspace_dir() -> std::path::PathBuf {
     use std::{mem::MaybeUninit, sync::Once};
     static ONCE: Once = Once::new();
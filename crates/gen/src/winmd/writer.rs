@@ -0,0 +1,642 @@
+use super::*;
+use std::collections::BTreeMap;
+
+/// A metadata table that may be written by the [`Writer`], numbered as in ECMA-335 II.22.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MetadataTable {
+    Module = 0x00,
+    TypeRef = 0x01,
+    TypeDef = 0x02,
+    Field = 0x04,
+    MethodDef = 0x06,
+    Param = 0x08,
+    InterfaceImpl = 0x09,
+    MemberRef = 0x0a,
+    Constant = 0x0b,
+    CustomAttribute = 0x0c,
+    ModuleRef = 0x1a,
+    TypeSpec = 0x1b,
+    ImplMap = 0x1c,
+    Assembly = 0x20,
+    AssemblyRef = 0x23,
+    NestedClass = 0x29,
+    GenericParam = 0x2a,
+}
+
+/// A coded index referring to a row in one of several tables, as in ECMA-335 II.24.2.6.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CodedIndex {
+    TypeDefOrRef,
+    HasConstant,
+    HasCustomAttribute,
+    MemberRefParent,
+    CustomAttributeType,
+    MemberForwarded,
+    ResolutionScope,
+    TypeOrMethodDef,
+}
+
+#[derive(Copy, Clone)]
+enum Column {
+    U16,
+    U32,
+    String,
+    Guid,
+    Blob,
+    Index(MetadataTable),
+    Coded(CodedIndex),
+}
+
+impl MetadataTable {
+    const ALL: [Self; 17] = [
+        Self::Module,
+        Self::TypeRef,
+        Self::TypeDef,
+        Self::Field,
+        Self::MethodDef,
+        Self::Param,
+        Self::InterfaceImpl,
+        Self::MemberRef,
+        Self::Constant,
+        Self::CustomAttribute,
+        Self::ModuleRef,
+        Self::TypeSpec,
+        Self::ImplMap,
+        Self::Assembly,
+        Self::AssemblyRef,
+        Self::NestedClass,
+        Self::GenericParam,
+    ];
+
+    fn columns(self) -> &'static [Column] {
+        use Column::*;
+
+        match self {
+            Self::Module => &[U16, String, Guid, Guid, Guid],
+            Self::TypeRef => &[Coded(CodedIndex::ResolutionScope), String, String],
+            Self::TypeDef => &[
+                U32,
+                String,
+                String,
+                Coded(CodedIndex::TypeDefOrRef),
+                Index(Self::Field),
+                Index(Self::MethodDef),
+            ],
+            Self::Field => &[U16, String, Blob],
+            Self::MethodDef => &[U32, U16, U16, String, Blob, Index(Self::Param)],
+            Self::Param => &[U16, U16, String],
+            Self::InterfaceImpl => &[Index(Self::TypeDef), Coded(CodedIndex::TypeDefOrRef)],
+            Self::MemberRef => &[Coded(CodedIndex::MemberRefParent), String, Blob],
+            Self::Constant => &[U16, Coded(CodedIndex::HasConstant), Blob],
+            Self::CustomAttribute => &[
+                Coded(CodedIndex::HasCustomAttribute),
+                Coded(CodedIndex::CustomAttributeType),
+                Blob,
+            ],
+            Self::ModuleRef => &[String],
+            Self::TypeSpec => &[Blob],
+            Self::ImplMap => &[
+                U16,
+                Coded(CodedIndex::MemberForwarded),
+                String,
+                Index(Self::ModuleRef),
+            ],
+            Self::Assembly => &[U32, U16, U16, U16, U16, U32, Blob, String, String],
+            Self::AssemblyRef => &[U16, U16, U16, U16, U32, Blob, String, String, Blob],
+            Self::NestedClass => &[Index(Self::TypeDef), Index(Self::TypeDef)],
+            Self::GenericParam => &[U16, U16, Coded(CodedIndex::TypeOrMethodDef), String],
+        }
+    }
+
+    // The columns by which the table must be sorted, if any.
+    fn sort_key(self) -> Option<&'static [usize]> {
+        match self {
+            Self::InterfaceImpl | Self::CustomAttribute | Self::NestedClass => Some(&[0]),
+            Self::Constant | Self::ImplMap => Some(&[1]),
+            Self::GenericParam => Some(&[2, 0]),
+            _ => None,
+        }
+    }
+}
+
+impl CodedIndex {
+    // The tables that may be referenced, in tag order. Tables that the writer doesn't support
+    // still occupy a tag so that the number of tag bits matches the specification.
+    fn tables(self) -> &'static [Option<MetadataTable>] {
+        use MetadataTable::*;
+
+        match self {
+            Self::TypeDefOrRef => &[Some(TypeDef), Some(TypeRef), Some(TypeSpec)],
+            Self::HasConstant => &[Some(Field), Some(Param), None],
+            Self::HasCustomAttribute => &[
+                Some(MethodDef),
+                Some(Field),
+                Some(TypeRef),
+                Some(TypeDef),
+                Some(Param),
+                Some(InterfaceImpl),
+                Some(MemberRef),
+                Some(Module),
+                None,
+                None,
+                None,
+                None,
+                Some(ModuleRef),
+                Some(TypeSpec),
+                Some(Assembly),
+                Some(AssemblyRef),
+                None,
+                None,
+                None,
+                Some(GenericParam),
+                None,
+                None,
+            ],
+            Self::MemberRefParent => &[
+                Some(TypeDef),
+                Some(TypeRef),
+                Some(ModuleRef),
+                Some(MethodDef),
+                Some(TypeSpec),
+            ],
+            Self::CustomAttributeType => &[None, None, Some(MethodDef), Some(MemberRef), None],
+            Self::MemberForwarded => &[Some(Field), Some(MethodDef)],
+            Self::ResolutionScope => &[
+                Some(Module),
+                Some(ModuleRef),
+                Some(AssemblyRef),
+                Some(TypeRef),
+            ],
+            Self::TypeOrMethodDef => &[Some(TypeDef), Some(MethodDef)],
+        }
+    }
+
+    fn bits(self) -> u32 {
+        let mut bits = 1;
+
+        while (1 << bits) < self.tables().len() {
+            bits += 1;
+        }
+
+        bits
+    }
+
+    /// Encodes a reference to the given one-based row of `table`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `table` cannot be referenced by this kind of coded index.
+    pub fn encode(self, table: MetadataTable, row: u32) -> u32 {
+        let tag = self
+            .tables()
+            .iter()
+            .position(|t| *t == Some(table))
+            .unwrap_or_else(|| panic!("{:?} cannot refer to {:?}", self, table));
+
+        (row << self.bits()) | tag as u32
+    }
+}
+
+/// Builds a Windows Metadata File from heap values and table rows.
+///
+/// Rows are added one table at a time with [`Writer::push`], whose values are given in the
+/// column order of ECMA-335 II.22. String, GUID, and blob columns take the heap index returned
+/// by [`Writer::string`], [`Writer::guid`], and [`Writer::blob`]; table columns take one-based row
+/// indexes and coded index columns take values from [`CodedIndex::encode`]. Tables that the
+/// specification requires to be sorted are sorted when the file is written.
+///
+/// ```
+/// use windows_gen::winmd::*;
+///
+/// let mut writer = Writer::new("Sample.winmd");
+/// let object = writer.type_ref("System", "Object");
+/// let name = writer.string("Class");
+/// let namespace = writer.string("Sample");
+/// let extends = CodedIndex::TypeDefOrRef.encode(MetadataTable::TypeRef, object);
+/// writer.push(MetadataTable::TypeDef, &[0x4001, name, namespace, extends, 1, 1]);
+///
+/// let file = File::try_from_bytes(writer.into_bytes()).unwrap();
+/// ```
+pub struct Writer {
+    strings: Vec<u8>,
+    string_map: BTreeMap<String, u32>,
+    blobs: Vec<u8>,
+    blob_map: BTreeMap<Vec<u8>, u32>,
+    guids: Vec<[u8; 16]>,
+    tables: BTreeMap<MetadataTable, Vec<Vec<u32>>>,
+}
+
+impl Writer {
+    /// Creates a writer for a module with the given name, along with its `Module` and `Assembly`
+    /// rows and a reference to the `mscorlib` assembly for resolving `System` types.
+    pub fn new(module_name: &str) -> Self {
+        let mut writer = Self {
+            strings: vec![0],
+            string_map: BTreeMap::new(),
+            blobs: vec![0],
+            blob_map: BTreeMap::new(),
+            guids: Vec::new(),
+            tables: BTreeMap::new(),
+        };
+
+        let name = writer.string(module_name);
+        let mvid = writer.guid([0; 16]);
+        writer.push(MetadataTable::Module, &[0, name, mvid, 0, 0]);
+
+        let assembly_name = module_name.trim_end_matches(".winmd");
+        let assembly_name = writer.string(assembly_name);
+        writer.push(
+            MetadataTable::Assembly,
+            &[0x8004, 255, 255, 255, 255, 0x200, 0, assembly_name, 0],
+        );
+
+        let mscorlib = writer.string("mscorlib");
+        let token = writer.blob(&[0xB7, 0x7A, 0x5C, 0x56, 0x19, 0x34, 0xE0, 0x89]);
+        writer.push(
+            MetadataTable::AssemblyRef,
+            &[4, 0, 0, 0, 0, token, mscorlib, 0, 0],
+        );
+
+        writer
+    }
+
+    /// Adds a string to the string heap, returning its index.
+    pub fn string(&mut self, value: &str) -> u32 {
+        if value.is_empty() {
+            return 0;
+        }
+
+        if let Some(index) = self.string_map.get(value) {
+            return *index;
+        }
+
+        let index = self.strings.len() as u32;
+        self.strings.extend_from_slice(value.as_bytes());
+        self.strings.push(0);
+        self.string_map.insert(value.to_string(), index);
+        index
+    }
+
+    /// Adds a blob to the blob heap, returning its index.
+    pub fn blob(&mut self, value: &[u8]) -> u32 {
+        if value.is_empty() {
+            return 0;
+        }
+
+        if let Some(index) = self.blob_map.get(value) {
+            return *index;
+        }
+
+        let index = self.blobs.len() as u32;
+        compress_unsigned(value.len() as u32, &mut self.blobs);
+        self.blobs.extend_from_slice(value);
+        self.blob_map.insert(value.to_vec(), index);
+        index
+    }
+
+    /// Adds a GUID to the GUID heap, returning its one-based index.
+    pub fn guid(&mut self, value: [u8; 16]) -> u32 {
+        self.guids.push(value);
+        self.guids.len() as u32
+    }
+
+    /// Adds a row to the given table, returning its one-based index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of values doesn't match the table's columns.
+    pub fn push(&mut self, table: MetadataTable, values: &[u32]) -> u32 {
+        assert!(
+            values.len() == table.columns().len(),
+            "{:?} rows have {} columns",
+            table,
+            table.columns().len()
+        );
+
+        let rows = self.tables.entry(table).or_default();
+        rows.push(values.to_vec());
+        rows.len() as u32
+    }
+
+    /// Returns the number of rows in the given table.
+    pub fn row_count(&self, table: MetadataTable) -> u32 {
+        self.tables.get(&table).map_or(0, |rows| rows.len() as u32)
+    }
+
+    /// Adds a `TypeRef` row for a type defined in the referenced `mscorlib` assembly, returning its
+    /// one-based index.
+    pub fn type_ref(&mut self, namespace: &str, name: &str) -> u32 {
+        let scope = CodedIndex::ResolutionScope.encode(MetadataTable::AssemblyRef, 1);
+        let name = self.string(name);
+        let namespace = self.string(namespace);
+        self.push(MetadataTable::TypeRef, &[scope, name, namespace])
+    }
+
+    /// Adds a `CustomAttribute` row with the given fixed arguments, returning its one-based index.
+    pub fn attribute(&mut self, parent: u32, constructor: u32, args: &[ConstantValue]) -> u32 {
+        let mut value = vec![0x01, 0x00];
+
+        for arg in args {
+            match arg {
+                ConstantValue::U8(arg) => value.extend_from_slice(&arg.to_le_bytes()),
+                ConstantValue::I8(arg) => value.extend_from_slice(&arg.to_le_bytes()),
+                ConstantValue::U16(arg) => value.extend_from_slice(&arg.to_le_bytes()),
+                ConstantValue::I16(arg) => value.extend_from_slice(&arg.to_le_bytes()),
+                ConstantValue::U32(arg) => value.extend_from_slice(&arg.to_le_bytes()),
+                ConstantValue::I32(arg) => value.extend_from_slice(&arg.to_le_bytes()),
+                ConstantValue::U64(arg) => value.extend_from_slice(&arg.to_le_bytes()),
+                ConstantValue::I64(arg) => value.extend_from_slice(&arg.to_le_bytes()),
+                ConstantValue::F32(arg) => value.extend_from_slice(&arg.to_le_bytes()),
+                ConstantValue::F64(arg) => value.extend_from_slice(&arg.to_le_bytes()),
+                ConstantValue::String(arg) => {
+                    compress_unsigned(arg.len() as u32, &mut value);
+                    value.extend_from_slice(arg.as_bytes());
+                }
+            }
+        }
+
+        // No named arguments.
+        value.extend_from_slice(&[0x00, 0x00]);
+
+        let value = self.blob(&value);
+        self.push(
+            MetadataTable::CustomAttribute,
+            &[parent, constructor, value],
+        )
+    }
+
+    /// Writes the metadata to the bytes of a `.winmd` file.
+    pub fn into_bytes(mut self) -> Vec<u8> {
+        for (table, rows) in self.tables.iter_mut() {
+            if let Some(key) = table.sort_key() {
+                rows.sort_by_key(|row| key.iter().map(|column| row[*column]).collect::<Vec<_>>());
+            }
+        }
+
+        let metadata = self.metadata();
+
+        const FILE_ALIGNMENT: u32 = 0x200;
+        const SECTION_RVA: u32 = 0x2000;
+        const COR_HEADER_SIZE: u32 = 72;
+
+        let section_size = COR_HEADER_SIZE + metadata.len() as u32;
+        let raw_size = round_up(section_size, FILE_ALIGNMENT);
+
+        let mut bytes = Vec::new();
+
+        // ImageDosHeader
+        bytes.extend_from_slice(&0x5A4Du16.to_le_bytes());
+        bytes.resize(0x3C, 0);
+        bytes.extend_from_slice(&0x40u32.to_le_bytes());
+
+        // ImageNtHeader
+        bytes.extend_from_slice(b"PE\0\0");
+        bytes.extend_from_slice(&0x014Cu16.to_le_bytes()); // machine
+        bytes.extend_from_slice(&1u16.to_le_bytes()); // number_of_sections
+        bytes.extend_from_slice(&[0; 12]);
+        bytes.extend_from_slice(&224u16.to_le_bytes()); // size_of_optional_header
+        bytes.extend_from_slice(&0x2102u16.to_le_bytes()); // characteristics
+
+        // ImageOptionalHeader
+        let optional_header = bytes.len();
+        bytes.extend_from_slice(&0x010Bu16.to_le_bytes()); // magic
+        bytes.resize(optional_header + 32, 0);
+        bytes.extend_from_slice(&SECTION_RVA.to_le_bytes()); // section_alignment
+        bytes.extend_from_slice(&FILE_ALIGNMENT.to_le_bytes()); // file_alignment
+        bytes.extend_from_slice(&[4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0]); // versions
+        bytes.resize(optional_header + 56, 0);
+        bytes.extend_from_slice(&(SECTION_RVA + round_up(section_size, SECTION_RVA)).to_le_bytes()); // size_of_image
+        bytes.extend_from_slice(&FILE_ALIGNMENT.to_le_bytes()); // size_of_headers
+        bytes.resize(optional_header + 68, 0);
+        bytes.extend_from_slice(&3u16.to_le_bytes()); // subsystem
+        bytes.extend_from_slice(&0x8540u16.to_le_bytes()); // dll_characteristics
+        bytes.resize(optional_header + 92, 0);
+        bytes.extend_from_slice(&16u32.to_le_bytes()); // number_of_rva_and_sizes
+        bytes.resize(optional_header + 96 + 14 * 8, 0);
+        bytes.extend_from_slice(&SECTION_RVA.to_le_bytes()); // com descriptor
+        bytes.extend_from_slice(&COR_HEADER_SIZE.to_le_bytes());
+        bytes.resize(optional_header + 224, 0);
+
+        // ImageSectionHeader
+        bytes.extend_from_slice(b".text\0\0\0");
+        bytes.extend_from_slice(&section_size.to_le_bytes());
+        bytes.extend_from_slice(&SECTION_RVA.to_le_bytes());
+        bytes.extend_from_slice(&raw_size.to_le_bytes());
+        bytes.extend_from_slice(&FILE_ALIGNMENT.to_le_bytes());
+        bytes.extend_from_slice(&[0; 12]);
+        bytes.extend_from_slice(&0x6000_0020u32.to_le_bytes());
+        bytes.resize(FILE_ALIGNMENT as usize, 0);
+
+        // ImageCorHeader
+        bytes.extend_from_slice(&COR_HEADER_SIZE.to_le_bytes());
+        bytes.extend_from_slice(&2u16.to_le_bytes());
+        bytes.extend_from_slice(&5u16.to_le_bytes());
+        bytes.extend_from_slice(&(SECTION_RVA + COR_HEADER_SIZE).to_le_bytes());
+        bytes.extend_from_slice(&(metadata.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&1u32.to_le_bytes()); // ILONLY
+        bytes.resize((FILE_ALIGNMENT + COR_HEADER_SIZE) as usize, 0);
+
+        bytes.extend_from_slice(&metadata);
+        bytes.resize((FILE_ALIGNMENT + raw_size) as usize, 0);
+        bytes
+    }
+
+    fn metadata(&self) -> Vec<u8> {
+        let tables = self.tables_stream();
+
+        let mut strings = self.strings.clone();
+        strings.resize(round_up(strings.len() as u32, 4) as usize, 0);
+
+        let mut blobs = self.blobs.clone();
+        blobs.resize(round_up(blobs.len() as u32, 4) as usize, 0);
+
+        let guids: Vec<u8> = self.guids.iter().flatten().copied().collect();
+
+        let version = b"WindowsRuntime 1.4\0\0";
+        let streams: [(&[u8], &[u8]); 4] = [
+            (b"#~", &tables),
+            (b"#Strings", &strings),
+            (b"#Blob", &blobs),
+            (b"#GUID", &guids),
+        ];
+
+        let header_size = 20
+            + version.len()
+            + streams
+                .iter()
+                .map(|(name, _)| 8 + round_up(name.len() as u32 + 1, 4) as usize)
+                .sum::<usize>();
+
+        let mut metadata = Vec::new();
+        metadata.extend_from_slice(&0x424A_5342u32.to_le_bytes());
+        metadata.extend_from_slice(&1u16.to_le_bytes());
+        metadata.extend_from_slice(&1u16.to_le_bytes());
+        metadata.extend_from_slice(&0u32.to_le_bytes());
+        metadata.extend_from_slice(&(version.len() as u32).to_le_bytes());
+        metadata.extend_from_slice(version);
+        metadata.extend_from_slice(&0u16.to_le_bytes());
+        metadata.extend_from_slice(&(streams.len() as u16).to_le_bytes());
+
+        let mut offset = header_size;
+
+        for (name, data) in &streams {
+            metadata.extend_from_slice(&(offset as u32).to_le_bytes());
+            metadata.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            metadata.extend_from_slice(name);
+            let padded = round_up(name.len() as u32 + 1, 4) as usize;
+            metadata.resize(metadata.len() + padded - name.len(), 0);
+            offset += data.len();
+        }
+
+        for (_, data) in &streams {
+            metadata.extend_from_slice(data);
+        }
+
+        metadata
+    }
+
+    fn tables_stream(&self) -> Vec<u8> {
+        let string_size = if self.strings.len() < (1 << 16) { 2 } else { 4 };
+        let guid_size = if self.guids.len() < (1 << 16) { 2 } else { 4 };
+        let blob_size = if self.blobs.len() < (1 << 16) { 2 } else { 4 };
+
+        let heap_sizes = (string_size == 4) as u8
+            | ((guid_size == 4) as u8) << 1
+            | ((blob_size == 4) as u8) << 2;
+
+        let index_size = |table: MetadataTable| {
+            if self.row_count(table) < (1 << 16) {
+                2
+            } else {
+                4
+            }
+        };
+
+        let coded_size = |coded: CodedIndex| {
+            let limit = 1 << (16 - coded.bits());
+
+            if coded
+                .tables()
+                .iter()
+                .flatten()
+                .all(|table| self.row_count(*table) < limit)
+            {
+                2
+            } else {
+                4
+            }
+        };
+
+        let mut valid = 0u64;
+        let mut sorted = 0u64;
+
+        for table in MetadataTable::ALL.iter() {
+            if self.row_count(*table) != 0 {
+                valid |= 1 << *table as u64;
+            }
+
+            if table.sort_key().is_some() {
+                sorted |= 1 << *table as u64;
+            }
+        }
+
+        let mut stream = Vec::new();
+        stream.extend_from_slice(&0u32.to_le_bytes());
+        stream.extend_from_slice(&[2, 0, heap_sizes, 1]);
+        stream.extend_from_slice(&valid.to_le_bytes());
+        stream.extend_from_slice(&sorted.to_le_bytes());
+
+        for rows in self.tables.values() {
+            stream.extend_from_slice(&(rows.len() as u32).to_le_bytes());
+        }
+
+        for (table, rows) in &self.tables {
+            for row in rows {
+                for (column, value) in table.columns().iter().zip(row) {
+                    let size = match column {
+                        Column::U16 => 2,
+                        Column::U32 => 4,
+                        Column::String => string_size,
+                        Column::Guid => guid_size,
+                        Column::Blob => blob_size,
+                        Column::Index(table) => index_size(*table),
+                        Column::Coded(coded) => coded_size(*coded),
+                    };
+
+                    stream.extend_from_slice(&value.to_le_bytes()[..size]);
+                }
+            }
+        }
+
+        stream.resize(round_up(stream.len() as u32, 4) as usize, 0);
+        stream
+    }
+}
+
+/// Appends an unsigned integer to a signature or blob using the compressed encoding of
+/// ECMA-335 II.23.2.
+pub fn compress_unsigned(value: u32, buffer: &mut Vec<u8>) {
+    if value < 0x80 {
+        buffer.push(value as u8);
+    } else if value < 0x4000 {
+        buffer.extend_from_slice(&(0x8000 | value as u16).to_be_bytes());
+    } else {
+        buffer.extend_from_slice(&(0xC000_0000 | value).to_be_bytes());
+    }
+}
+
+fn round_up(value: u32, align: u32) -> u32 {
+    (value + align - 1) & !(align - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut writer = Writer::new("Test.winmd");
+
+        let value_type = writer.type_ref("System", "ValueType");
+        let extends = CodedIndex::TypeDefOrRef.encode(MetadataTable::TypeRef, value_type);
+        let name = writer.string("Sample");
+        let namespace = writer.string("Test");
+        let field_list = writer.row_count(MetadataTable::Field) + 1;
+        let def = writer.push(
+            MetadataTable::TypeDef,
+            &[0x4109, name, namespace, extends, field_list, 1],
+        );
+
+        let field_name = writer.string("Value");
+        let signature = writer.blob(&[0x06, 0x08]);
+        writer.push(MetadataTable::Field, &[0x0006, field_name, signature]);
+
+        let attribute = writer.type_ref("Test", "SampleAttribute");
+        let parent = CodedIndex::MemberRefParent.encode(MetadataTable::TypeRef, attribute);
+        let ctor = writer.string(".ctor");
+        let signature = writer.blob(&[0x20, 0x01, 0x01, 0x09]);
+        let ctor = writer.push(MetadataTable::MemberRef, &[parent, ctor, signature]);
+
+        writer.attribute(
+            CodedIndex::HasCustomAttribute.encode(MetadataTable::TypeDef, def),
+            CodedIndex::CustomAttributeType.encode(MetadataTable::MemberRef, ctor),
+            &[ConstantValue::U32(123)],
+        );
+
+        let file = File::try_from_bytes(writer.into_bytes()).unwrap();
+        let reader: &'static TypeReader =
+            Box::leak(Box::new(TypeReader::try_from_files(vec![file]).unwrap()));
+
+        let def = reader.expect_type_def(("Test", "Sample"));
+        assert!(def.category() == TypeCategory::Struct);
+        assert!(def.flags().windows_runtime());
+
+        let fields: Vec<&str> = def.fields().map(|field| field.name()).collect();
+        assert_eq!(fields, ["Value"]);
+
+        assert!(def.has_attribute(("Test", "SampleAttribute")));
+        let attribute = def.attributes().next().unwrap();
+        let args = attribute.args();
+        assert!(matches!(args[0].1, AttributeArg::U32(123)));
+    }
+}
@@ -0,0 +1,92 @@
+//! Renders selected type definitions and compares them against the snapshots checked in under
+//! `tests/snapshots`. Set the `BLESS` environment variable to update the snapshots after an
+//! intentional change to the generated code:
+//!
+//! ```text
+//! BLESS=1 cargo test -p windows_gen --test snapshots
+//! ```
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use windows_gen::*;
+
+fn render((namespace, name): (&str, &str)) -> String {
+    let reader = winmd::TypeReader::get();
+    let def = reader.expect_type_def((namespace, name));
+    let tokens = TypeDefinition::from_type_def(&def).gen();
+
+    let mut rustfmt = Command::new("rustfmt")
+        .args(&["--edition", "2018", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to run rustfmt");
+
+    rustfmt
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(tokens.as_str().as_bytes())
+        .unwrap();
+
+    let output = rustfmt.wait_with_output().unwrap();
+    assert!(
+        output.status.success(),
+        "Failed to format {}.{}",
+        namespace,
+        name
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn snapshot(type_name: (&str, &str)) {
+    let actual = render(type_name);
+
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("tests");
+    path.push("snapshots");
+    path.push(format!("{}.{}.rs", type_name.0, type_name.1));
+
+    if std::env::var_os("BLESS").is_some() {
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("Missing snapshot {:?}; run with BLESS=1 to create it", path))
+        .replace("\r\n", "\n");
+
+    assert!(
+        actual == expected,
+        "Generated code for {}.{} does not match {:?}; run with BLESS=1 to update it",
+        type_name.0,
+        type_name.1,
+        path
+    );
+}
+
+#[test]
+fn winrt_struct() {
+    snapshot(("Windows.Foundation", "Point"));
+}
+
+#[test]
+fn winrt_enum() {
+    snapshot(("Windows.Foundation", "AsyncStatus"));
+}
+
+#[test]
+fn winrt_interface() {
+    snapshot(("Windows.Foundation", "IStringable"));
+}
+
+#[test]
+fn winrt_delegate() {
+    snapshot(("Windows.Foundation", "AsyncActionCompletedHandler"));
+}
+
+#[test]
+fn win32_struct() {
+    snapshot(("Windows.Win32.DisplayDevices", "RECT"));
+}
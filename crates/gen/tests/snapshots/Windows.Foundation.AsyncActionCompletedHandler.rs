@@ -0,0 +1,147 @@
+#[doc = "The [`AsyncActionCompletedHandler`](https://docs.microsoft.com/uwp/api/windows.foundation.asyncactioncompletedhandler) delegate from `Windows.Foundation`."]
+#[repr(transparent)]
+pub struct AsyncActionCompletedHandler(::windows::IUnknown);
+impl ::std::clone::Clone for AsyncActionCompletedHandler {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+impl ::std::cmp::PartialEq for AsyncActionCompletedHandler {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl ::std::cmp::Eq for AsyncActionCompletedHandler {}
+impl ::std::fmt::Debug for AsyncActionCompletedHandler {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+unsafe impl ::windows::Interface for AsyncActionCompletedHandler {
+    type Vtable = AsyncActionCompletedHandler_abi;
+    const IID: ::windows::Guid = ::windows::Guid::from_values(
+        2767019137,
+        30409,
+        16573,
+        [139, 230, 177, 217, 15, 178, 10, 231],
+    );
+}
+unsafe impl ::windows::RuntimeType for AsyncActionCompletedHandler {
+    type DefaultType = ::std::option::Option<Self>;
+    const SIGNATURE: ::windows::ConstBuffer =
+        { ::windows::ConstBuffer::from_slice(b"delegate({a4ed5c81-76c9-40bd-8be6-b1d90fb20ae7})") };
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct AsyncActionCompletedHandler_abi(
+    pub  unsafe extern "system" fn(
+        this: ::windows::RawPtr,
+        iid: &::windows::Guid,
+        interface: *mut ::windows::RawPtr,
+    ) -> ::windows::ErrorCode,
+    pub unsafe extern "system" fn(this: ::windows::RawPtr) -> u32,
+    pub unsafe extern "system" fn(this: ::windows::RawPtr) -> u32,
+    pub  unsafe extern "system" fn(
+        this: ::windows::RawPtr,
+        async_info: ::windows::RawPtr,
+        async_status: AsyncStatus,
+    ) -> ::windows::ErrorCode,
+);
+impl AsyncActionCompletedHandler {
+    pub fn invoke<'a, T0__: ::std::convert::Into<::windows::Param<'a, IAsyncAction>>>(
+        &self,
+        async_info: T0__,
+        async_status: AsyncStatus,
+    ) -> ::windows::Result<()> {
+        let this = self;
+        unsafe {
+            (::windows::Interface::vtable(this).3)(
+                ::windows::Abi::abi(this),
+                async_info.into().ok()?.abi(),
+                async_status,
+            )
+            .ok()
+        }
+    }
+    pub const IID: ::windows::Guid = <Self as ::windows::Interface>::IID;
+    pub fn new<
+        F: FnMut(&::std::option::Option<IAsyncAction>, AsyncStatus) -> ::windows::Result<()> + 'static,
+    >(
+        invoke: F,
+    ) -> Self {
+        let com = AsyncActionCompletedHandler_box::<F> {
+            vtable: &AsyncActionCompletedHandler_box::<F>::VTABLE,
+            count: ::windows::RefCount::new(),
+            invoke,
+        };
+        unsafe { std::mem::transmute(::std::boxed::Box::new(com)) }
+    }
+}
+#[repr(C)]
+struct AsyncActionCompletedHandler_box<
+    F: FnMut(&::std::option::Option<IAsyncAction>, AsyncStatus) -> ::windows::Result<()> + 'static,
+> {
+    vtable: *const AsyncActionCompletedHandler_abi,
+    invoke: F,
+    count: ::windows::RefCount,
+}
+#[allow(non_snake_case)]
+impl<
+        F: FnMut(&::std::option::Option<IAsyncAction>, AsyncStatus) -> ::windows::Result<()> + 'static,
+    > AsyncActionCompletedHandler_box<F>
+{
+    const VTABLE: AsyncActionCompletedHandler_abi = AsyncActionCompletedHandler_abi(
+        Self::QueryInterface,
+        Self::AddRef,
+        Self::Release,
+        Self::Invoke,
+    );
+    unsafe extern "system" fn QueryInterface(
+        this: ::windows::RawPtr,
+        iid: &::windows::Guid,
+        interface: *mut ::windows::RawPtr,
+    ) -> ::windows::ErrorCode {
+        let this = this as *mut ::windows::RawPtr as *mut Self;
+        *interface = if iid == &<AsyncActionCompletedHandler as ::windows::Interface>::IID
+            || iid == &<::windows::IUnknown as ::windows::Interface>::IID
+            || iid == &<::windows::IAgileObject as ::windows::Interface>::IID
+        {
+            &mut (*this).vtable as *mut _ as _
+        } else {
+            ::std::ptr::null_mut()
+        };
+        if (*interface).is_null() {
+            ::windows::ErrorCode::E_NOINTERFACE
+        } else {
+            (*this).count.add_ref();
+            ::windows::ErrorCode::S_OK
+        }
+    }
+    unsafe extern "system" fn AddRef(this: ::windows::RawPtr) -> u32 {
+        let this = this as *mut ::windows::RawPtr as *mut Self;
+        (*this).count.add_ref()
+    }
+    unsafe extern "system" fn Release(this: ::windows::RawPtr) -> u32 {
+        let this = this as *mut ::windows::RawPtr as *mut Self;
+        let remaining = (*this).count.release();
+        if remaining == 0 {
+            Box::from_raw(this);
+        }
+        remaining
+    }
+    unsafe extern "system" fn Invoke(
+        this: ::windows::RawPtr,
+        async_info: ::windows::RawPtr,
+        async_status: AsyncStatus,
+    ) -> ::windows::ErrorCode {
+        let this = this as *mut ::windows::RawPtr as *mut Self;
+        ::windows::catch_panic(|| {
+            ((*this).invoke)(
+                &*(&async_info as *const <IAsyncAction as ::windows::Abi>::Abi
+                    as *const <IAsyncAction as ::windows::RuntimeType>::DefaultType),
+                async_status,
+            )
+            .into()
+        })
+    }
+}
@@ -0,0 +1,51 @@
+#[doc = "The [`AsyncStatus`](https://docs.microsoft.com/uwp/api/windows.foundation.asyncstatus) enum from `Windows.Foundation`."]
+#[allow(non_camel_case_types)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct AsyncStatus(pub i32);
+impl ::std::convert::From<i32> for AsyncStatus {
+    fn from(value: i32) -> Self {
+        Self(value)
+    }
+}
+impl ::std::convert::From<AsyncStatus> for i32 {
+    fn from(value: AsyncStatus) -> Self {
+        value.0
+    }
+}
+impl ::std::clone::Clone for AsyncStatus {
+    fn clone(&self) -> Self {
+        Self(self.0)
+    }
+}
+impl ::std::default::Default for AsyncStatus {
+    fn default() -> Self {
+        Self(0)
+    }
+}
+impl ::std::fmt::Debug for AsyncStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+impl ::std::marker::Copy for AsyncStatus {}
+impl AsyncStatus {
+    #![allow(non_upper_case_globals)]
+    pub const Canceled: Self = Self(2i32);
+    pub const Completed: Self = Self(1i32);
+    pub const Error: Self = Self(3i32);
+    pub const Started: Self = Self(0i32);
+    #[doc = r" Returns whether the value is one defined by the enum, as values read from the"]
+    #[doc = r" OS or the wire may not be."]
+    pub const fn is_defined(&self) -> bool {
+        matches!(self.0, 0i32 | 1i32 | 2i32 | 3i32)
+    }
+}
+unsafe impl ::windows::Abi for AsyncStatus {
+    type Abi = Self;
+}
+unsafe impl ::windows::RuntimeType for AsyncStatus {
+    type DefaultType = Self;
+    const SIGNATURE: ::windows::ConstBuffer =
+        ::windows::ConstBuffer::from_slice(b"enum(Windows.Foundation.AsyncStatus;i4)");
+}
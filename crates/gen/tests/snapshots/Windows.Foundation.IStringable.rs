@@ -0,0 +1,113 @@
+#[doc = "The [`IStringable`](https://docs.microsoft.com/uwp/api/windows.foundation.istringable) interface from `Windows.Foundation`."]
+#[repr(transparent)]
+pub struct IStringable(::windows::Object);
+impl ::std::clone::Clone for IStringable {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+impl ::std::fmt::Debug for IStringable {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+impl ::std::cmp::PartialEq for IStringable {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl ::std::cmp::Eq for IStringable {}
+impl IStringable {
+    #[doc = "The [`ToString`](https://docs.microsoft.com/uwp/api/windows.foundation.istringable.tostring) method of `IStringable`."]
+    pub fn to_string(&self) -> ::windows::Result<::windows::HString> {
+        let this = self;
+        unsafe {
+            let mut result__: <::windows::HString as ::windows::Abi>::Abi = ::std::mem::zeroed();
+            (::windows::Interface::vtable(this).6)(::windows::Abi::abi(this), &mut result__)
+                .from_abi::<::windows::HString>(result__)
+        }
+    }
+    pub const IID: ::windows::Guid = <Self as ::windows::Interface>::IID;
+    pub fn cast<Target: ::windows::Interface>(&self) -> ::windows::Result<Target> {
+        ::windows::Interface::cast(self)
+    }
+}
+unsafe impl ::windows::Interface for IStringable {
+    type Vtable = IStringable_abi;
+    const IID: ::windows::Guid = ::windows::Guid::from_values(
+        2520162132,
+        36534,
+        18672,
+        [171, 206, 193, 178, 17, 230, 39, 195],
+    );
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IStringable_abi(
+    pub  unsafe extern "system" fn(
+        this: ::windows::RawPtr,
+        iid: &::windows::Guid,
+        interface: *mut ::windows::RawPtr,
+    ) -> ::windows::ErrorCode,
+    pub unsafe extern "system" fn(this: ::windows::RawPtr) -> u32,
+    pub unsafe extern "system" fn(this: ::windows::RawPtr) -> u32,
+    pub  unsafe extern "system" fn(
+        this: ::windows::RawPtr,
+        count: *mut u32,
+        values: *mut *mut ::windows::Guid,
+    ) -> ::windows::ErrorCode,
+    pub  unsafe extern "system" fn(
+        this: ::windows::RawPtr,
+        value: *mut ::windows::RawPtr,
+    ) -> ::windows::ErrorCode,
+    pub unsafe extern "system" fn(this: ::windows::RawPtr, value: *mut i32) -> ::windows::ErrorCode,
+    pub  unsafe extern "system" fn(
+        this: ::windows::RawPtr,
+        result__: *mut ::windows::RawPtr,
+    ) -> ::windows::ErrorCode,
+);
+unsafe impl ::windows::RuntimeType for IStringable {
+    type DefaultType = ::std::option::Option<Self>;
+    const SIGNATURE: ::windows::ConstBuffer =
+        { ::windows::ConstBuffer::from_slice(b"{96369f54-8eb6-48f0-abce-c1b211e627c3}") };
+}
+impl ::std::convert::From<IStringable> for ::windows::Object {
+    fn from(value: IStringable) -> Self {
+        value.0
+    }
+}
+impl ::std::convert::From<&IStringable> for ::windows::Object {
+    fn from(value: &IStringable) -> Self {
+        ::std::convert::From::from(::std::clone::Clone::clone(value))
+    }
+}
+impl<'a> ::std::convert::Into<::windows::Param<'a, ::windows::Object>> for IStringable {
+    fn into(self) -> ::windows::Param<'a, ::windows::Object> {
+        ::windows::Param::Owned(::std::convert::Into::<::windows::Object>::into(self))
+    }
+}
+impl<'a> ::std::convert::Into<::windows::Param<'a, ::windows::Object>> for &'a IStringable {
+    fn into(self) -> ::windows::Param<'a, ::windows::Object> {
+        ::windows::Param::Borrowed(&self.0)
+    }
+}
+impl ::std::convert::From<IStringable> for ::windows::IUnknown {
+    fn from(value: IStringable) -> Self {
+        value.0.into()
+    }
+}
+impl ::std::convert::From<&IStringable> for ::windows::IUnknown {
+    fn from(value: &IStringable) -> Self {
+        ::std::convert::From::from(::std::clone::Clone::clone(value))
+    }
+}
+impl<'a> ::std::convert::Into<::windows::Param<'a, ::windows::IUnknown>> for IStringable {
+    fn into(self) -> ::windows::Param<'a, ::windows::IUnknown> {
+        ::windows::Param::Owned(::std::convert::Into::<::windows::IUnknown>::into(self))
+    }
+}
+impl<'a> ::std::convert::Into<::windows::Param<'a, ::windows::IUnknown>> for &'a IStringable {
+    fn into(self) -> ::windows::Param<'a, ::windows::IUnknown> {
+        ::windows::Param::Borrowed(unsafe { ::std::mem::transmute(self) })
+    }
+}
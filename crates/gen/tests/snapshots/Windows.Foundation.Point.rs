@@ -0,0 +1,45 @@
+#[doc = "The [`Point`](https://docs.microsoft.com/uwp/api/windows.foundation.point) struct from `Windows.Foundation`."]
+#[repr(C)]
+#[allow(non_snake_case)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+impl Point {}
+unsafe impl ::windows::Abi for Point {
+    type Abi = Self;
+}
+impl ::std::default::Default for Point {
+    fn default() -> Self {
+        Self { x: 0.0, y: 0.0 }
+    }
+}
+impl ::std::fmt::Debug for Point {
+    fn fmt(&self, fmt: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        fmt.debug_struct("Point")
+            .field("x", &format_args!("{:?}", self.x))
+            .field("y", &format_args!("{:?}", self.y))
+            .finish()
+    }
+}
+impl ::std::clone::Clone for Point {
+    fn clone(&self) -> Self {
+        Self {
+            x: self.x,
+            y: self.y,
+        }
+    }
+}
+impl ::std::cmp::PartialEq for Point {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+impl ::std::cmp::Eq for Point {}
+impl ::std::marker::Copy for Point {}
+unsafe impl ::windows::RuntimeType for Point {
+    type DefaultType = Self;
+    const SIGNATURE: ::windows::ConstBuffer =
+        ::windows::ConstBuffer::from_slice(b"struct(Windows.Foundation.Point;f4;f4)");
+}
+const _: () = assert!(::std::mem::size_of::<Point>() == 8 && ::std::mem::align_of::<Point>() == 4);
@@ -0,0 +1,55 @@
+#[doc = "The [`RECT`](https://docs.microsoft.com/search/?terms=RECT) struct from `Windows.Win32.DisplayDevices`."]
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Hash)]
+pub struct RECT {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+impl RECT {}
+unsafe impl ::windows::Abi for RECT {
+    type Abi = Self;
+}
+impl ::std::default::Default for RECT {
+    fn default() -> Self {
+        Self {
+            left: 0,
+            top: 0,
+            right: 0,
+            bottom: 0,
+        }
+    }
+}
+impl ::std::fmt::Debug for RECT {
+    fn fmt(&self, fmt: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        fmt.debug_struct("RECT")
+            .field("left", &format_args!("{:?}", self.left))
+            .field("top", &format_args!("{:?}", self.top))
+            .field("right", &format_args!("{:?}", self.right))
+            .field("bottom", &format_args!("{:?}", self.bottom))
+            .finish()
+    }
+}
+impl ::std::clone::Clone for RECT {
+    fn clone(&self) -> Self {
+        Self {
+            left: self.left,
+            top: self.top,
+            right: self.right,
+            bottom: self.bottom,
+        }
+    }
+}
+impl ::std::cmp::PartialEq for RECT {
+    fn eq(&self, other: &Self) -> bool {
+        self.left == other.left
+            && self.top == other.top
+            && self.right == other.right
+            && self.bottom == other.bottom
+    }
+}
+impl ::std::cmp::Eq for RECT {}
+impl ::std::marker::Copy for RECT {}
+const _: () = assert!(::std::mem::size_of::<RECT>() == 16 && ::std::mem::align_of::<RECT>() == 4);
@@ -1,27 +1,43 @@
 use super::*;
-use gen::{NamespaceTypes, TypeLimit, TypeLimits, TypeTree};
+use gen::{GenOptions, NamespaceTypes, TypeLimit, TypeLimits};
 use std::convert::{TryFrom, TryInto};
+use syn::ext::IdentExt;
 use syn::spanned::Spanned;
 
-pub struct BuildLimits(pub std::collections::BTreeSet<TypesDeclaration>);
+pub struct BuildLimits(
+    pub std::collections::BTreeSet<TypesDeclaration>,
+    pub GenOptions,
+    pub Vec<NamespaceAlias>,
+);
+
+/// A shorter name for a namespace's module, given with `as` syntax.
+pub struct NamespaceAlias {
+    pub alias: syn::Ident,
+    pub namespace: &'static str,
+}
 
 impl BuildLimits {
-    pub fn to_tokens_string(self) -> Result<String, proc_macro2::TokenStream> {
+    /// Returns the generated code along with the IDL for the requested types, the list of
+    /// deprecated and ANSI types that were left out, and the cargo features gating each namespace, if
+    /// enabled.
+    pub fn to_tokens_string(
+        self,
+    ) -> Result<(String, Option<String>, Option<String>, Option<String>), proc_macro2::TokenStream>
+    {
         let is_foundation = self.0.is_empty();
+        let emit_idl = self.1.idl;
+        let exclude = self.1.exclude_deprecated || self.1.exclude_ansi;
+        let features = self.1.features;
+        self.1.set();
 
-        let reader = winmd::TypeReader::get();
+        let reader = winmd::TypeReader::try_get().map_err(|error| {
+            syn::Error::new(proc_macro2::Span::call_site(), error).to_compile_error()
+        })?;
 
         let mut limits = TypeLimits::new(reader);
 
-        let foundation_namespaces = &[
-            "Windows.Foundation",
-            "Windows.Foundation.Collections",
-            "Windows.Foundation.Diagnostics",
-            "Windows.Foundation.Numerics",
-        ];
-
         if is_foundation {
-            for namespace in foundation_namespaces {
+            for namespace in gen::FOUNDATION_NAMESPACES {
                 limits
                     .insert(NamespaceTypes {
                         namespace: &namespace,
@@ -40,28 +56,50 @@ impl BuildLimits {
             })?;
         }
 
-        let mut tree = TypeTree::from_limits(reader, &limits);
+        let idl = if emit_idl {
+            Some(gen::gen_idl(reader, &limits))
+        } else {
+            None
+        };
 
-        if !is_foundation {
-            for namespace in foundation_namespaces {
-                tree.remove(namespace);
-            }
+        let exclusions = if exclude {
+            Some(gen::gen_exclusions(reader, &limits))
+        } else {
+            None
+        };
 
-            tree.reexport();
-        }
+        let feature_list = if features {
+            Some(gen::gen_features(reader, &limits, is_foundation))
+        } else {
+            None
+        };
 
-        let ts = tree.gen().fold(squote::TokenStream::new(), |mut accum, n| {
-            accum.combine(&n);
-            accum
-        });
+        let mut ts = gen::gen_bindings(reader, &limits, is_foundation);
+
+        for alias in self.2 {
+            let path = alias.namespace.split('.').map(|segment| {
+                let segment = gen::to_snake(segment);
+                gen::format_ident(&segment)
+            });
+
+            let name = gen::format_ident(&alias.alias.to_string());
+
+            let feature = match gen::feature_name(alias.namespace) {
+                Some(feature) if features => squote::quote! { #[cfg(feature = #feature)] },
+                _ => squote::TokenStream::new(),
+            };
+
+            ts.combine(&squote::quote! { #feature pub use self#(::#path)* as #name; });
+        }
 
-        Ok(ts.into_string())
+        Ok((ts.into_string(), idl, exclusions, feature_list))
     }
 }
 
 pub struct TypesDeclaration {
     pub types: NamespaceTypes,
     pub syntax: syn::UseTree,
+    pub alias: Option<syn::Ident>,
 }
 
 impl std::cmp::PartialOrd for TypesDeclaration {
@@ -87,9 +125,12 @@ impl Eq for TypesDeclaration {}
 impl TryFrom<syn::UseTree> for TypesDeclaration {
     type Error = syn::Error;
     fn try_from(tree: syn::UseTree) -> Result<Self, Self::Error> {
+        let (types, alias) = use_tree_to_namespace_types(&tree)?;
+
         Ok(Self {
-            types: use_tree_to_namespace_types(&tree)?,
+            types,
             syntax: tree,
+            alias,
         })
     }
 }
@@ -97,26 +138,142 @@ impl TryFrom<syn::UseTree> for TypesDeclaration {
 impl syn::parse::Parse for BuildLimits {
     fn parse(input: syn::parse::ParseStream) -> syn::parse::Result<Self> {
         let mut limits = std::collections::BTreeSet::new();
+        let mut options = GenOptions::default();
+        let mut aliases = Vec::new();
         loop {
             if input.is_empty() {
                 break;
             }
 
-            let use_tree: syn::UseTree = input.parse()?;
-            let limit: TypesDeclaration = use_tree.try_into()?;
+            if input.peek(syn::Ident) && input.peek2(syn::Token![=]) {
+                parse_option(input, &mut options)?;
+            } else {
+                let use_tree: syn::UseTree = input.parse()?;
+                let mut limit: TypesDeclaration = use_tree.try_into()?;
 
-            limits.insert(limit);
+                if let Some(alias) = limit.alias.take() {
+                    aliases.push(NamespaceAlias {
+                        alias,
+                        namespace: limit.types.namespace,
+                    });
+                }
+
+                limits.insert(limit);
+            }
 
             if !input.is_empty() {
                 input.parse::<syn::Token![,]>()?;
             }
         }
-        Ok(Self(limits))
+        Ok(Self(limits, options, aliases))
+    }
+}
+
+fn parse_option(
+    input: syn::parse::ParseStream,
+    options: &mut GenOptions,
+) -> syn::parse::Result<()> {
+    let name: syn::Ident = input.parse()?;
+    input.parse::<syn::Token![=]>()?;
+
+    match name.to_string().as_str() {
+        "tuple_returns" => options.tuple_returns = input.parse::<syn::LitBool>()?.value,
+        "idl" => options.idl = input.parse::<syn::LitBool>()?.value,
+        "keep_reserved" => options.keep_reserved = input.parse::<syn::LitBool>()?.value,
+        "raw_arrays" => options.raw_arrays = input.parse::<syn::LitBool>()?.value,
+        "raw_pointers" => options.raw_pointers = input.parse::<syn::LitBool>()?.value,
+        "raw_strings" => options.raw_strings = input.parse::<syn::LitBool>()?.value,
+        "exclude_deprecated" => options.exclude_deprecated = input.parse::<syn::LitBool>()?.value,
+        "exclude_ansi" => options.exclude_ansi = input.parse::<syn::LitBool>()?.value,
+        "serde" => options.serde = input.parse::<syn::LitBool>()?.value,
+        "bytemuck" => options.bytemuck = input.parse::<syn::LitBool>()?.value,
+        "enums" => options.enums = input.parse::<syn::LitBool>()?.value,
+        "features" => options.features = input.parse::<syn::LitBool>()?.value,
+        "link" => options.link = Some(input.parse::<syn::LitStr>()?.value()),
+        "delay_load" => {
+            let content;
+            syn::bracketed!(content in input);
+            let functions =
+                content.parse_terminated::<syn::Ident, syn::Token![,]>(|input| input.parse())?;
+            options.delay_load = functions
+                .iter()
+                .map(|function| function.to_string())
+                .collect();
+        }
+        "variadic" => {
+            let content;
+            syn::bracketed!(content in input);
+            let functions =
+                content.parse_terminated::<syn::Ident, syn::Token![,]>(|input| input.parse())?;
+            options.variadic = functions
+                .iter()
+                .map(|function| function.to_string())
+                .collect();
+        }
+        "builders" => {
+            let content;
+            syn::bracketed!(content in input);
+            let structs =
+                content.parse_terminated::<syn::Ident, syn::Token![,]>(|input| input.parse())?;
+            options.builders = structs.iter().map(|name| name.to_string()).collect();
+        }
+        "rename" => {
+            let content;
+            syn::bracketed!(content in input);
+            let renames =
+                content.parse_terminated::<(String, String), syn::Token![,]>(|input| {
+                    let owner = syn::Ident::parse_any(input)?;
+                    input.parse::<syn::Token![.]>()?;
+                    let member = syn::Ident::parse_any(input)?;
+                    input.parse::<syn::Token![=]>()?;
+                    let name = syn::Ident::parse_any(input)?;
+                    Ok((format!("{}.{}", owner, member), name.to_string()))
+                })?;
+            options.rename = renames.into_iter().collect();
+        }
+        "allow" => {
+            let content;
+            syn::bracketed!(content in input);
+            let lints = content
+                .parse_terminated::<syn::Path, syn::Token![,]>(syn::Path::parse_mod_style)?;
+
+            options.allow = lints
+                .iter()
+                .map(|lint| {
+                    let segments: Vec<String> = lint
+                        .segments
+                        .iter()
+                        .map(|segment| segment.ident.to_string())
+                        .collect();
+                    segments.join("::")
+                })
+                .collect();
+        }
+        _ => return Err(syn::Error::new(name.span(), "Unknown option")),
     }
+
+    Ok(())
 }
 
-fn use_tree_to_namespace_types(use_tree: &syn::UseTree) -> syn::parse::Result<NamespaceTypes> {
-    let reader = winmd::TypeReader::get();
+fn use_tree_to_namespace_types(
+    use_tree: &syn::UseTree,
+) -> syn::parse::Result<(NamespaceTypes, Option<syn::Ident>)> {
+    let reader =
+        winmd::TypeReader::try_get().map_err(|error| syn::Error::new(use_tree.span(), error))?;
+
+    // A namespace renamed with `as` includes all of its types and gains an alias module.
+    if let Some((path, rename)) = use_tree_rename(use_tree, String::new()) {
+        let namespace = find_namespace(reader, &path, rename.span())
+            .map_err(|_| syn::Error::new(rename.span(), "Only namespaces may be renamed"))?;
+
+        let types = NamespaceTypes {
+            namespace,
+            limit: TypeLimit::All,
+        };
+
+        return Ok((types, Some(rename.rename.clone())));
+    }
+
     fn recurse(
         reader: &'static winmd::TypeReader,
         tree: &syn::UseTree,
@@ -177,7 +334,25 @@ fn use_tree_to_namespace_types(use_tree: &syn::UseTree) -> syn::parse::Result<Na
         }
     }
 
-    recurse(reader, use_tree, &mut String::new())
+    Ok((recurse(reader, use_tree, &mut String::new())?, None))
+}
+
+fn use_tree_rename(tree: &syn::UseTree, mut current: String) -> Option<(String, &syn::UseRename)> {
+    if !current.is_empty() {
+        current.push('.');
+    }
+
+    match tree {
+        syn::UseTree::Path(p) => {
+            current.push_str(&p.ident.to_string());
+            use_tree_rename(&p.tree, current)
+        }
+        syn::UseTree::Rename(r) => {
+            current.push_str(&r.ident.to_string());
+            Some((current, r))
+        }
+        _ => None,
+    }
 }
 
 fn find_namespace(
@@ -8,7 +8,8 @@ struct Implements(Vec<gen::TypeDefinition>);
 impl syn::parse::Parse for Implements {
     fn parse(inner_type: syn::parse::ParseStream) -> syn::parse::Result<Self> {
         let mut types = Vec::new();
-        let reader = winmd::TypeReader::get();
+        let reader = winmd::TypeReader::try_get()
+            .map_err(|error| syn::Error::new(inner_type.span(), error))?;
 
         loop {
             use_tree_to_types(reader, &inner_type.parse::<ImplementTree>()?, &mut types)?;
@@ -23,6 +23,27 @@ use syn::parse_macro_input;
 /// follow the same convention as Rust `use` paths. Types know which other types they depend on so
 /// `build` will generate any other WinRT types needed for the specified type to work.
 ///
+/// A namespace may be given a shorter name with `as`, such as
+/// `windows::win32::windows_and_messaging as wm`, which generates all of the namespace's types
+/// and adds a `wm` module alongside the generated `windows` module that refers to it.
+///
+/// # Metadata
+/// Types are read from the `.winmd` files in the workspace's `.windows/winmd` directory, or the
+/// metadata bundled with the crate if there are none. MIDL 3.0 `.idl` files in the same directory
+/// are compiled to metadata first, so SDKs that ship IDL rather than a winmd may be used without
+/// transcribing their interfaces by hand.
+///
+/// The `WINDOWS_WINMD_PATHS` environment variable, which may be set in the `[env]` section of
+/// `.cargo/config.toml`, replaces the `.windows/winmd` directory with a list of files and
+/// directories separated like those of `PATH`, such as an internal mirror of the metadata.
+///
+/// Setting the `WINDOWS_WINMD_VERSION` environment variable pins the build to the metadata in the
+/// `<version>` subdirectory of each metadata directory instead, or to the metadata bundled with
+/// the crate if set to `bundled`.
+///
+/// The generated `provenance` module records the generator version and the name, size, and hash
+/// of each metadata file that was read, so that bindings can be traced back to their inputs.
+///
 /// # Example
 /// The following `build!` generates all types inside of the `microsoft::ai::machine_learning`
 /// namespace.
@@ -32,15 +53,136 @@ use syn::parse_macro_input;
 ///     microsoft::ai::machine_learning::*
 /// );
 /// ```
+///
+/// # Options
+/// Options that change the shape of the generated code may be listed alongside the types
+/// using `name = value` syntax:
+///
+/// - `tuple_returns = true` returns the out parameters of WinRT methods as part of a tuple
+///   instead of taking them as `&mut` parameters. Win32 functions and COM interface methods
+///   are unchanged.
+/// - `idl = true` also writes a `windows.idl` file next to the generated code, describing the
+///   requested WinRT types as MIDL 3.0 for tools that consume IDL rather than metadata.
+/// - `allow = [lint, ...]` replaces the lints allowed on each generated module, which by
+///   default are `non_camel_case_types`, `non_snake_case`, `non_upper_case_globals`,
+///   `unused_variables`, `deprecated`, and `clippy::all`, so that crates built with
+///   `-D warnings` aren't broken by generated code. Allowing `deprecated` keeps the bindings
+///   from warning about their own uses of deprecated APIs, which are still reported to callers.
+/// - `delay_load = [function, ...]` resolves the named functions with `LoadLibraryExW` and
+///   `GetProcAddress` the first time they're called instead of importing them, returning a
+///   `Result` with the error from the loader if they're missing. A binary can then use newer
+///   APIs where they're available while still launching on older versions of Windows.
+/// - `variadic = [function, ...]` imports the named functions, such as `wsprintfW`, as the C
+///   variadic functions they are, taking any arguments after their fixed parameters. The
+///   metadata only describes them by their fixed parameters, and functions whose signature is
+///   marked variadic are imported this way regardless. Variadic functions are never wrapped or
+///   delay loaded, so their parameters are always taken as they're imported.
+/// - `builders = [struct, ...]` gives the named structs, such as `DXGI_SWAP_CHAIN_DESC1`, a
+///   chained `with_` setter for each field, so that `DXGI_SWAP_CHAIN_DESC1::default()
+///   .with_width(640).with_height(480)` sets only the fields that matter. Win32 structs with a
+///   size field, like `WNDCLASSEXW` and `STARTUPINFOW`, get them regardless, with the size
+///   already filled in by `default`.
+/// - `link = "onecore"` links every function against the given umbrella library, such as
+///   `onecore`, `onecoreuap`, or `windowsapp`, rather than the classic import libraries like
+///   `kernel32` and `user32`, for targets such as Windows containers and the Store where the
+///   classic libraries aren't allowed.
+/// - `rename = [CIECHROMA.Y = luminance]` names the listed struct fields and parameters, keyed
+///   by the struct, function, or method and the name in the metadata. By default they're
+///   converted to snake case, and names that still collide, like the `y` and `Y` fields of
+///   `CIECHROMA`, are numbered in declaration order as `y` and `y2`. Names that are Rust keywords are
+///   escaped as raw identifiers, such as `r#type`, or with a trailing underscore for `self`,
+///   `Self`, `crate`, and `super`, which can't be raw identifiers.
+/// - `keep_reserved = true` keeps reserved parameters, such as `pvReserved`, in function
+///   signatures. By default they're left out and passed as null or zero on the caller's behalf,
+///   as the functions require.
+/// - `raw_arrays = true` keeps array parameters as a pointer and a length, as the functions
+///   are imported. By default, arrays whose length the metadata says is passed in another
+///   parameter, such as the buffer and size passed to `ReadFile`, are taken as a `&[T]` or
+///   `&mut [T]` slice whose length is passed on the caller's behalf. Untyped buffers are taken as
+///   slices of bytes, and empty slices are passed as null.
+/// - `raw_pointers = true` keeps optional pointer parameters as raw pointers. By default, those
+///   the metadata marks optional that point to a single value, such as the `SECURITY_ATTRIBUTES`
+///   passed to `CreateEventW`, are taken as an `Option<&T>`, or an `Option<&mut T>` if the
+///   function may write through them, and `None` is passed as null.
+/// - `raw_strings = true` keeps string parameters as raw pointers. By default, null-terminated
+///   strings the function only reads, such as the file name passed to `CreateFileW`, are taken
+///   as anything that converts to a `windows::StringParam`: a `&str` or `String`, an `&HString`,
+///   an `&OsStr` or `&Path` for wide strings, a `&CStr` for ANSI strings, or a raw pointer,
+///   which is passed as is so that null can still be passed for optional strings.
+/// - `exclude_deprecated = true` leaves deprecated types, along with ANSI functions and structs
+///   like `MessageBoxA` that have a wide counterpart, out of namespaces included with `*`. Types
+///   named explicitly or needed by other types are still generated, and the names of those left
+///   out are written to a `windows.excluded.txt` file next to the generated code.
+/// - `exclude_ansi = true` leaves only the ANSI functions and structs out, keeping deprecated
+///   types, so that only the wide versions of APIs like `MessageBoxW` can be called. Types from
+///   other namespaces that only the ANSI functions need aren't pulled in for them either, and the
+///   names of those left out are written to `windows.excluded.txt` as for `exclude_deprecated`.
+/// - `serde = true` derives `serde::Serialize` and `serde::Deserialize` for enums and for
+///   structs made up of numbers, enums, and other such structs, so that values like
+///   `DISPLAYCONFIG_SOURCE_MODE` can be persisted directly. Structs with pointers, interfaces,
+///   strings, unions, or arrays of more than 32 elements are left alone. The crate must depend
+///   on `serde` and enable the `serde` feature of the `windows` crate, which implements the
+///   traits for `Guid`, `ErrorCode`, and `BOOL`.
+/// - `bytemuck = true` implements `bytemuck::Pod` and `bytemuck::Zeroable` for enums and for
+///   structs that are plain data, such as `RECT` and `POINT`, so that they may be cast to and
+///   from byte slices with `bytemuck::cast_slice`. Structs with pointers, booleans, padding, or
+///   packing, as well as unions, are left alone. The crate must depend on `bytemuck` and enable
+///   the `bytemuck` feature of the `windows` crate, which implements the traits for `Guid`,
+///   `ErrorCode`, and `BOOL`.
+/// - `enums = true` also generates a Rust `enum` for each enum that doesn't hold flags, named
+///   for the enum with an `Enum` suffix, such as `AsyncStatusEnum`, so that values can be matched
+///   exhaustively within the crate the bindings are generated in. The enums are `non_exhaustive`,
+///   as a newer SDK may add values, so other crates need a wildcard arm. Each converts from the
+///   generated type with `TryFrom`, which fails with the original value if it isn't one the
+///   metadata declares, and back with `From`. Bindings still take and return the generated types,
+///   as the OS may pass values a newer SDK added, and their `Debug` output names the value where
+///   it's declared.
+/// - `features = true` puts each namespace's module behind a cargo feature named for the
+///   namespace without its `Windows` prefix, such as `Win32_Direct3D11` or `UI_Xaml`, so that a
+///   crate of shared bindings only compiles the namespaces its dependents enable. The features
+///   are written to a `windows.features.txt` file next to the generated code, ready to be pasted
+///   into the `[features]` section of the crate's `Cargo.toml`. Each enables the features of its
+///   parent namespace and of the namespaces its types depend on.
 #[proc_macro]
 pub fn build(stream: TokenStream) -> TokenStream {
     let build = parse_macro_input!(stream as BuildLimits);
 
-    let tokens = match build.to_tokens_string() {
+    let (tokens, idl, exclusions, features) = match build.to_tokens_string() {
         Ok(t) => t,
         Err(t) => return t.into(),
     };
 
+    let tokens = gen::format_code(&tokens);
+
+    let idl = match idl {
+        Some(idl) => quote! {
+            path.set_file_name("windows.idl");
+            ::std::fs::write(&path, #idl).expect("Could not write IDL to output file");
+        },
+        None => quote! {},
+    };
+
+    let exclusions = match exclusions {
+        Some(exclusions) => quote! {
+            path.set_file_name("windows.excluded.txt");
+            ::std::fs::write(&path, #exclusions).expect("Could not write excluded types to output file");
+        },
+        None => quote! {},
+    };
+
+    let features = match features {
+        Some(features) => quote! {
+            path.set_file_name("windows.features.txt");
+            ::std::fs::write(&path, #features).expect("Could not write features to output file");
+        },
+        None => quote! {},
+    };
+
+    let metadata_paths: Vec<String> = winmd::winmd_paths()
+        .iter()
+        .map(|path| path.to_string_lossy().into_owned())
+        .collect();
+
     let workspace_windows_dir = winmd::workspace_windows_dir();
 
     let mut source = workspace_windows_dir.clone();
@@ -62,6 +204,13 @@ pub fn build(stream: TokenStream) -> TokenStream {
             // environment variables are only set when the build script run and not when it is being compiled.
 
             use ::std::io::Write;
+
+            // The bindings are generated when the build script is compiled, so it's rustc that
+            // has to notice when the metadata or the variables selecting it change.
+            const _: ::std::option::Option<&str> = ::std::option_env!("WINDOWS_WINMD_VERSION");
+            const _: ::std::option::Option<&str> = ::std::option_env!("WINDOWS_WINMD_PATHS");
+            #(const _: &[u8] = ::std::include_bytes!(#metadata_paths);)*
+
             let mut path = ::std::path::PathBuf::from(
                 ::std::env::var("OUT_DIR").expect("No `OUT_DIR` env variable set"),
             );
@@ -70,9 +219,9 @@ pub fn build(stream: TokenStream) -> TokenStream {
             let mut file = ::std::fs::File::create(&path).expect("Failed to create windows.rs");
             file.write_all(#tokens.as_bytes()).expect("Could not write generated code to output file");
 
-            let mut cmd = ::std::process::Command::new("rustfmt");
-            cmd.arg(&path);
-            let _ = cmd.output();
+            #idl
+            #exclusions
+            #features
 
             fn copy(source: &::std::path::PathBuf, destination: &mut ::std::path::PathBuf) {
                 if let ::std::result::Result::Ok(files) = ::std::fs::read_dir(source) {
@@ -129,6 +278,10 @@ pub fn build(stream: TokenStream) -> TokenStream {
 /// If the attribute `TokenStream` contains the name of a WinRT class then all of its
 /// interfaces are implemented. Otherwise, whatever interfaces are contained within
 /// the attribute TokenStream are implemented.
+///
+/// Methods that return an `IAsyncAction` or `IAsyncOperation<T>` can wrap a Rust future
+/// with `IAsyncAction::from_future` or `IAsyncOperation::from_future`, which take care of
+/// the completion handler, status, cancelation, and errors.
 #[proc_macro_attribute]
 pub fn implement(attribute: TokenStream, input: TokenStream) -> TokenStream {
     implement::gen(attribute, input)
@@ -10,7 +10,11 @@ fn main() {
         test_component::*,
         windows::ui::xaml::*,
         windows::data::xml::dom::*,
+
+        // Namespace aliases
+        windows::ai::machine_learning as ml,
         windows::application_model::appointments::AppointmentDaysOfWeek,
+        windows::application_model::contacts::ContactDate,
 
         // Usage of method named `try` when `ICurrencyIdentifiersStatics` is generated
         // This tests that it is escaped.
@@ -19,6 +23,12 @@ fn main() {
         // Test for https://github.com/microsoft/windows-rs/issues/300
         windows::devices::wifi_direct::WiFiDirectDevice,
 
+        // Event args with a deferral gain a `with_deferral` helper.
+        windows::application_model::SuspendingOperation,
+
+        // Background tasks implemented in Rust.
+        windows::application_model::background::{BackgroundTaskBuilder, IBackgroundTask, TimeTrigger},
+
         // Test for https://github.com/microsoft/windows-rs/issues/280
         windows::application_model::email::EmailAttachment,
         windows::storage::streams::{InMemoryRandomAccessStream, RandomAccessStreamReference},
@@ -38,10 +48,11 @@ fn main() {
             PROPENUMPROCW,
         },
         windows::win32::dxgi::{
-            DXGI_ADAPTER_FLAG, DXGI_FORMAT, DXGI_MODE_DESC, DXGI_MODE_SCALING,
+            DXGI_ADAPTER_DESC1, DXGI_ADAPTER_FLAG, DXGI_FORMAT, DXGI_MODE_DESC, DXGI_MODE_SCALING,
             DXGI_MODE_SCANLINE_ORDER, DXGI_RATIONAL,IDXGIFactory7, CreateDXGIFactory1
         },
         windows::win32::display_devices::{
+            CIECHROMA,
             RECT,
         },
         windows::win32::system_services::{
@@ -49,10 +60,12 @@ fn main() {
             SetEvent,
             WaitForSingleObject,
             WM_KEYUP,
+            HWND_TOPMOST,
             DXGI_ERROR_INVALID_CALL,
         },
         windows::win32::direct3d12::{
-            D3D12_DEFAULT_BLEND_FACTOR_ALPHA
+            D3D12_DEFAULT_BLEND_FACTOR_ALPHA,
+            ID3D12DescriptorHeap,
         },
         windows::win32::windows_accessibility::{
             UIA_ScrollPatternNoScroll
@@ -63,8 +76,8 @@ fn main() {
         windows::win32::windows_programming::{
             CloseHandle
         },
-        windows::win32::com::CreateUri,
-        windows::win32::structured_storage::{CreateStreamOnHGlobal, STREAM_SEEK},
+        windows::win32::com::{CreateUri, StringFromIID},
+        windows::win32::structured_storage::{CreateStreamOnHGlobal, STREAM_SEEK, PROPVARIANT},
         windows::win32::upnp::UIAnimationTransitionLibrary,
         windows::win32::ldap::ldapsearch,
         windows::win32::upnp::UIAnimationManager,
@@ -73,5 +86,29 @@ fn main() {
         windows::win32::direct3d11::D3DDisassemble11Trace,
         windows::win32::windows_update_agent::IAutomaticUpdates,
         windows::win32::windows_color_system::WhitePoint,
+        windows::win32::multimedia::WAVEFORMATEX,
+        windows::win32::background_intelligent_transfer_service::BITS_JOB_PROPERTY_VALUE,
+        windows::win32::keyboard_and_mouse_input::INPUT,
+        windows::win32::security::BCryptGenRandom,
+
+        // Resolved the first time it's called rather than imported
+        windows::win32::hi_dpi::SetProcessDpiAwarenessContext,
+        delay_load = [SetProcessDpiAwarenessContext],
+
+        // Imported as a C variadic function
+        windows::win32::menus_and_resources::wsprintfW,
+        variadic = [wsprintfW],
+
+        // Names a field that would otherwise be numbered to avoid `y`
+        rename = [CIECHROMA.Y = luminance],
+
+        // Derives serde traits for plain data types
+        serde = true,
+
+        // Implements bytemuck traits for plain data types
+        bytemuck = true,
+
+        // Generates Rust enums alongside enums that don't hold flags
+        enums = true,
     );
 }
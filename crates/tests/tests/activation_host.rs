@@ -0,0 +1,19 @@
+use windows::ActivationHost;
+
+#[test]
+fn manifest() {
+    let manifest = ActivationHost::manifest("bin\\server.exe", &["Server.Widget", "Server.Gadget"]);
+
+    assert_eq!(
+        manifest,
+        r#"<Extension Category="windows.activatableClass.outOfProcessServer">
+  <OutOfProcessServer ServerName="server">
+    <Path>bin\server.exe</Path>
+    <Instancing>singleInstance</Instancing>
+    <ActivatableClass ActivatableClassId="Server.Widget" />
+    <ActivatableClass ActivatableClassId="Server.Gadget" />
+  </OutOfProcessServer>
+</Extension>
+"#
+    );
+}
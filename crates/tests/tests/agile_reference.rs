@@ -0,0 +1,18 @@
+use windows::foundation::collections::PropertySet;
+use windows::AgileReference;
+
+#[test]
+fn agile_reference() -> windows::Result<()> {
+    let set = PropertySet::new()?;
+    let reference = AgileReference::new(&set)?;
+    assert!(reference.resolve()? == set);
+
+    // Errors aren't `Send`, so just the size is sent back.
+    let size = std::thread::spawn(move || reference.resolve().and_then(|set| set.size()).ok())
+        .join()
+        .unwrap();
+
+    assert!(size == Some(0));
+
+    Ok(())
+}
@@ -53,3 +53,143 @@ async fn async_await() -> windows::Result<()> {
 fn test_async_await() -> windows::Result<()> {
     futures::executor::block_on(async_await())
 }
+
+#[test]
+fn async_info() -> windows::Result<()> {
+    use tests::windows::storage::streams::*;
+    use windows::foundation::AsyncStatus;
+
+    let stream = &InMemoryRandomAccessStream::new()?;
+
+    let writer = DataWriter::create_data_writer(stream)?;
+    writer.write_byte(1)?;
+    let operation = writer.store_async()?;
+    operation.get()?;
+
+    assert!(operation.status()? == AsyncStatus::Completed);
+    assert!(operation.error_code()? == windows::ErrorCode::S_OK);
+    assert!(operation.id()? != 0);
+
+    Ok(())
+}
+
+#[test]
+fn async_progress() -> windows::Result<()> {
+    use tests::windows::storage::streams::*;
+
+    let stream = &InMemoryRandomAccessStream::new()?;
+
+    let writer = DataWriter::new()?;
+    writer.write_bytes(&[1, 2, 3])?;
+    let operation = stream.write_async(writer.detach_buffer()?)?;
+
+    let mut progress = operation.progress_stream()?;
+    let mut reports = Vec::new();
+    futures::executor::block_on(async {
+        while let Some(report) = progress.next().await {
+            reports.push(report);
+        }
+    });
+
+    assert!(reports.iter().all(|report| *report <= 3));
+    assert!(operation.get()? == 3);
+
+    Ok(())
+}
+
+#[test]
+fn async_progress_stream() -> windows::Result<()> {
+    use futures::StreamExt;
+    use std::future::IntoFuture;
+    use tests::windows::storage::streams::*;
+
+    let stream = &InMemoryRandomAccessStream::new()?;
+
+    let writer = DataWriter::new()?;
+    writer.write_bytes(&[1, 2, 3])?;
+    let operation = stream.write_async(writer.detach_buffer()?)?;
+
+    let reports: Vec<u32> = futures::executor::block_on(operation.progress_stream()?.collect());
+
+    assert!(reports.iter().all(|report| *report <= 3));
+    assert!(futures::executor::block_on(operation.into_future())? == 3);
+
+    Ok(())
+}
+
+#[test]
+fn async_drop() -> windows::Result<()> {
+    use std::future::IntoFuture;
+    use tests::windows::storage::streams::*;
+    use windows::foundation::AsyncStatus;
+
+    let stream = &InMemoryRandomAccessStream::new()?;
+
+    let writer = DataWriter::create_data_writer(stream)?;
+    writer.write_byte(1)?;
+    let operation = writer.store_async()?;
+
+    // Dropping the future before the operation finishes cancels it.
+    drop(operation.clone().into_future());
+    assert!(operation.status()? != AsyncStatus::Started);
+
+    Ok(())
+}
+
+#[test]
+fn async_from_future() -> windows::Result<()> {
+    use futures::channel::oneshot;
+    use windows::foundation::{AsyncStatus, IAsyncAction, IAsyncOperation};
+    use windows::{Error, ErrorCode};
+
+    let operation = IAsyncOperation::<i32>::from_future(async { Ok(123) });
+    assert!(operation.status()? == AsyncStatus::Completed);
+    assert!(operation.get()? == 123);
+
+    // Polled again once the value is sent.
+    let (sender, receiver) = oneshot::channel();
+    let operation =
+        IAsyncOperation::<i32>::from_future(async move { Ok(receiver.await.unwrap_or(0)) });
+    assert!(operation.status()? == AsyncStatus::Started);
+    sender.send(456).unwrap();
+    assert!(operation.status()? == AsyncStatus::Completed);
+    assert!(operation.get()? == 456);
+
+    let action = IAsyncAction::from_future(async { Err(Error::fast_error(ErrorCode::E_BOUNDS)) });
+    assert!(action.status()? == AsyncStatus::Error);
+    assert!(action.error_code()? == ErrorCode::E_BOUNDS);
+    assert!(action.get().unwrap_err().code() == ErrorCode::E_BOUNDS);
+
+    // Canceling drops the future, which drops the receiver.
+    let (sender, receiver) = oneshot::channel::<()>();
+    let action = IAsyncAction::from_future(async move {
+        let _ = receiver.await;
+        Ok(())
+    });
+    action.cancel()?;
+    assert!(action.status()? == AsyncStatus::Canceled);
+    assert!(sender.is_canceled());
+    assert!(action.get().unwrap_err().code() == ErrorCode::ERROR_CANCELLED);
+
+    Ok(())
+}
+
+#[test]
+fn async_progress_dropped() -> windows::Result<()> {
+    use std::future::IntoFuture;
+    use tests::windows::storage::streams::*;
+
+    let stream = &InMemoryRandomAccessStream::new()?;
+
+    let writer = DataWriter::new()?;
+    writer.write_bytes(&[1, 2, 3])?;
+    let operation = stream.write_async(writer.detach_buffer()?)?;
+
+    // The stream shares the completion handler, so dropping it early leaves the operation to be
+    // waited on as usual.
+    drop(operation.progress_stream()?);
+    assert!(operation.get()? == 3);
+    assert!(futures::executor::block_on(operation.into_future())? == 3);
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use tests::windows::application_model::background::*;
+use tests::*;
+
+#[test]
+fn background_task() -> windows::Result<()> {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let task: IBackgroundTask = Task { sender }.into();
+
+    task.run(None)?;
+    assert!(receiver.recv().unwrap() == "run");
+
+    Ok(())
+}
+
+#[test]
+fn register_named() -> windows::Result<()> {
+    // Registration needs package identity, so whether it succeeds depends on how the tests are run.
+    // This only checks that the helper returns rather than panicking either way.
+    let trigger = TimeTrigger::create(15, false)?;
+    let _ = BackgroundTaskBuilder::register_named("RustTestTask", trigger);
+
+    Ok(())
+}
+
+#[::windows::implement(windows::application_model::background::IBackgroundTask)]
+struct Task {
+    sender: std::sync::mpsc::Sender<&'static str>,
+}
+
+impl Task {
+    fn run(&self, instance: &Option<IBackgroundTaskInstance>) -> windows::Result<()> {
+        self.sender.send("run").unwrap();
+
+        // The deferral keeps the task alive until asynchronous work is done.
+        if let Some(instance) = instance {
+            let deferral = instance.get_deferral()?;
+            deferral.complete()?;
+        }
+
+        Ok(())
+    }
+}
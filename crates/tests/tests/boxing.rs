@@ -89,7 +89,7 @@ fn explicit_boxing() -> windows::Result<()> {
 
     let object = PropertyValue::create_uint32_array(&[1, 2, 3])?;
     let pv: IPropertyValue = object.cast()?;
-    let mut array = windows::Array::new();
+    let mut array = Vec::new();
     assert!(array.is_empty());
     assert!(array.len() == 0);
 
@@ -101,7 +101,7 @@ fn explicit_boxing() -> windows::Result<()> {
     let object =
         PropertyValue::create_string_array(&["Hello".into(), "Rust".into(), "WinRT".into()])?;
     let pv: IPropertyValue = object.cast()?;
-    let mut array = windows::Array::new();
+    let mut array = Vec::new();
     assert!(array.is_empty());
     assert!(array.len() == 0);
 
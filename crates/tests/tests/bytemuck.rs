@@ -0,0 +1,48 @@
+use tests::{
+    windows::foundation::{AsyncStatus, Point},
+    windows::win32::display_devices::RECT,
+};
+use windows::{Guid, BOOL};
+
+#[test]
+fn structs() {
+    let rects = [
+        RECT {
+            left: 1,
+            top: 2,
+            right: 3,
+            bottom: 4,
+        },
+        RECT::default(),
+    ];
+
+    let bytes: &[u8] = bytemuck::cast_slice(&rects);
+    assert!(bytes.len() == 32);
+    assert!(bytes[..4] == 1i32.to_ne_bytes());
+
+    let values: &[i32] = bytemuck::cast_slice(&rects);
+    assert!(values == [1, 2, 3, 4, 0, 0, 0, 0]);
+
+    let point: Point = bytemuck::cast([1.5f32, 2.0f32]);
+    assert!(point == Point { x: 1.5, y: 2.0 });
+
+    let zeroed: RECT = bytemuck::Zeroable::zeroed();
+    assert!(zeroed == RECT::default());
+}
+
+#[test]
+fn enums() {
+    let status: AsyncStatus = bytemuck::cast(3i32);
+    assert!(status == AsyncStatus::Error);
+}
+
+#[test]
+fn primitives() {
+    let guid = Guid::from("AF86E2E0-B12D-4C6A-9C5A-D7AA65101E90");
+    let bytes = bytemuck::bytes_of(&guid);
+    assert!(bytes.len() == 16);
+    assert!(*bytemuck::from_bytes::<Guid>(bytes) == guid);
+
+    let value: BOOL = bytemuck::cast(1i32);
+    assert!(value.as_bool());
+}
@@ -1,5 +1,5 @@
 use windows::foundation::{IClosable, IStringable, Uri};
-use windows::{Interface, Result};
+use windows::Result;
 
 #[test]
 fn try_into() -> Result<()> {
@@ -24,3 +24,21 @@ fn try_into() -> Result<()> {
 
     Ok(())
 }
+
+// Interfaces and classes may also be cast without importing the `Interface` trait.
+mod inherent {
+    use windows::foundation::{IStringable, Uri};
+    use windows::Result;
+
+    #[test]
+    fn cast() -> Result<()> {
+        let uri = Uri::create_uri("http://kennykerr.ca")?;
+        let s: IStringable = uri.cast()?;
+        assert!(s.to_string()? == "http://kennykerr.ca/");
+
+        let uri: Uri = s.cast()?;
+        assert!(uri.domain()? == "kennykerr.ca");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use chrono::{Duration, TimeZone, Utc};
+use tests::test_component::TestRunner;
+use windows::foundation::{DateTime, IPropertyValue, PropertyValue, TimeSpan};
+use windows::Interface;
+
+#[test]
+fn time_span() -> windows::Result<()> {
+    let a: TimeSpan = Duration::milliseconds(1234).into();
+    let b = TestRunner::create_time_span(1234)?;
+    assert_eq!(a, b);
+
+    let c: Duration = b.into();
+    assert_eq!(c.num_milliseconds(), 1234);
+
+    let d: Duration = TimeSpan { duration: -15 }.into();
+    assert_eq!(d, Duration::nanoseconds(-1500));
+    assert_eq!(TimeSpan::from(d), TimeSpan { duration: -15 });
+
+    // Durations beyond the range of a `TimeSpan` saturate.
+    assert_eq!(
+        TimeSpan::from(Duration::days(100_000_000)).duration,
+        i64::MAX
+    );
+    assert_eq!(
+        TimeSpan::from(Duration::days(-100_000_000)).duration,
+        i64::MIN
+    );
+
+    Ok(())
+}
+
+#[test]
+fn date_time() {
+    let unix = Utc.timestamp_opt(0, 0).unwrap();
+    let a: DateTime = unix.into();
+    assert_eq!(a.universal_time, 116_444_736_000_000_000);
+
+    let b: chrono::DateTime<Utc> = a.into();
+    assert_eq!(b, unix);
+
+    // Times before the Unix epoch round trip as well.
+    let early = DateTime {
+        universal_time: 1_234_567,
+    };
+    let c: chrono::DateTime<Utc> = early.clone().into();
+    assert_eq!(c, Utc.timestamp_opt(-11_644_473_600, 123_456_700).unwrap());
+    assert_eq!(DateTime::from(c), early);
+}
+
+#[test]
+fn date_time_param() -> windows::Result<()> {
+    let now = Utc.timestamp_opt(1_600_000_000, 0).unwrap();
+    let object = PropertyValue::create_date_time(now)?;
+    let pv: IPropertyValue = object.cast()?;
+    assert!(chrono::DateTime::<Utc>::from(pv.get_date_time()?) == now);
+
+    Ok(())
+}
@@ -2,6 +2,7 @@ use std::convert::*;
 use std::iter::FromIterator;
 use windows::foundation::collections::{IIterable, IVectorView, PropertySet};
 use windows::foundation::{IWwwFormUrlDecoderEntry, Uri};
+use windows::HString;
 
 #[test]
 fn uri() -> windows::Result<()> {
@@ -87,3 +88,31 @@ fn property_set() -> windows::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn stock_iterable() -> windows::Result<()> {
+    use tests::windows::ai::machine_learning::TensorBoolean;
+
+    let iterable = IIterable::<i32>::from_iter(vec![1, 2, 3]);
+    let values: Vec<i32> = iterable.into_iter().collect();
+    assert!(values == [1, 2, 3]);
+
+    let iterable: IIterable<HString> = ["A", "B"].iter().map(|s| HString::from(*s)).collect();
+    let iterator = iterable.first()?;
+    assert!(iterator.current()? == "A");
+    assert!(iterator.move_next()?);
+    assert!(iterator.current()? == "B");
+    assert!(!iterator.move_next()?);
+    assert!(!iterator.has_current()?);
+
+    // A Vec may be passed directly where an IIterable is expected.
+    let tensor = TensorBoolean::create2(vec![2, 3])?;
+    let shape: Vec<i64> = tensor.shape()?.into_iter().collect();
+    assert!(shape == [2, 3]);
+
+    let tensor = TensorBoolean::create2(&[4_i64, 5][..])?;
+    let shape: Vec<i64> = tensor.shape()?.into_iter().collect();
+    assert!(shape == [4, 5]);
+
+    Ok(())
+}
@@ -18,3 +18,19 @@ fn test_mta() -> Result<()> {
     let _ = unsafe { updates.Pause() };
     Ok(())
 }
+
+#[test]
+fn test_iid() {
+    let iid = Guid::from("673425BF-C082-4C7C-BDFD-569464B8E0CE");
+    assert!(IAutomaticUpdates::IID == iid);
+    assert!(windows::uuidof::<IAutomaticUpdates>() == iid);
+}
+
+#[test]
+fn test_winrt_iid() {
+    use windows::foundation::{AsyncActionCompletedHandler, IReference, IStringable};
+
+    assert!(IStringable::IID == Guid::from("96369F54-8EB6-48F0-ABCE-C1B211E627C3"));
+    assert!(AsyncActionCompletedHandler::IID == Guid::from("A4ED5C81-76C9-40BD-8BE6-B1D90FB20AE7"));
+    assert!(IReference::<i32>::IID == windows::uuidof::<IReference<i32>>());
+}
@@ -0,0 +1,26 @@
+use std::io::{Read, Write};
+use tests::windows::storage::streams::{DataReader, DataWriter};
+
+#[test]
+fn read_write() -> windows::Result<()> {
+    let mut writer = DataWriter::new()?;
+    writer.write_all(b"hello ").unwrap();
+    write!(writer, "{}", 123).unwrap();
+    writer.flush().unwrap();
+    assert!(writer.unstored_buffer_length()? == 9);
+
+    let mut reader = DataReader::from_buffer(writer.detach_buffer()?)?;
+
+    let mut hello = [0; 6];
+    reader.read_exact(&mut hello).unwrap();
+    assert!(&hello == b"hello ");
+
+    let mut rest = String::new();
+    reader.read_to_string(&mut rest).unwrap();
+    assert!(rest == "123");
+
+    // Nothing is left to read.
+    assert!(reader.read(&mut hello).unwrap() == 0);
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use std::convert::TryFrom;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use windows::foundation::{DateTime, IPropertyValue, PropertyValue};
+use windows::Interface;
+
+#[test]
+fn conversion() -> windows::Result<()> {
+    let a: DateTime = UNIX_EPOCH.into();
+    assert_eq!(a.universal_time, 116_444_736_000_000_000);
+    assert_eq!(SystemTime::try_from(a)?, UNIX_EPOCH);
+
+    let later = UNIX_EPOCH + Duration::new(1_600_000_000, 123_456_700);
+    let b: DateTime = later.into();
+    assert_eq!(b.universal_time, 132_444_736_001_234_567);
+    assert_eq!(SystemTime::try_from(b)?, later);
+
+    // Times before the Unix epoch round trip as well.
+    let earlier = UNIX_EPOCH - Duration::new(86_400, 100);
+    let c: DateTime = earlier.into();
+    assert_eq!(c.universal_time, 116_443_872_000_000_000 - 1);
+    assert_eq!(SystemTime::try_from(c)?, earlier);
+
+    Ok(())
+}
+
+#[test]
+fn system_time_param() -> windows::Result<()> {
+    let now = UNIX_EPOCH + Duration::from_secs(1_600_000_000);
+    let object = PropertyValue::create_date_time(now)?;
+    let pv: IPropertyValue = object.cast()?;
+    assert!(SystemTime::try_from(pv.get_date_time()?)? == now);
+
+    Ok(())
+}
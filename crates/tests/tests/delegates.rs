@@ -3,7 +3,7 @@ use windows::foundation::collections::{
     CollectionChange, IObservableMap, MapChangedEventHandler, PropertySet,
 };
 use windows::foundation::{AsyncActionCompletedHandler, AsyncStatus, TypedEventHandler, Uri};
-use windows::{Abi, Interface};
+use windows::Abi;
 
 #[test]
 fn non_generic() -> windows::Result<()> {
@@ -89,3 +89,13 @@ fn event() -> windows::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn panic() {
+    let d = AsyncActionCompletedHandler::new(|_, _| panic!("delegate panicked"));
+
+    // The panic is returned as an error rather than unwinding across the ABI.
+    let error = d.invoke(None, AsyncStatus::Completed).unwrap_err();
+    assert!(error.code() == windows::ErrorCode(0x8000_4005));
+    assert!(error.message() == "delegate panicked");
+}
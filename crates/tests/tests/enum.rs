@@ -1,4 +1,6 @@
+use std::convert::TryFrom;
 use tests::windows::application_model::appointments::AppointmentDaysOfWeek;
+use tests::windows::win32::security::{ACCESS_MODEEnum, ACCESS_MODE};
 use windows::foundation::AsyncStatus;
 
 #[test]
@@ -43,3 +45,61 @@ fn unsigned_enum() {
     assert!(weekend == 0x41.into());
     assert!(weekend.0 == 0x41);
 }
+
+#[test]
+fn flags_enum() {
+    let mut days = AppointmentDaysOfWeek::Monday | AppointmentDaysOfWeek::Tuesday;
+    assert!(days.contains(AppointmentDaysOfWeek::Monday));
+    assert!(!days.contains(AppointmentDaysOfWeek::Monday | AppointmentDaysOfWeek::Friday));
+    assert!(days.contains(AppointmentDaysOfWeek::None));
+
+    days |= AppointmentDaysOfWeek::Friday;
+    assert!(days.0 == 0x26);
+
+    days &= !AppointmentDaysOfWeek::Monday;
+    assert!(days == AppointmentDaysOfWeek::Tuesday | AppointmentDaysOfWeek::Friday);
+
+    days ^= AppointmentDaysOfWeek::Tuesday;
+    assert!(days == AppointmentDaysOfWeek::Friday);
+    assert!(days ^ AppointmentDaysOfWeek::Friday == AppointmentDaysOfWeek::None);
+    assert!(days & AppointmentDaysOfWeek::Saturday == AppointmentDaysOfWeek::None);
+}
+
+#[test]
+fn defined_values() {
+    assert!(AsyncStatus::Error.is_defined());
+    assert!(!AsyncStatus::from(4).is_defined());
+    assert!(i32::from(AsyncStatus::Canceled) == 2);
+
+    let days = AppointmentDaysOfWeek::Sunday | AppointmentDaysOfWeek::Saturday;
+    assert!(days.is_defined());
+    assert!(!AppointmentDaysOfWeek::from(0x80).is_defined());
+    assert!(u32::from(days) == 0x41);
+}
+
+#[test]
+fn rust_enum() {
+    let mode = ACCESS_MODEEnum::try_from(ACCESS_MODE::DENY_ACCESS).unwrap();
+    assert!(mode == ACCESS_MODEEnum::DENY_ACCESS);
+    assert!(ACCESS_MODE::from(mode) == ACCESS_MODE::DENY_ACCESS);
+
+    let name = match mode {
+        ACCESS_MODEEnum::NOT_USED_ACCESS => "not used",
+        ACCESS_MODEEnum::GRANT_ACCESS => "grant",
+        ACCESS_MODEEnum::SET_ACCESS => "set",
+        ACCESS_MODEEnum::DENY_ACCESS => "deny",
+        ACCESS_MODEEnum::REVOKE_ACCESS => "revoke",
+        ACCESS_MODEEnum::SET_AUDIT_SUCCESS => "audit success",
+        ACCESS_MODEEnum::SET_AUDIT_FAILURE => "audit failure",
+        // Only needed outside the crate the bindings are generated in.
+        _ => "unknown",
+    };
+    assert!(name == "deny");
+
+    // Values the metadata doesn't declare are returned as the error.
+    assert!(ACCESS_MODEEnum::try_from(ACCESS_MODE(100)) == Err(ACCESS_MODE(100)));
+
+    // Declared values are named in the debug output.
+    assert!(format!("{:?}", ACCESS_MODE::DENY_ACCESS) == "DENY_ACCESS");
+    assert!(format!("{:?}", ACCESS_MODE(100)) == "100");
+}
@@ -0,0 +1,49 @@
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::Arc;
+use windows::foundation::{AsyncActionCompletedHandler, AsyncStatus};
+use windows::EventSource;
+
+#[test]
+fn event_source() -> windows::Result<()> {
+    let event = EventSource::<AsyncActionCompletedHandler>::new();
+    assert!(event.is_empty());
+
+    let count = Arc::new(AtomicI32::new(0));
+
+    let first = count.clone();
+    let first = event.add(&AsyncActionCompletedHandler::new(move |_, status| {
+        assert!(status == AsyncStatus::Completed);
+        first.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }));
+
+    let second = count.clone();
+    let second = event.add(&AsyncActionCompletedHandler::new(move |_, _| {
+        second.fetch_add(10, Ordering::Relaxed);
+        Ok(())
+    }));
+
+    assert!(first.value != second.value);
+
+    event.call(|delegate| delegate.invoke(None, AsyncStatus::Completed));
+    assert_eq!(count.load(Ordering::Relaxed), 11);
+
+    event.remove(second);
+    event.call(|delegate| delegate.invoke(None, AsyncStatus::Completed));
+    assert_eq!(count.load(Ordering::Relaxed), 12);
+
+    event.remove(first);
+    assert!(event.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn event_source_disconnected() {
+    let event = EventSource::<AsyncActionCompletedHandler>::new();
+    event.add(&AsyncActionCompletedHandler::new(|_, _| Ok(())));
+
+    // Handlers that fail because they are disconnected are removed.
+    event.call(|_| Err(windows::ErrorCode(0x8001_0108).into()));
+    assert!(event.is_empty());
+}
@@ -172,7 +172,7 @@ fn arrays() -> windows::Result<()> {
     {
         let a: [bool; 3] = [true, false, true];
         let mut b = [false; 3];
-        let mut c = windows::Array::new();
+        let mut c = Vec::new();
         let d = tests.array1(&a, &mut b, &mut c)?;
         assert!(a == b);
         assert!(a == c[..]);
@@ -182,7 +182,7 @@ fn arrays() -> windows::Result<()> {
     {
         let a: [u8; 3] = [1, 2, 3];
         let mut b = [0; 3];
-        let mut c = windows::Array::new();
+        let mut c = Vec::new();
         let d = tests.array2(&a, &mut b, &mut c)?;
         assert!(a == b);
         assert!(a == c[..]);
@@ -192,7 +192,7 @@ fn arrays() -> windows::Result<()> {
     {
         let a: [u16; 3] = [1, 2, 3];
         let mut b = [0; 3];
-        let mut c = windows::Array::new();
+        let mut c = Vec::new();
         let d = tests.array3(&a, &mut b, &mut c)?;
         assert!(a == b);
         assert!(a == c[..]);
@@ -202,7 +202,7 @@ fn arrays() -> windows::Result<()> {
     {
         let a: [u32; 3] = [1, 2, 3];
         let mut b = [0; 3];
-        let mut c = windows::Array::new();
+        let mut c = Vec::new();
         let d = tests.array4(&a, &mut b, &mut c)?;
         assert!(a == b);
         assert!(a == c[..]);
@@ -212,7 +212,7 @@ fn arrays() -> windows::Result<()> {
     {
         let a: [u64; 3] = [1, 2, 3];
         let mut b = [0; 3];
-        let mut c = windows::Array::new();
+        let mut c = Vec::new();
         let d = tests.array5(&a, &mut b, &mut c)?;
         assert!(a == b);
         assert!(a == c[..]);
@@ -222,7 +222,7 @@ fn arrays() -> windows::Result<()> {
     {
         let a: [i16; 3] = [1, 2, 3];
         let mut b = [0; 3];
-        let mut c = windows::Array::new();
+        let mut c = Vec::new();
         let d = tests.array6(&a, &mut b, &mut c)?;
         assert!(a == b);
         assert!(a == c[..]);
@@ -232,7 +232,7 @@ fn arrays() -> windows::Result<()> {
     {
         let a: [i32; 3] = [1, 2, 3];
         let mut b = [0; 3];
-        let mut c = windows::Array::new();
+        let mut c = Vec::new();
         let d = tests.array7(&a, &mut b, &mut c)?;
         assert!(a == b);
         assert!(a == c[..]);
@@ -242,7 +242,7 @@ fn arrays() -> windows::Result<()> {
     {
         let a: [i64; 3] = [1, 2, 3];
         let mut b = [0; 3];
-        let mut c = windows::Array::new();
+        let mut c = Vec::new();
         let d = tests.array8(&a, &mut b, &mut c)?;
         assert!(a == b);
         assert!(a == c[..]);
@@ -252,7 +252,7 @@ fn arrays() -> windows::Result<()> {
     {
         let a: [f32; 3] = [1.0, 2.0, 3.0];
         let mut b = [0.0; 3];
-        let mut c = windows::Array::new();
+        let mut c = Vec::new();
         let d = tests.array9(&a, &mut b, &mut c)?;
         assert!(a == b);
         assert!(a == c[..]);
@@ -262,7 +262,7 @@ fn arrays() -> windows::Result<()> {
     {
         let a: [f64; 3] = [1.0, 2.0, 3.0];
         let mut b = [0.0; 3];
-        let mut c = windows::Array::new();
+        let mut c = Vec::new();
         let d = tests.array10(&a, &mut b, &mut c)?;
         assert!(a == b);
         assert!(a == c[..]);
@@ -272,7 +272,7 @@ fn arrays() -> windows::Result<()> {
     {
         let a: [u16; 3] = [0x61, 0x62, 0x63]; // WinRT char e.g. L'a' , L'b', L'c'
         let mut b = [0; 3];
-        let mut c = windows::Array::new();
+        let mut c = Vec::new();
         let d = tests.array11(&a, &mut b, &mut c)?;
         assert!(a == b);
         assert!(a == c[..]);
@@ -286,7 +286,7 @@ fn arrays() -> windows::Result<()> {
             windows::HString::new(),
             windows::HString::new(),
         ];
-        let mut c = windows::Array::new();
+        let mut c = Vec::new();
         let d = tests.array12(&a, &mut b, &mut c)?;
         assert!(a == b);
         assert!(a == c[..]);
@@ -339,7 +339,7 @@ fn arrays() -> windows::Result<()> {
             Blittable::default(),
         ];
 
-        let mut c = windows::Array::new();
+        let mut c = Vec::new();
         let d = tests.array13(&a, &mut b, &mut c)?;
         assert!(a == b);
         assert!(a == c[..]);
@@ -383,7 +383,7 @@ fn arrays() -> windows::Result<()> {
             NonBlittable::default(),
         ];
 
-        let mut c = windows::Array::new();
+        let mut c = Vec::new();
         let d = tests.array14(&a, &mut b, &mut c)?;
         assert!(a == b);
         assert!(a == c[..]);
@@ -465,7 +465,7 @@ fn arrays() -> windows::Result<()> {
 
         let mut b = [Nested::default(), Nested::default(), Nested::default()];
 
-        let mut c = windows::Array::new();
+        let mut c = Vec::new();
         let d = tests.array15(&a, &mut b, &mut c)?;
         assert!(a == b);
         assert!(a == c[..]);
@@ -481,7 +481,7 @@ fn arrays() -> windows::Result<()> {
 
         let mut b = [None, None, None];
 
-        let mut c = windows::Array::new();
+        let mut c = Vec::new();
         let d = tests.array16(&a, &mut b, &mut c)?;
         assert!(a == b);
         assert!(a == c[..]);
@@ -1,7 +1,5 @@
 #[test]
 fn generic_guids() -> windows::Result<()> {
-    use windows::Interface;
-
     type A = windows::foundation::collections::IIterable<windows::foundation::IStringable>;
     type B = windows::foundation::collections::IKeyValuePair<
         windows::HString,
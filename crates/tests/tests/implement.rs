@@ -2,8 +2,6 @@
 //     pub use windows::foundation;
 // }
 
-use windows::Interface; // for .cast()
-
 #[test]
 fn implement() -> windows::Result<()> {
     let (sender, receiver) = std::sync::mpsc::channel();
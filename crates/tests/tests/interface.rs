@@ -1,5 +1,5 @@
 use windows::foundation::{IStringable, IUriRuntimeClass, Uri};
-use windows::{Abi, Interface};
+use windows::Abi;
 
 #[test]
 fn interface() -> windows::Result<()> {
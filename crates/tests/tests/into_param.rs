@@ -0,0 +1,17 @@
+use windows::foundation::Uri;
+
+#[test]
+fn strings() -> windows::Result<()> {
+    let text = String::from("http://kennykerr.ca");
+
+    let uri = Uri::create_uri(&text)?; // &String
+    assert!(uri.domain()? == "kennykerr.ca");
+
+    let uri = Uri::create_uri(text.as_str())?; // &str
+    assert!(uri.domain()? == "kennykerr.ca");
+
+    let uri = Uri::create_uri(text)?; // String
+    assert!(uri.domain()? == "kennykerr.ca");
+
+    Ok(())
+}
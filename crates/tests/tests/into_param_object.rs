@@ -1,5 +1,6 @@
-use windows::foundation::{PropertyValue, Uri};
+use windows::foundation::{IStringable, PropertyValue, Uri};
 use windows::Interface;
+use windows::Object;
 
 #[test]
 fn into() -> windows::Result<()> {
@@ -17,3 +18,47 @@ fn into() -> windows::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn unknown() -> windows::Result<()> {
+    let uri = Uri::create_uri("http://kennykerr.ca")?;
+
+    // Classes, interfaces and objects all convert to IUnknown.
+    let unknown: windows::IUnknown = (&uri).into();
+    assert!(unknown.cast::<Uri>()?.domain()? == "kennykerr.ca");
+
+    let stringable: IStringable = uri.into();
+    let unknown: windows::IUnknown = stringable.into();
+    let object: Object = unknown.cast()?;
+    let unknown: windows::IUnknown = object.into();
+    assert!(unknown.cast::<Uri>()?.domain()? == "kennykerr.ca");
+
+    Ok(())
+}
+
+#[test]
+fn borrowed() -> windows::Result<()> {
+    let uri = Uri::create_uri("http://kennykerr.ca")?;
+
+    // References to classes and interfaces are borrowed rather than copied whenever no
+    // conversion is required.
+    assert!(matches!(
+        Into::<windows::Param<Object>>::into(&uri),
+        windows::Param::Borrowed(_)
+    ));
+    assert!(matches!(
+        Into::<windows::Param<windows::IUnknown>>::into(&uri),
+        windows::Param::Borrowed(_)
+    ));
+
+    let stringable: IStringable = uri.cast()?;
+    assert!(matches!(
+        Into::<windows::Param<Object>>::into(&stringable),
+        windows::Param::Borrowed(_)
+    ));
+
+    let object = PropertyValue::create_inspectable(&stringable)?;
+    assert!(object.cast::<Uri>()?.domain()? == "kennykerr.ca");
+
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use tests::windows::application_model::contacts::ContactDate;
+
+#[test]
+fn into() -> windows::Result<()> {
+    let date = ContactDate::new()?;
+
+    date.set_day(25)?; // value
+    assert!(date.day()? == Some(25));
+
+    date.set_month(Some(12))?; // optional value
+    assert!(date.month()? == Some(12));
+
+    date.set_day(None::<u32>)?; // null
+    assert!(date.day()? == None);
+
+    Ok(())
+}
@@ -10,3 +10,14 @@ fn namespace() -> windows::Result<()> {
 
     Ok(())
 }
+
+// This test ensures that a namespace renamed with `as` in `build!` is reachable through its alias.
+#[test]
+fn namespace_alias() -> windows::Result<()> {
+    use tests::ml::{TensorBoolean, TensorKind};
+
+    let tensor = TensorBoolean::create()?;
+    assert!(tensor.tensor_kind()? == TensorKind::Boolean);
+
+    Ok(())
+}
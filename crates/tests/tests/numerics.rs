@@ -593,3 +593,94 @@ fn matrix4x4_mul() {
 
     test_with_scalar!(value1, value2, *, expected);
 }
+
+#[test]
+fn constructors() {
+    assert_eq!(Vector2::new(1.0, 2.0), Vector2 { x: 1.0, y: 2.0 });
+    assert_eq!(
+        Vector3::new(1.0, 2.0, 3.0),
+        Vector3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        }
+    );
+    assert_eq!(
+        Vector4::new(1.0, 2.0, 3.0, 4.0),
+        Vector4 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+            w: 4.0,
+        }
+    );
+
+    let translation = Matrix4x4::translation(1.0, 2.0, 3.0);
+    assert_eq!(translation * Matrix4x4::identity(), translation);
+    assert_eq!(translation.m43, 3.0);
+    assert_eq!(translation.m44, 1.0);
+}
+
+#[test]
+fn quaternion_add() {
+    let value1 = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    let value2 = Quaternion::new(5.0, 6.0, 7.0, 8.0);
+    let expected = Quaternion::new(6.0, 8.0, 10.0, 12.0);
+
+    test_with_same_type!(value1, value2, +, expected);
+}
+
+#[test]
+fn quaternion_sub() {
+    let value1 = Quaternion::new(1.0, 6.0, 7.0, 4.0);
+    let value2 = Quaternion::new(5.0, 2.0, 3.0, 8.0);
+    let expected = Quaternion::new(-4.0, 4.0, 4.0, -4.0);
+
+    test_with_same_type!(value1, value2, -, expected);
+}
+
+#[test]
+fn quaternion_mul() {
+    let value1 = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    let value2 = Quaternion::new(5.0, 6.0, 7.0, 8.0);
+    let expected = Quaternion::new(24.0, 48.0, 48.0, -6.0);
+
+    test_with_same_type!(value1, value2, *, expected);
+
+    let value2 = 2.0;
+    let expected = Quaternion::new(2.0, 4.0, 6.0, 8.0);
+
+    test_with_scalar!(value1, value2, *, expected);
+
+    let identity = Quaternion::identity();
+    assert_eq!(&value1 * &identity, value1);
+    assert_eq!(identity.length(), 1.0);
+}
+
+#[test]
+fn mint() {
+    let vector: mint::Vector3<f32> = Vector3::new(1.0, 2.0, 3.0).into();
+    assert_eq!(vector, mint::Vector3::from([1.0, 2.0, 3.0]));
+    assert_eq!(Vector3::from(vector), Vector3::new(1.0, 2.0, 3.0));
+
+    let quaternion: mint::Quaternion<f32> = Quaternion::new(1.0, 2.0, 3.0, 4.0).into();
+    assert_eq!(quaternion.s, 4.0);
+    assert_eq!(
+        Quaternion::from(quaternion),
+        Quaternion::new(1.0, 2.0, 3.0, 4.0)
+    );
+
+    let translation: mint::RowMatrix4<f32> = Matrix4x4::translation(1.0, 2.0, 3.0).into();
+    assert_eq!(translation.w, mint::Vector4::from([1.0, 2.0, 3.0, 1.0]));
+    assert_eq!(
+        Matrix4x4::from(translation),
+        Matrix4x4::translation(1.0, 2.0, 3.0)
+    );
+
+    let translation: mint::RowMatrix3x2<f32> = Matrix3x2::translation(1.0, 2.0).into();
+    assert_eq!(translation.z, mint::Vector2::from([1.0, 2.0]));
+    assert_eq!(
+        Matrix3x2::from(translation),
+        Matrix3x2::translation(1.0, 2.0)
+    );
+}
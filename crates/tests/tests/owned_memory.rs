@@ -0,0 +1,19 @@
+use tests::windows::win32::com::StringFromIID;
+use windows::{CoTaskMem, Interface, Object};
+
+#[test]
+fn co_task_mem() -> windows::Result<()> {
+    let mut string = CoTaskMem::<u16>::new();
+    assert!(string.is_null());
+
+    unsafe {
+        StringFromIID(&Object::IID, string.set_abi()).ok()?;
+        assert!(!string.is_null());
+
+        // The IID is formatted with braces, without the trailing null.
+        let wide = string.as_slice(38);
+        assert!(String::from_utf16_lossy(wide) == "{AF86E2E0-B12D-4C6A-9C5A-D7AA65101E90}");
+    }
+
+    Ok(())
+}
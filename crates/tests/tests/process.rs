@@ -0,0 +1,39 @@
+use windows::ProcessBuilder;
+
+#[test]
+fn exit_code() -> windows::Result<()> {
+    let process = ProcessBuilder::new("cmd.exe")
+        .args(&["/c", "exit", "3"])
+        .spawn()?;
+    assert!(process.id() != 0);
+    assert!(process.wait()? == 3);
+    assert!(process.exit_code()? == Some(3));
+
+    Ok(())
+}
+
+#[test]
+fn suspended() -> windows::Result<()> {
+    let process = ProcessBuilder::new("cmd.exe")
+        .args(&["/c", "exit", "4"])
+        .suspended(true)
+        .spawn()?;
+
+    assert!(process.exit_code()? == None);
+    process.resume()?;
+    assert!(process.wait()? == 4);
+
+    Ok(())
+}
+
+#[test]
+fn environment() -> windows::Result<()> {
+    let process = ProcessBuilder::new("cmd.exe")
+        .args(&["/c", "exit", "%WINDOWS_RS_TEST%"])
+        .env("WINDOWS_RS_TEST", "5")
+        .spawn()?;
+
+    assert!(process.wait()? == 5);
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use tests::provenance::*;
+
+#[test]
+fn provenance() {
+    assert_eq!(GENERATOR_VERSION, windows::provenance::GENERATOR_VERSION);
+
+    let (_, size, fingerprint) = METADATA
+        .iter()
+        .find(|(name, _, _)| *name == "TestComponent.winmd")
+        .unwrap();
+
+    assert!(*size > 0);
+    assert_eq!(fingerprint.len(), 16);
+}
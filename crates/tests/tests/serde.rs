@@ -0,0 +1,44 @@
+use tests::{
+    windows::foundation::{AsyncStatus, Point},
+    windows::win32::display_devices::RECT,
+};
+use windows::{ErrorCode, Guid, BOOL};
+
+#[test]
+fn structs() {
+    let rect = RECT {
+        left: 1,
+        top: 2,
+        right: 3,
+        bottom: 4,
+    };
+
+    let json = serde_json::to_string(&rect).unwrap();
+    assert!(json == r#"{"left":1,"top":2,"right":3,"bottom":4}"#);
+    assert!(serde_json::from_str::<RECT>(&json).unwrap() == rect);
+
+    let point: Point = serde_json::from_str(r#"{"x":1.5,"y":2.0}"#).unwrap();
+    assert!(point == Point { x: 1.5, y: 2.0 });
+}
+
+#[test]
+fn enums() {
+    let json = serde_json::to_string(&AsyncStatus::Error).unwrap();
+    assert!(json == "3");
+    assert!(serde_json::from_str::<AsyncStatus>(&json).unwrap() == AsyncStatus::Error);
+}
+
+#[test]
+fn primitives() {
+    let guid = Guid::from("AF86E2E0-B12D-4C6A-9C5A-D7AA65101E90");
+    let json = serde_json::to_string(&guid).unwrap();
+    assert!(json == r#""AF86E2E0-B12D-4C6A-9C5A-D7AA65101E90""#);
+    assert!(serde_json::from_str::<Guid>(&json).unwrap() == guid);
+    assert!(serde_json::from_str::<Guid>(r#""not a guid""#).is_err());
+
+    assert!(serde_json::to_string(&ErrorCode::E_NOINTERFACE).unwrap() == "2147500034");
+    assert!(serde_json::from_str::<ErrorCode>("0").unwrap() == ErrorCode::S_OK);
+
+    assert!(serde_json::to_string(&BOOL(2)).unwrap() == "true");
+    assert!(serde_json::from_str::<BOOL>("false").unwrap() == BOOL(0));
+}
@@ -1,3 +1,4 @@
+use std::convert::TryFrom;
 use std::time::Duration;
 use tests::test_component::TestRunner;
 use windows::foundation::{IPropertyValue, PropertyValue, TimeSpan};
@@ -9,9 +10,13 @@ fn conversion() -> windows::Result<()> {
     let b = TestRunner::create_time_span(1234)?;
     assert_eq!(a, b);
 
-    let c: Duration = b.into();
+    let c = Duration::try_from(b)?;
     assert_eq!(c.as_millis(), 1234);
 
+    // A negative span has no equivalent `Duration`.
+    let d = Duration::try_from(TimeSpan { duration: -15 });
+    assert_eq!(d.unwrap_err().code(), windows::ErrorCode::E_BOUNDS);
+
     Ok(())
 }
 
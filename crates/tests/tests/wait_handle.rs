@@ -0,0 +1,39 @@
+use tests::windows::win32::system_services::{CreateEventW, SetEvent, HANDLE};
+use tests::windows::win32::windows_programming::CloseHandle;
+use windows::WaitHandle;
+
+fn create_event() -> HANDLE {
+    unsafe {
+        let event = CreateEventW(None, true.into(), false.into(), std::ptr::null_mut());
+        assert!(event.0 != 0);
+        event
+    }
+}
+
+#[test]
+fn wait_handle() -> windows::Result<()> {
+    let event = create_event();
+    let wait = unsafe { WaitHandle::new(event.0 as _)? };
+
+    let value = event.0;
+    std::thread::spawn(move || unsafe {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        SetEvent(HANDLE(value));
+    });
+
+    futures::executor::block_on(wait);
+
+    // A handle that's already signaled completes right away.
+    futures::executor::block_on(unsafe { WaitHandle::new(event.0 as _)? });
+
+    // Dropping the future before the handle is signaled cancels the wait.
+    let other = create_event();
+    drop(unsafe { WaitHandle::new(other.0 as _)? });
+
+    unsafe {
+        CloseHandle(other);
+        CloseHandle(event);
+    }
+
+    Ok(())
+}
@@ -1,34 +1,41 @@
 use tests::{
+    windows::win32::background_intelligent_transfer_service::BITS_JOB_PROPERTY_VALUE,
     windows::win32::com::CreateUri,
     windows::win32::debug::{MiniDumpWriteDump, MINIDUMP_TYPE},
     windows::win32::direct3d11::D3DDisassemble11Trace,
     windows::win32::direct3d12::D3D12_DEFAULT_BLEND_FACTOR_ALPHA,
     windows::win32::direct3d_hlsl::D3DCOMPILER_DLL,
-    windows::win32::display_devices::RECT,
+    windows::win32::display_devices::{CIECHROMA, RECT},
     windows::win32::dxgi::{
-        CreateDXGIFactory1, IDXGIFactory7, DXGI_ADAPTER_FLAG, DXGI_FORMAT, DXGI_MODE_DESC,
-        DXGI_MODE_SCALING, DXGI_MODE_SCANLINE_ORDER, DXGI_RATIONAL,
+        CreateDXGIFactory1, IDXGIFactory, IDXGIFactory7, DXGI_ADAPTER_DESC1, DXGI_ADAPTER_FLAG,
+        DXGI_FORMAT, DXGI_MODE_DESC, DXGI_MODE_SCALING, DXGI_MODE_SCANLINE_ORDER, DXGI_RATIONAL,
     },
     windows::win32::game_mode::HasExpandedResources,
+    windows::win32::hi_dpi::SetProcessDpiAwarenessContext,
+    windows::win32::keyboard_and_mouse_input::{INPUT_Anonymous, INPUT, KEYBDINPUT},
     windows::win32::ldap::ldapsearch,
-    windows::win32::security::ACCESS_MODE,
-    windows::win32::structured_storage::{CreateStreamOnHGlobal, STREAM_SEEK},
+    windows::win32::menus_and_resources::wsprintfW,
+    windows::win32::multimedia::WAVEFORMATEX,
+    windows::win32::security::{try_BCryptGenRandom, BCryptGenRandom, ACCESS_MODE},
+    windows::win32::structured_storage::{CreateStreamOnHGlobal, PROPVARIANT, STREAM_SEEK},
     windows::win32::system_services::{
-        CreateEventW, SetEvent, WaitForSingleObject, DXGI_ERROR_INVALID_CALL, HANDLE, WM_KEYUP,
+        try_SetEvent, CreateEventW, SetEvent, WaitForSingleObject, DXGI_ERROR_INVALID_CALL, HANDLE,
+        HWND_TOPMOST, WM_KEYUP,
     },
     windows::win32::upnp::UIAnimationManager,
     windows::win32::upnp::UIAnimationTransitionLibrary,
     windows::win32::windows_accessibility::UIA_ScrollPatternNoScroll,
     windows::win32::windows_and_messaging::{CHOOSECOLORW, HWND, PROPENUMPROCA, PROPENUMPROCW},
     windows::win32::windows_color_system::WhitePoint,
-    windows::win32::windows_programming::CloseHandle,
-    windows::{Abi, Guid, Interface, BOOL, FALSE},
+    windows::win32::windows_programming::{try_CloseHandle, CloseHandle},
+    windows::{Abi, Guid, BOOL, FALSE},
 };
 
 #[test]
 fn signed_enum32() {
     assert!(ACCESS_MODE::default() == 0.into());
     assert!(ACCESS_MODE::REVOKE_ACCESS.abi() == ACCESS_MODE::REVOKE_ACCESS);
+    assert!(ACCESS_MODE::NOT_USED_ACCESS < ACCESS_MODE::REVOKE_ACCESS);
 }
 
 #[test]
@@ -71,6 +78,65 @@ fn rect() {
     );
 }
 
+#[test]
+fn renamed_field() {
+    let chroma = CIECHROMA {
+        x: 1,
+        y: 2,
+        luminance: 3,
+    };
+
+    assert!(chroma.x == 1);
+    assert!(chroma.y == 2);
+    assert!(chroma.luminance == 3);
+}
+
+#[test]
+fn rect_hash() {
+    let mut set = std::collections::HashSet::new();
+    set.insert(RECT {
+        left: 1,
+        top: 2,
+        right: 3,
+        bottom: 4,
+    });
+
+    assert!(set.contains(&RECT {
+        left: 1,
+        top: 2,
+        right: 3,
+        bottom: 4,
+    }));
+    assert!(!set.contains(&RECT::default()));
+}
+
+#[test]
+fn packed() {
+    let format = WAVEFORMATEX {
+        n_channels: 2,
+        n_samples_per_sec: 44100,
+        ..Default::default()
+    };
+
+    let clone = format.clone();
+    assert!(clone == format);
+    assert!(clone.n_samples_per_sec == 44100);
+    assert!(format!("{:?}", clone).contains("n_samples_per_sec: 44100"));
+}
+
+#[test]
+fn union() {
+    let mut value = BITS_JOB_PROPERTY_VALUE::default();
+    assert!(unsafe { value.uint64 } == 0);
+
+    value.dword = 123;
+    assert!(unsafe { value.dword } == 123);
+
+    let copy = value;
+    assert!(unsafe { copy.dword } == 123);
+    assert!(format!("{:?}", copy) == "BITS_JOB_PROPERTY_VALUE");
+}
+
 #[test]
 fn dxgi_mode_desc() {
     let _ = DXGI_MODE_DESC {
@@ -86,6 +152,26 @@ fn dxgi_mode_desc() {
     };
 }
 
+#[test]
+fn size_field_default() {
+    let color = CHOOSECOLORW::default();
+    assert!(color.l_struct_size == std::mem::size_of::<CHOOSECOLORW>() as u32);
+
+    let rect = RECT::default();
+    assert!(rect.left == 0);
+}
+
+#[test]
+fn builder() {
+    let color = CHOOSECOLORW::default()
+        .with_rgb_result(0x00ff_0000)
+        .with_flags(1);
+
+    assert!(color.l_struct_size == std::mem::size_of::<CHOOSECOLORW>() as u32);
+    assert!(color.rgb_result == 0x00ff_0000);
+    assert!(color.flags == 1);
+}
+
 #[cfg(target_pointer_width = "64")]
 #[test]
 fn size64() {
@@ -104,6 +190,7 @@ fn size32() {
     assert!(std::mem::size_of::<RECT>() == 16);
     assert!(std::mem::size_of::<DXGI_MODE_DESC>() == 28);
     assert!(std::mem::size_of::<CHOOSECOLORW>() == 36);
+    assert!(std::mem::size_of::<WAVEFORMATEX>() == 18);
 }
 
 #[test]
@@ -112,17 +199,28 @@ fn constant() {
     assert!(D3D12_DEFAULT_BLEND_FACTOR_ALPHA == 1f32);
     assert!(UIA_ScrollPatternNoScroll == -1f64);
     assert!(D3DCOMPILER_DLL == "d3dcompiler_47.dll");
+
+    // Typed as the handle it stands for.
+    assert!(HWND_TOPMOST == HWND(-1));
+}
+
+#[test]
+fn handle() {
+    assert!(HANDLE(0).is_null());
+    assert!(HANDLE(0).is_invalid());
+    assert!(HANDLE(-1).is_invalid());
+    assert!(!HANDLE(-1).is_null());
+    assert!(!HANDLE(4).is_invalid());
+
+    assert!(HWND::from(8) == HWND(8));
+    assert!(isize::from(HWND(8)) == 8);
+    assert!(format!("{:?}", HWND(0x1234)) == "HWND(0x1234)");
 }
 
 #[test]
 fn function() -> windows::Result<()> {
     unsafe {
-        let event = CreateEventW(
-            std::ptr::null_mut(),
-            true.into(),
-            false.into(),
-            std::ptr::null_mut(),
-        );
+        let event = CreateEventW(None, true.into(), false.into(), std::ptr::null_mut());
         assert!(event.0 != 0);
 
         SetEvent(event).ok()?;
@@ -135,6 +233,70 @@ fn function() -> windows::Result<()> {
     }
 }
 
+#[test]
+fn result_function() -> windows::Result<()> {
+    unsafe {
+        let event = CreateEventW(None, true.into(), false.into(), std::ptr::null_mut());
+        try_SetEvent(event)?;
+        try_CloseHandle(event)?;
+
+        // A null handle fails with `ERROR_INVALID_HANDLE` from `GetLastError`.
+        let error = try_SetEvent(HANDLE(0)).unwrap_err();
+        assert!(error.code() == windows::ErrorCode(0x8007_0006));
+        Ok(())
+    }
+}
+
+#[test]
+fn string_param() -> windows::Result<()> {
+    unsafe {
+        let event = CreateEventW(None, true.into(), false.into(), "windows-rs-string-param");
+        assert!(event.0 != 0);
+
+        CloseHandle(event).ok()?;
+        Ok(())
+    }
+}
+
+#[test]
+fn variadic() {
+    unsafe {
+        let mut buffer = [0u16; 64];
+        let format: Vec<u16> = "%d-%s\0".encode_utf16().collect();
+        let text: Vec<u16> = "text\0".encode_utf16().collect();
+
+        let len = wsprintfW(buffer.as_mut_ptr(), format.as_ptr(), 42i32, text.as_ptr());
+        assert_eq!(String::from_utf16_lossy(&buffer[..len as usize]), "42-text");
+    }
+}
+
+#[test]
+fn owned_handle() -> windows::Result<()> {
+    unsafe {
+        let mut event = windows::Owned::new(CreateEventW(
+            None,
+            true.into(),
+            false.into(),
+            std::ptr::null_mut(),
+        ));
+        assert!(!event.is_invalid());
+
+        SetEvent(*event).ok()?;
+        assert!(WaitForSingleObject(*event, 0) == 0);
+
+        // Replacing the handle frees the one already owned.
+        *event.set_abi() = CreateEventW(None, true.into(), false.into(), std::ptr::null_mut());
+        assert!(WaitForSingleObject(*event, 0) != 0);
+
+        let raw = event.into_raw();
+        CloseHandle(raw).ok()?;
+
+        let empty = windows::Owned::<HANDLE>::default();
+        assert!(empty.is_null());
+        Ok(())
+    }
+}
+
 #[test]
 fn bool_as_error() {
     unsafe {
@@ -217,10 +379,7 @@ fn com() -> windows::Result<()> {
 #[test]
 fn com_inheritance() {
     unsafe {
-        let mut factory: Option<IDXGIFactory7> = None;
-        let factory: IDXGIFactory7 = CreateDXGIFactory1(&IDXGIFactory7::IID, factory.set_abi())
-            .and_some(factory)
-            .unwrap();
+        let factory: IDXGIFactory7 = CreateDXGIFactory1().unwrap();
 
         // IDXGIFactory
         assert!(
@@ -243,6 +402,12 @@ fn com_inheritance() {
                 .0
                 == DXGI_ERROR_INVALID_CALL as u32
         );
+
+        // Borrowed as a base interface without a cast
+        let base: &IDXGIFactory = &factory;
+        assert!(
+            base.GetWindowAssociation(std::ptr::null_mut()).0 == DXGI_ERROR_INVALID_CALL as u32
+        );
     }
 }
 
@@ -259,7 +424,6 @@ fn onecore_imports() -> windows::Result<()> {
                 .as_wide()
                 .as_ptr(),
             0,
-            0,
             &mut uri,
         )
         .and_some(uri)?;
@@ -273,13 +437,13 @@ fn onecore_imports() -> windows::Result<()> {
             0,
             HANDLE(0),
             MINIDUMP_TYPE::MiniDumpNormal,
-            std::ptr::null_mut(),
-            std::ptr::null_mut(),
-            std::ptr::null_mut(),
+            None,
+            None,
+            None,
         );
         assert!(!result.as_bool());
 
-        assert!(D3DDisassemble11Trace(std::ptr::null_mut(), 0, None, 0, 0, 0, &mut None).is_err());
+        assert!(D3DDisassemble11Trace(&[], None, 0, 0, 0, &mut None).is_err());
 
         Ok(())
     }
@@ -312,18 +476,24 @@ fn onecore_imports() -> windows::Result<()> {
 
 #[test]
 fn callback() {
-    let a: PROPENUMPROCA = callback_a;
-    assert!(BOOL(789) == a(HWND(123), "hello a\0".as_ptr() as *const i8, HANDLE(456)));
+    unsafe {
+        let a: PROPENUMPROCA = callback_a;
+        assert!(BOOL(789) == a(HWND(123), "hello a\0".as_ptr() as *const i8, HANDLE(456)));
 
-    let a: PROPENUMPROCW = callback_w;
-    assert!(
-        BOOL(789)
-            == a(
-                HWND(123),
-                windows::HString::from("hello w\0").as_wide().as_ptr(),
-                HANDLE(456)
-            )
-    );
+        let a: PROPENUMPROCW = callback_w;
+        assert!(
+            BOOL(789)
+                == a(
+                    HWND(123),
+                    windows::HString::from("hello w\0").as_wide().as_ptr(),
+                    HANDLE(456)
+                )
+        );
+    }
+
+    // Callback fields default to null.
+    let color = CHOOSECOLORW::default();
+    assert!(color.lpfn_hook.is_none());
 }
 
 // TODO: second parameter should be *const i8
@@ -382,9 +552,109 @@ fn empty_struct() {
     assert!(UIAnimationManager == Guid::from("4C1FC63A-695C-47E8-A339-1A194BE3D0B8"));
 }
 
+#[test]
+fn nested_struct() {
+    let mut input = INPUT {
+        r#type: 1, // INPUT_KEYBOARD
+        anonymous: INPUT_Anonymous {
+            ki: KEYBDINPUT {
+                w_vk: 0x41,
+                ..Default::default()
+            },
+        },
+    };
+
+    unsafe {
+        assert_eq!(input.anonymous.ki.w_vk, 0x41);
+        input.anonymous.ki.dw_flags = 2; // KEYEVENTF_KEYUP
+        assert_eq!(input.anonymous.ki.dw_flags, 2);
+    }
+
+    let clone = input.clone();
+    assert_eq!(clone.r#type, 1);
+    assert_eq!(unsafe { clone.anonymous.ki.w_vk }, 0x41);
+    assert_eq!(
+        std::mem::size_of::<INPUT>(),
+        if cfg!(target_pointer_width = "64") {
+            40
+        } else {
+            28
+        }
+    );
+}
+
+#[test]
+fn union_of_references() -> windows::Result<()> {
+    use std::mem::ManuallyDrop;
+
+    let mut value = PROPVARIANT::default();
+
+    unsafe {
+        let mut stream = None;
+        let stream = CreateStreamOnHGlobal(0, true.into(), &mut stream).and_some(stream)?;
+        let inner = &mut value.anonymous.anonymous;
+        inner.vt = 13; // VT_UNKNOWN
+        inner.anonymous.punk_val = ManuallyDrop::new(Some(stream.into()));
+        assert!(inner.anonymous.punk_val.is_some());
+
+        // The union doesn't know which field is set, so the reference is released explicitly.
+        ManuallyDrop::drop(&mut inner.anonymous.punk_val);
+    }
+
+    assert_eq!(
+        std::mem::size_of::<PROPVARIANT>(),
+        if cfg!(target_pointer_width = "64") {
+            24
+        } else {
+            16
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn nt_status() -> windows::Result<()> {
+    let mut buffer = [0u8; 16];
+
+    unsafe {
+        // BCRYPT_USE_SYSTEM_PREFERRED_RNG
+        assert!(BCryptGenRandom(std::ptr::null(), &mut buffer, 2).is_ok());
+        try_BCryptGenRandom(std::ptr::null(), &mut buffer, 2)?;
+
+        // Without an algorithm or the flag, there's nothing to generate the numbers with.
+        let status = BCryptGenRandom(std::ptr::null(), &mut buffer, 0);
+        assert!(status.is_err());
+        assert_eq!(status.ok().unwrap_err().code(), status.to_error_code());
+        assert_eq!(status.to_error_code().0 & 0x1000_0000, 0x1000_0000);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn array_fields() {
+    let mut desc = DXGI_ADAPTER_DESC1::default();
+    assert_eq!(desc.description, [0; 128]);
+    assert_eq!(desc, desc.clone());
+
+    let name: Vec<u16> = "Adapter".encode_utf16().collect();
+    desc.description[..name.len()].copy_from_slice(&name);
+    assert_ne!(desc, DXGI_ADAPTER_DESC1::default());
+    assert!(format!("{:?}", desc).contains("description: [65, 100, 97"));
+}
+
 #[test]
 fn struct_constants() {
     assert_eq!(WhitePoint::CHROMATICITY, 0);
     assert_eq!(WhitePoint::TEMPERATURE, 1);
     assert_eq!(WhitePoint::D65, 2);
 }
+
+#[test]
+fn delay_load() -> windows::Result<()> {
+    // The function exists, so it's resolved even though setting the awareness may fail if the
+    // process already has one.
+    let _: BOOL = unsafe { SetProcessDpiAwarenessContext(-4)? }; // DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2
+
+    Ok(())
+}
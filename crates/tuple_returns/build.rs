@@ -0,0 +1,7 @@
+fn main() {
+    windows::build!(
+        test_component::TestRunner,
+        // Returns out parameters as part of a tuple
+        tuple_returns = true,
+    );
+}
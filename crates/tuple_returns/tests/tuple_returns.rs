@@ -0,0 +1,17 @@
+use tuple_returns::test_component::*;
+
+#[test]
+fn tuple_returns() -> windows::Result<()> {
+    let tests = TestRunner::make_tests()?;
+
+    let (c, b) = tests.param1(true)?;
+    assert!(b && c);
+
+    let (c, b) = tests.param2(123)?;
+    assert!(b == 123 && c == 123);
+
+    let (c, b) = tests.param12("hello")?;
+    assert!(b == "hello" && c == "hello");
+
+    Ok(())
+}
@@ -0,0 +1,7 @@
+fn main() {
+    windows::build!(
+        windows::application_model::background::{
+            BackgroundTaskBuilder, BackgroundTaskRegistration, IBackgroundTask, TimeTrigger
+        },
+    );
+}
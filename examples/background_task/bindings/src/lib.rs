@@ -0,0 +1 @@
+::windows::include_bindings!();
@@ -0,0 +1,37 @@
+use bindings::windows::application_model::background::*;
+use bindings::*;
+
+const TASK_NAME: &str = "RustBackgroundTask";
+
+// Registers a task that is triggered every 15 minutes, replacing the registration from any earlier
+// run. Background tasks require package identity, so this only succeeds when run from a packaged
+// app. Without a task entry point, the task runs in the app's own process and is delivered to the
+// app's `OnBackgroundActivated` override, which can pass the task instance on to `Task`.
+fn register() -> windows::Result<BackgroundTaskRegistration> {
+    BackgroundTaskBuilder::register_named(TASK_NAME, TimeTrigger::create(15, false)?)
+}
+
+#[::windows::implement(windows::application_model::background::IBackgroundTask)]
+struct Task;
+
+impl Task {
+    fn run(&self, instance: &Option<IBackgroundTaskInstance>) -> windows::Result<()> {
+        println!("Running {}", TASK_NAME);
+
+        // The deferral keeps the task alive until any asynchronous work is done.
+        if let Some(instance) = instance {
+            let deferral = instance.get_deferral()?;
+            deferral.complete()?;
+        }
+
+        Ok(())
+    }
+}
+
+fn main() -> windows::Result<()> {
+    let registration = register()?;
+    println!("Registered {:?}", registration.task_id()?);
+
+    let task: IBackgroundTask = Task.into();
+    task.run(None)
+}
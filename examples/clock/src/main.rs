@@ -214,8 +214,7 @@ impl Window {
         let target = self.target.as_ref().unwrap();
         let brush = self.brush.as_ref().unwrap();
 
-        let mut size = D2D_SIZE_F::default();
-        unsafe { target.GetSize(&mut size) };
+        let size = unsafe { target.GetSize() };
 
         let radius = size.width.min(size.height).max(200.0) / 2.0 - 50.0;
         let translation = Matrix3x2::translation(size.width / 2.0, size.height / 2.0);
@@ -310,8 +309,7 @@ impl Window {
     }
 
     fn create_clock(&self, target: &ID2D1DeviceContext) -> Result<ID2D1Bitmap1> {
-        let mut size_f = D2D_SIZE_F::default();
-        unsafe { target.GetSize(&mut size_f) };
+        let size_f = unsafe { target.GetSize() };
 
         let size_u = D2D_SIZE_U {
             width: (size_f.width * self.dpi / 96.0) as u32,
@@ -540,7 +538,7 @@ fn create_factory() -> Result<ID2D1Factory1> {
         D2D1CreateFactory(
             D2D1_FACTORY_TYPE::D2D1_FACTORY_TYPE_SINGLE_THREADED,
             &ID2D1Factory1::IID,
-            &options,
+            Some(&options),
             result.set_abi(),
         )
         .and_some(result)
@@ -548,10 +546,7 @@ fn create_factory() -> Result<ID2D1Factory1> {
 }
 
 fn create_dxfactory() -> Result<IDXGIFactory2> {
-    unsafe {
-        let mut dxfactory: Option<IDXGIFactory2> = None;
-        CreateDXGIFactory1(&IDXGIFactory2::IID, dxfactory.set_abi()).and_some(dxfactory)
-    }
+    unsafe { CreateDXGIFactory1() }
 }
 
 fn create_style(factory: &ID2D1Factory1) -> Result<ID2D1StrokeStyle> {
@@ -598,12 +593,11 @@ fn create_device_with_type(drive_type: D3D_DRIVER_TYPE) -> Result<ID3D11Device>
             drive_type,
             0,
             flags,
-            std::ptr::null(),
-            0,
+            &[],
             D3D11_SDK_VERSION as u32,
-            &mut device,
-            std::ptr::null_mut(),
-            &mut None,
+            Some(&mut device),
+            None,
+            None,
         )
         .and_some(device)
     }
@@ -6,12 +6,7 @@ use bindings::{
 
 fn main() -> Result<()> {
     unsafe {
-        let event = CreateEventW(
-            std::ptr::null_mut(),
-            true.into(),
-            false.into(),
-            std::ptr::null_mut(),
-        );
+        let event = CreateEventW(None, true.into(), false.into(), std::ptr::null_mut());
 
         assert!(event.0 != 0);
 
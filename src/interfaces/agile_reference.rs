@@ -0,0 +1,43 @@
+use crate::*;
+
+/// A reference to an object that may be resolved from any apartment. `IAgileReference` represents the
+/// [IAgileReference](https://docs.microsoft.com/en-us/windows/win32/api/objidl/nn-objidl-iagilereference)
+/// interface.
+#[repr(transparent)]
+#[derive(Clone, PartialEq, Eq)]
+pub struct IAgileReference(IUnknown);
+
+impl IAgileReference {
+    /// Returns the object in the calling apartment, marshaling it there if needed.
+    pub fn resolve<T: Interface>(&self) -> Result<T> {
+        unsafe {
+            let mut object = None;
+            (self.vtable().3)(self.abi(), &T::IID, &mut object as *mut _ as _).and_some(object)
+        }
+    }
+}
+
+#[repr(C)]
+pub struct IAgileReference_vtable(
+    pub unsafe extern "system" fn(this: RawPtr, iid: &Guid, interface: *mut RawPtr) -> ErrorCode,
+    pub unsafe extern "system" fn(this: RawPtr) -> u32,
+    pub unsafe extern "system" fn(this: RawPtr) -> u32,
+    pub unsafe extern "system" fn(this: RawPtr, iid: &Guid, object: *mut RawPtr) -> ErrorCode,
+);
+
+unsafe impl Interface for IAgileReference {
+    type Vtable = IAgileReference_vtable;
+
+    const IID: Guid = Guid::from_values(
+        0xC03F_6A43,
+        0x65A4,
+        0x9818,
+        [0x98, 0x7E, 0xE0, 0xB8, 0x10, 0xD2, 0xA6, 0xF2],
+    );
+}
+
+impl std::fmt::Debug for IAgileReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
@@ -1,5 +1,6 @@
 mod activation_factory;
 mod agile_object;
+mod agile_reference;
 mod error_info;
 mod language_exception_error_info2;
 mod object;
@@ -8,6 +9,7 @@ mod unknown;
 
 pub use activation_factory::*;
 pub use agile_object::*;
+pub use agile_reference::*;
 pub use error_info::*;
 pub use language_exception_error_info2::*;
 pub use object::*;
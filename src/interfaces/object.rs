@@ -69,6 +69,30 @@ impl std::fmt::Debug for Object {
     }
 }
 
+impl From<Object> for IUnknown {
+    fn from(value: Object) -> Self {
+        value.0
+    }
+}
+
+impl From<&Object> for IUnknown {
+    fn from(value: &Object) -> Self {
+        value.0.clone()
+    }
+}
+
+impl<'a> Into<Param<'a, IUnknown>> for Object {
+    fn into(self) -> Param<'a, IUnknown> {
+        Param::Owned(self.into())
+    }
+}
+
+impl<'a> Into<Param<'a, IUnknown>> for &'a Object {
+    fn into(self) -> Param<'a, IUnknown> {
+        Param::Borrowed(&self.0)
+    }
+}
+
 macro_rules! primitive_boxed_type {
     ($(($t:ty, $m:ident)),+) => {
         $(impl std::convert::TryFrom<$t> for Object {
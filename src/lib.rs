@@ -21,13 +21,15 @@ use interfaces::*;
 use runtime::*;
 
 pub use interfaces::{IActivationFactory, IAgileObject, IUnknown, Object};
-pub use result::{Error, ErrorCode, Result, BOOL, FALSE, TRUE};
+pub use result::{Error, ErrorCode, Result, BOOL, BOOLEAN, FALSE, NTSTATUS, TRUE};
 pub use runtime::{
-    create_instance, factory, initialize_mta, initialize_sta, Array, FactoryCache, Guid, Param,
-    RefCount, Waiter,
+    create_instance, factory, initialize_mta, initialize_sta, ActivationHost, Activator,
+    AgileReference, Array, AsyncFuture, Awaitable, CoTaskMem, EventSource, FactoryCache, Free,
+    Guid, HeapMem, LocalMem, Owned, Param, Process, ProcessBuilder, ProgressNext, ProgressSink,
+    ProgressStream, RefCount, WaitHandle, Waiter,
 };
-pub use strings::{BString, CoString, HString};
-pub use traits::{Abi, Interface, RuntimeName, RuntimeType};
+pub use strings::{BString, CoString, HString, StringParam};
+pub use traits::{uuidof, Abi, Interface, RuntimeName, RuntimeType};
 pub use windows_macros::{build, implement};
 
 extern crate self as windows;
@@ -36,6 +38,8 @@ mod bindings {
     include_bindings!();
 }
 
+pub use bindings::provenance;
+
 #[doc(hidden)]
 pub type RawPtr = *mut std::ffi::c_void;
 
@@ -45,6 +49,12 @@ pub use bindings::windows::foundation;
 #[doc(hidden)]
 pub use const_sha1::ConstBuffer;
 
+#[doc(hidden)]
+pub use runtime::catch_panic;
+
+#[doc(hidden)]
+pub use runtime::delay_load;
+
 /// A stand-in for a type which is not yet fully supported by the `windows` crate.
 ///
 /// There should be tracking issues for each one of these types as they will eventually be supported.
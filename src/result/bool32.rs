@@ -90,6 +90,15 @@ impl PartialEq<BOOL> for BOOL {
     }
 }
 
+impl Eq for BOOL {}
+
+// Any non-zero value is true, so values that compare equal hash the same way.
+impl std::hash::Hash for BOOL {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_bool().hash(state)
+    }
+}
+
 impl PartialEq<bool> for BOOL {
     fn eq(&self, other: &bool) -> bool {
         self.as_bool() == *other
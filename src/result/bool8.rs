@@ -0,0 +1,142 @@
+use crate::{Abi, ErrorCode};
+
+/// An 8-bit boolean value returned by some Win32 functions, such as those in the native and
+/// security APIs.
+///
+/// The metadata describes `BOOLEAN` as a plain `u8`, so the generated bindings return `u8`
+/// values that may be wrapped as `BOOLEAN(value)` to test them.
+#[repr(transparent)]
+#[derive(Copy, Clone, Default)]
+pub struct BOOLEAN(pub u8);
+
+impl BOOLEAN {
+    /// Convert the `BOOLEAN` into a `bool`.
+    #[inline]
+    pub fn as_bool(self) -> bool {
+        self.0 != 0
+    }
+
+    /// Asserts that `self` is a success code.
+    #[inline]
+    pub fn unwrap(self) {
+        self.ok().unwrap();
+    }
+
+    /// Expects that `self` is a success code.
+    #[inline]
+    pub fn expect(self, msg: &str) {
+        self.ok().expect(msg);
+    }
+
+    /// Converts the `BOOLEAN` to `Result<()>`.
+    #[inline]
+    pub fn ok(self) -> crate::Result<()> {
+        if self.as_bool() {
+            Ok(())
+        } else {
+            Err(ErrorCode::from_thread().into())
+        }
+    }
+}
+
+unsafe impl Abi for BOOLEAN {
+    type Abi = Self;
+}
+
+impl From<BOOLEAN> for bool {
+    fn from(value: BOOLEAN) -> Self {
+        value.as_bool()
+    }
+}
+
+impl From<&BOOLEAN> for bool {
+    fn from(value: &BOOLEAN) -> Self {
+        value.as_bool()
+    }
+}
+
+impl From<bool> for BOOLEAN {
+    fn from(value: bool) -> Self {
+        Self(value as u8)
+    }
+}
+
+impl From<&bool> for BOOLEAN {
+    fn from(value: &bool) -> Self {
+        (*value).into()
+    }
+}
+
+impl std::fmt::Debug for BOOLEAN {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let msg = if self.as_bool() { "TRUE" } else { "FALSE" };
+        f.write_str(msg)
+    }
+}
+
+impl PartialEq<BOOLEAN> for BOOLEAN {
+    fn eq(&self, other: &BOOLEAN) -> bool {
+        self.as_bool() == other.as_bool()
+    }
+}
+
+impl Eq for BOOLEAN {}
+
+impl std::hash::Hash for BOOLEAN {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_bool().hash(state)
+    }
+}
+
+impl PartialEq<bool> for BOOLEAN {
+    fn eq(&self, other: &bool) -> bool {
+        self.as_bool() == *other
+    }
+}
+
+impl PartialEq<BOOLEAN> for bool {
+    fn eq(&self, other: &BOOLEAN) -> bool {
+        *self == other.as_bool()
+    }
+}
+
+impl std::ops::Not for BOOLEAN {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        (!self.as_bool()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion() {
+        assert!(BOOLEAN(1).as_bool());
+        assert!(BOOLEAN(0xFF).as_bool());
+        assert!(!BOOLEAN(0).as_bool());
+
+        let rust_bool: bool = BOOLEAN(2).into();
+        assert!(rust_bool);
+
+        assert_eq!(BOOLEAN::from(true), BOOLEAN(123));
+        assert_eq!(BOOLEAN::from(false), false);
+        assert_eq!(!BOOLEAN(0), true);
+    }
+
+    #[test]
+    fn methods() {
+        let value = BOOLEAN(1);
+        value.unwrap();
+        value.expect("test");
+        assert!(value.ok().is_ok());
+        assert!(BOOLEAN(0).ok().is_err());
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(format!("{:?}", BOOLEAN(1)), "TRUE");
+        assert_eq!(format!("{:?}", BOOLEAN(0)), "FALSE");
+    }
+}
@@ -2,7 +2,7 @@ use crate::*;
 
 /// A primitive error code value returned by most COM functions. An `ErrorCode` is sometimes called an `HRESULT`.
 #[repr(transparent)]
-#[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Hash)]
 #[must_use]
 pub struct ErrorCode(pub u32);
 
@@ -103,6 +103,12 @@ impl ErrorCode {
 
     /// A null pointer was sent or received.
     pub const E_POINTER: ErrorCode = ErrorCode(0x8000_4003);
+
+    /// The value is outside the valid range.
+    pub const E_BOUNDS: ErrorCode = ErrorCode(0x8000_000B);
+
+    /// The operation was canceled.
+    pub const ERROR_CANCELLED: ErrorCode = ErrorCode(0x8007_04C7);
 }
 
 unsafe impl Abi for ErrorCode {
@@ -1,10 +1,14 @@
 mod bool32;
+mod bool8;
 mod error;
 mod error_code;
+mod nt_status;
 
 pub use bool32::*;
+pub use bool8::*;
 pub use error::*;
 pub use error_code::*;
+pub use nt_status::*;
 
 /// A `Result` type that provides Windows error information.
 #[must_use]
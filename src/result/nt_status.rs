@@ -0,0 +1,82 @@
+use crate::*;
+
+/// A status code returned by native and kernel-adjacent functions, such as those in `bcrypt`
+/// and `ntdll`. An `NTSTATUS` is negative for errors and zero or positive for success, along
+/// with informational codes.
+#[repr(transparent)]
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct NTSTATUS(pub i32);
+
+impl NTSTATUS {
+    /// Returns `true` if `self` is a success or an informational code.
+    #[inline]
+    pub fn is_ok(self) -> bool {
+        self.0 >= 0
+    }
+
+    /// Returns `true` if `self` is a warning or an error code.
+    #[inline]
+    pub fn is_err(self) -> bool {
+        !self.is_ok()
+    }
+
+    /// Asserts that `self` is a success code.
+    ///
+    /// This will invoke the `panic!` macro if `self` is a failure code and display the
+    /// `NTSTATUS` value for diagnostics.
+    #[inline]
+    pub fn unwrap(self) {
+        assert!(self.is_ok(), "NTSTATUS 0x{:X}", self.0);
+    }
+
+    /// Converts the `NTSTATUS` to an `ErrorCode` with the NT facility bit set. This is
+    /// equivalent to [HRESULT_FROM_NT](https://docs.microsoft.com/en-us/windows/win32/api/winerror/nf-winerror-hresult_from_nt).
+    #[inline]
+    pub fn to_error_code(self) -> ErrorCode {
+        ErrorCode(self.0 as u32 | 0x1000_0000)
+    }
+
+    /// Converts the `NTSTATUS` to `Result<()>`, with the code converted by `to_error_code` if
+    /// it's a failure.
+    #[inline]
+    pub fn ok(self) -> Result<()> {
+        if self.is_ok() {
+            Ok(())
+        } else {
+            Err(self.to_error_code().into())
+        }
+    }
+
+    /// The operation succeeded.
+    pub const STATUS_SUCCESS: NTSTATUS = NTSTATUS(0);
+}
+
+unsafe impl Abi for NTSTATUS {
+    type Abi = Self;
+}
+
+impl From<NTSTATUS> for ErrorCode {
+    fn from(value: NTSTATUS) -> Self {
+        value.to_error_code()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion() {
+        assert!(NTSTATUS::STATUS_SUCCESS.is_ok());
+        assert!(NTSTATUS(0x4000_0000).is_ok()); // STATUS_OBJECT_NAME_EXISTS
+
+        let invalid_parameter = NTSTATUS(0xC000_000D_u32 as i32);
+        assert!(invalid_parameter.is_err());
+        assert_eq!(invalid_parameter.to_error_code(), ErrorCode(0xD000_000D));
+        assert_eq!(ErrorCode::from(invalid_parameter), ErrorCode(0xD000_000D));
+
+        assert!(NTSTATUS::STATUS_SUCCESS.ok().is_ok());
+        NTSTATUS::STATUS_SUCCESS.unwrap();
+    }
+}
@@ -0,0 +1,226 @@
+use crate::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Creates a new instance of a class implemented in Rust, usually by converting a type
+/// implementing the class's default interface with `#[implement]` into an `Object`.
+pub type Activator = fn() -> Result<Object>;
+
+/// Registers the activation factories for classes implemented in Rust with the current process
+/// so that they may be activated by other processes, as with an out-of-process server.
+///
+/// The registrations are revoked when the host is dropped, so a server should keep the host
+/// alive for as long as it is serving requests.
+pub struct ActivationHost {
+    cookie: RawPtr,
+    id: usize,
+}
+
+impl ActivationHost {
+    /// Registers the given classes, identified by their runtime class names.
+    pub fn register(classes: &[(&'static str, Activator)]) -> Result<Self> {
+        let names: Vec<HString> = classes.iter().map(|(name, _)| (*name).into()).collect();
+        let abi: Vec<RawPtr> = names.iter().map(|name| name.abi() as _).collect();
+        let callbacks: Vec<GetActivationFactory> =
+            classes.iter().map(|_| get_factory as _).collect();
+
+        // Identifies this host's registrations so that they can be removed again without
+        // disturbing those of other hosts for the same classes.
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+
+        REGISTRATIONS.lock().unwrap().extend(
+            classes
+                .iter()
+                .map(|(name, activator)| (id, *name, *activator)),
+        );
+
+        let mut cookie = std::ptr::null_mut();
+
+        let result = unsafe {
+            RoRegisterActivationFactories(
+                abi.as_ptr(),
+                callbacks.as_ptr(),
+                classes.len() as u32,
+                &mut cookie,
+            )
+            .map_err(Error::from)
+            .and_then(|code| code.ok())
+        };
+
+        if let Err(error) = result {
+            unregister(id);
+            return Err(error);
+        }
+
+        Ok(Self { cookie, id })
+    }
+
+    /// Returns the `Extension` element that declares the classes in an app's package manifest,
+    /// so that the system knows to start `executable` to activate them.
+    pub fn manifest(executable: &str, classes: &[&str]) -> String {
+        let classes: Vec<(&str, &str)> = classes.iter().map(|class| (*class, "both")).collect();
+        gen::gen_manifest_extension(gen::ManifestServer::OutOfProcess(executable), &classes)
+    }
+}
+
+impl Drop for ActivationHost {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = RoRevokeActivationFactories(self.cookie);
+        }
+
+        unregister(self.id);
+    }
+}
+
+type GetActivationFactory = extern "system" fn(name: RawPtr, factory: *mut RawPtr) -> ErrorCode;
+
+type Registration = (usize, &'static str, Activator);
+
+// The callback passed to `RoRegisterActivationFactories` has no context other than the class
+// name, so the activators are kept in a process-wide list along with the host that registered
+// them.
+static REGISTRATIONS: Mutex<Vec<Registration>> = Mutex::new(Vec::new());
+
+fn unregister(id: usize) {
+    REGISTRATIONS
+        .lock()
+        .unwrap()
+        .retain(|(host, _, _)| *host != id);
+}
+
+extern "system" fn get_factory(name: RawPtr, factory: *mut RawPtr) -> ErrorCode {
+    const CLASS_E_CLASSNOTAVAILABLE: ErrorCode = ErrorCode(0x8004_0111);
+
+    // Safe because the name is borrowed for the duration of the call.
+    let name: &HString = unsafe { std::mem::transmute(&name) };
+    let name = name.to_string();
+
+    let activator = REGISTRATIONS
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|(_, class, _)| *class == name)
+        .map(|(_, _, activator)| *activator);
+
+    match activator {
+        Some(activator) => unsafe {
+            *factory = Factory::new(activator);
+            ErrorCode::S_OK
+        },
+        None => CLASS_E_CLASSNOTAVAILABLE,
+    }
+}
+
+// A minimal `IActivationFactory` implementation that calls an `Activator`.
+#[repr(C)]
+struct Factory {
+    vtable: *const IActivationFactory_vtable,
+    count: RefCount,
+    activator: Activator,
+}
+
+impl Factory {
+    const VTABLE: IActivationFactory_vtable = IActivationFactory_vtable(
+        Self::query_interface,
+        Self::add_ref,
+        Self::release,
+        Self::get_iids,
+        Self::get_runtime_class_name,
+        Self::get_trust_level,
+        Self::activate_instance,
+    );
+
+    fn new(activator: Activator) -> RawPtr {
+        let factory = Box::new(Self {
+            vtable: &Self::VTABLE,
+            count: RefCount::new(),
+            activator,
+        });
+
+        Box::into_raw(factory) as _
+    }
+
+    unsafe extern "system" fn query_interface(
+        this: RawPtr,
+        iid: &Guid,
+        interface: *mut RawPtr,
+    ) -> ErrorCode {
+        if *iid == IUnknown::IID
+            || *iid == Object::IID
+            || *iid == IAgileObject::IID
+            || *iid == IActivationFactory::IID
+        {
+            *interface = this;
+            Self::add_ref(this);
+            ErrorCode::S_OK
+        } else {
+            *interface = std::ptr::null_mut();
+            ErrorCode::E_NOINTERFACE
+        }
+    }
+
+    unsafe extern "system" fn add_ref(this: RawPtr) -> u32 {
+        (*(this as *mut Self)).count.add_ref()
+    }
+
+    unsafe extern "system" fn release(this: RawPtr) -> u32 {
+        let remaining = (*(this as *mut Self)).count.release();
+
+        if remaining == 0 {
+            drop(Box::from_raw(this as *mut Self));
+        }
+
+        remaining
+    }
+
+    unsafe extern "system" fn get_iids(
+        _this: RawPtr,
+        count: *mut u32,
+        values: *mut *mut Guid,
+    ) -> ErrorCode {
+        *count = 0;
+        *values = std::ptr::null_mut();
+        ErrorCode::S_OK
+    }
+
+    unsafe extern "system" fn get_runtime_class_name(
+        _this: RawPtr,
+        value: *mut RawPtr,
+    ) -> ErrorCode {
+        *value = std::ptr::null_mut();
+        ErrorCode::S_OK
+    }
+
+    unsafe extern "system" fn get_trust_level(_this: RawPtr, value: *mut i32) -> ErrorCode {
+        *value = 0; // BaseTrust
+        ErrorCode::S_OK
+    }
+
+    unsafe extern "system" fn activate_instance(
+        this: RawPtr,
+        object: &mut Option<Object>,
+    ) -> ErrorCode {
+        match ((*(this as *mut Self)).activator)() {
+            Ok(instance) => {
+                // The out parameter may not be initialized, so it mustn't be dropped.
+                std::ptr::write(object, Some(instance));
+                ErrorCode::S_OK
+            }
+            Err(error) => error.into(),
+        }
+    }
+}
+
+demand_load! {
+    "combase.dll" {
+        fn RoRegisterActivationFactories(
+            classes: *const RawPtr,
+            callbacks: *const GetActivationFactory,
+            count: u32,
+            cookie: *mut RawPtr,
+        ) -> ErrorCode;
+        fn RoRevokeActivationFactories(cookie: RawPtr) -> ();
+    }
+}
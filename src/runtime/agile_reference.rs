@@ -0,0 +1,67 @@
+use crate::*;
+use std::marker::PhantomData;
+
+/// A reference to an object, such as a UI object bound to the thread that created it, that may be
+/// sent to and used from other threads.
+///
+/// Calling a non-agile object from another apartment is an error at best, and may corrupt the
+/// object's state. An `AgileReference` instead marshals the object to the apartment that
+/// `resolve` is called from, returning an error if it can't be reached from there.
+pub struct AgileReference<T> {
+    reference: IAgileReference,
+    _t: PhantomData<T>,
+}
+
+impl<T: Interface> AgileReference<T> {
+    /// Creates an agile reference to the object from the object's own apartment.
+    pub fn new(object: &T) -> Result<Self> {
+        let unknown: IUnknown = object.cast()?;
+        let mut reference: Option<IAgileReference> = None;
+
+        unsafe {
+            RoGetAgileReference(
+                AGILEREFERENCE_DEFAULT,
+                &T::IID,
+                unknown.abi(),
+                &mut reference as *mut _ as _,
+            )?
+            .and_some(reference)
+            .map(|reference| Self {
+                reference,
+                _t: PhantomData,
+            })
+        }
+    }
+
+    /// Returns the object for use from the calling thread's apartment.
+    pub fn resolve(&self) -> Result<T> {
+        self.reference.resolve()
+    }
+}
+
+impl<T> Clone for AgileReference<T> {
+    fn clone(&self) -> Self {
+        Self {
+            reference: self.reference.clone(),
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for AgileReference<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.reference)
+    }
+}
+
+// The agile reference itself may be used from any apartment, which is its purpose.
+unsafe impl<T> Send for AgileReference<T> {}
+unsafe impl<T> Sync for AgileReference<T> {}
+
+const AGILEREFERENCE_DEFAULT: u32 = 0;
+
+demand_load! {
+    "combase.dll" {
+        fn RoGetAgileReference(options: u32, iid: &Guid, object: RawPtr, reference: *mut RawPtr) -> ErrorCode;
+    }
+}
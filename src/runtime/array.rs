@@ -85,6 +85,28 @@ impl<T: RuntimeType> Array<T> {
         }
     }
 
+    /// Moves the elements of the array into a `Vec`, freeing the original buffer.
+    pub fn into_vec(mut self) -> Vec<T::DefaultType> {
+        let len = self.len();
+        let mut vec = Vec::with_capacity(len);
+
+        if len == 0 {
+            return vec;
+        }
+
+        // SAFETY: the elements are moved into the vector's own allocation and the array is reset to
+        // empty before the buffer is freed, so they are neither dropped nor freed twice.
+        unsafe {
+            std::ptr::copy_nonoverlapping(self.data, vec.as_mut_ptr(), len);
+            vec.set_len(len);
+            CoTaskMemFree(self.data as _);
+        }
+
+        self.data = std::ptr::null_mut();
+        self.len = 0;
+        vec
+    }
+
     #[doc(hidden)]
     /// Get a mutable pointer to the array's length
     ///
@@ -170,6 +192,18 @@ mod tests {
         assert!(empty[2] == 0);
     }
 
+    #[test]
+    fn into_vec() {
+        let empty = Array::<u32>::new();
+        assert!(empty.into_vec().is_empty());
+
+        let mut a = Array::<u32>::with_len(3);
+        a[0] = 1;
+        a[1] = 2;
+        a[2] = 3;
+        assert!(a.into_vec() == [1, 2, 3]);
+    }
+
     #[test]
     fn uri() {
         use windows::foundation::Uri;
@@ -0,0 +1,185 @@
+use crate::*;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, Weak};
+use std::task::{Context, Poll, Waker};
+
+/// Implemented by the async actions and operations so that they can be awaited. This trait is
+/// used by the generated bindings and should not be used directly.
+#[doc(hidden)]
+pub trait Awaitable: Interface {
+    type Output;
+
+    fn is_started(&self) -> Result<bool>;
+    fn set_completed_callback<F: FnMut() + 'static>(&self, callback: F) -> Result<()>;
+    fn results(&self) -> Result<Self::Output>;
+    fn request_cancel(&self);
+
+    /// Calls the callback once the operation is no longer running, or right away if it isn't.
+    /// Unlike the operation's completion handler, any number of callbacks may be registered.
+    /// The callback must be `Send` as it's called on whichever thread completes the operation:
+    ///
+    /// ```compile_fail,E0277
+    /// fn wait<T: windows::Awaitable>(operation: &T) -> windows::Result<()> {
+    ///     let shared = std::rc::Rc::new(0);
+    ///     operation.on_completed(move || drop(shared.clone()))
+    /// }
+    /// ```
+    fn on_completed<F: FnMut() + Send + 'static>(&self, callback: F) -> Result<()> {
+        on_completed(self, Box::new(callback))
+    }
+}
+
+// An async action or operation only accepts a single completion handler, but may be waited on
+// with `get`, awaited, and have its progress streamed, in any order. The first of these sets a
+// handler that calls the callbacks registered by all of them, which are found through a
+// process-wide list keyed by the operation's identity.
+type Registrations = Mutex<Vec<(usize, Weak<Mutex<Callbacks>>)>>;
+
+static REGISTRATIONS: Registrations = Mutex::new(Vec::new());
+
+struct Callbacks(Vec<Box<dyn FnMut() + Send>>);
+
+fn on_completed<T: Awaitable>(operation: &T, mut callback: Box<dyn FnMut() + Send>) -> Result<()> {
+    if !operation.is_started()? {
+        callback();
+        return Ok(());
+    }
+
+    let identity = operation.cast::<IUnknown>()?.abi() as usize;
+
+    let callbacks = {
+        let mut registrations = REGISTRATIONS.lock().unwrap();
+
+        // Operations that were released before completing leave their registration behind.
+        registrations.retain(|(_, callbacks)| callbacks.strong_count() > 0);
+
+        let existing = registrations
+            .iter()
+            .find(|(key, _)| *key == identity)
+            .and_then(|(_, callbacks)| callbacks.upgrade());
+
+        if let Some(callbacks) = existing {
+            callbacks.lock().unwrap().0.push(callback);
+            return Ok(());
+        }
+
+        let callbacks = Arc::new(Mutex::new(Callbacks(vec![callback])));
+        registrations.push((identity, Arc::downgrade(&callbacks)));
+        callbacks
+    };
+
+    // The registration is removed before the callbacks are called so that none are added once
+    // they have been. Calling it again does nothing.
+    let complete = Arc::new(move || {
+        REGISTRATIONS
+            .lock()
+            .unwrap()
+            .retain(|(key, _)| *key != identity);
+
+        let pending = std::mem::take(&mut callbacks.lock().unwrap().0);
+
+        for mut callback in pending {
+            callback();
+        }
+    });
+
+    let handler = complete.clone();
+
+    if let Err(error) = operation.set_completed_callback(move || handler()) {
+        // A handler can't be set once the operation has completed, in which case the callbacks
+        // are called right away. Otherwise some other handler has been set.
+        if operation.is_started()? {
+            REGISTRATIONS
+                .lock()
+                .unwrap()
+                .retain(|(key, _)| *key != identity);
+
+            return Err(error);
+        }
+
+        complete();
+    }
+
+    Ok(())
+}
+
+/// The future that an async action or operation is turned into when it's awaited.
+///
+/// The operation's completion handler is set the first time the future is polled and wakes
+/// whichever task polled it last. If the future is dropped before the operation has finished,
+/// as when a timeout elapses or a `select` picks another branch, the handler is detached and
+/// the operation is canceled so that it doesn't keep running unobserved.
+pub struct AsyncFuture<T: Awaitable> {
+    operation: T,
+    waker: Option<Arc<Mutex<Option<Waker>>>>,
+    finished: bool,
+}
+
+impl<T: Awaitable> AsyncFuture<T> {
+    #[doc(hidden)]
+    pub fn new(operation: T) -> Self {
+        Self {
+            operation,
+            waker: None,
+            finished: false,
+        }
+    }
+}
+
+// The future never relies on its address, and generic operations only hold phantom type parameters.
+impl<T: Awaitable> Unpin for AsyncFuture<T> {}
+
+impl<T: Awaitable> Future for AsyncFuture<T> {
+    type Output = Result<T::Output>;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        if !this.operation.is_started()? {
+            this.finished = true;
+            return Poll::Ready(this.operation.results());
+        }
+
+        match &this.waker {
+            Some(waker) => *waker.lock().unwrap() = Some(context.waker().clone()),
+            None => {
+                let waker = Arc::new(Mutex::new(Some(context.waker().clone())));
+                let shared = waker.clone();
+
+                this.operation.on_completed(move || {
+                    if let Some(waker) = shared.lock().unwrap().take() {
+                        waker.wake();
+                    }
+                })?;
+
+                this.waker = Some(waker);
+            }
+        }
+
+        // The operation may have finished before the new waker was stored, in which case the
+        // handler has already woken the previous one.
+        if this.operation.is_started()? {
+            Poll::Pending
+        } else {
+            this.finished = true;
+            Poll::Ready(this.operation.results())
+        }
+    }
+}
+
+impl<T: Awaitable> Drop for AsyncFuture<T> {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+
+        if let Some(waker) = &self.waker {
+            waker.lock().unwrap().take();
+        }
+
+        if let Ok(true) = self.operation.is_started() {
+            self.operation.request_cancel();
+        }
+    }
+}
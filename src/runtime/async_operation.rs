@@ -0,0 +1,528 @@
+use crate::foundation::{
+    AsyncActionCompletedHandler, AsyncOperationCompletedHandler, AsyncStatus, IAsyncAction,
+    IAsyncInfo, IAsyncOperation,
+};
+use crate::*;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+// Stock implementations of `IAsyncAction` and `IAsyncOperation<T>` over Rust futures so that
+// classes implemented in Rust can return asynchronous results without writing the state machine
+// by hand.
+//
+// There's no executor: the future is first polled before `from_future` returns and is then polled
+// again by whichever thread wakes it, such as the thread pool thread that completes an awaited
+// WinRT operation. Futures that need an executor of their own, such as those relying on a tokio
+// reactor, should be spawned on it and the resulting handle awaited instead. Since the future may
+// be polled on any thread, it must be `Send`.
+
+const E_ILLEGAL_STATE_CHANGE: ErrorCode = ErrorCode(0x8000_000D);
+const E_ILLEGAL_METHOD_CALL: ErrorCode = ErrorCode(0x8000_000E);
+const E_ILLEGAL_DELEGATE_ASSIGNMENT: ErrorCode = ErrorCode(0x8000_0018);
+
+impl IAsyncAction {
+    /// Returns an action that runs the future and completes with its result.
+    ///
+    /// The action is canceled by dropping the future, and fails with the error's code if the
+    /// future returns an error.
+    ///
+    /// The future must be `Send`, since it's polled on whichever thread wakes it:
+    ///
+    /// ```compile_fail,E0277
+    /// let shared = std::rc::Rc::new(0);
+    /// windows::foundation::IAsyncAction::from_future(async move {
+    ///     drop(shared);
+    ///     Ok(())
+    /// });
+    /// ```
+    pub fn from_future<F: Future<Output = Result<()>> + Send + 'static>(future: F) -> Self {
+        StockAsync::<Action>::new(Box::pin(future))
+    }
+}
+
+impl<T: RuntimeType + 'static> IAsyncOperation<T> {
+    /// Returns an operation that runs the future and completes with its result.
+    ///
+    /// The operation is canceled by dropping the future, and fails with the error's code if the
+    /// future returns an error.
+    pub fn from_future<F: Future<Output = Result<T::DefaultType>> + Send + 'static>(
+        future: F,
+    ) -> Self {
+        StockAsync::<Operation<T>>::new(Box::pin(future))
+    }
+}
+
+// What differs between actions and operations: the interface, its completion handler, and its
+// results.
+trait AsyncKind: Sized + 'static {
+    type Interface: Interface;
+    type Handler: Interface + Clone;
+    type Output;
+    type Vtable: 'static;
+
+    const VTABLE: Self::Vtable;
+
+    fn invoke(handler: &Self::Handler, sender: &Self::Interface, status: AsyncStatus);
+}
+
+struct Action;
+struct Operation<T>(PhantomData<T>);
+
+#[repr(C)]
+struct ActionVtable(
+    unsafe extern "system" fn(this: RawPtr, iid: &Guid, interface: *mut RawPtr) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr) -> u32,
+    unsafe extern "system" fn(this: RawPtr) -> u32,
+    unsafe extern "system" fn(this: RawPtr, count: *mut u32, values: *mut *mut Guid) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, value: *mut RawPtr) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, value: *mut i32) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, handler: RawPtr) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, result: *mut RawPtr) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr) -> ErrorCode,
+);
+
+#[repr(C)]
+struct OperationVtable<T: RuntimeType + 'static>(
+    unsafe extern "system" fn(this: RawPtr, iid: &Guid, interface: *mut RawPtr) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr) -> u32,
+    unsafe extern "system" fn(this: RawPtr) -> u32,
+    unsafe extern "system" fn(this: RawPtr, count: *mut u32, values: *mut *mut Guid) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, value: *mut RawPtr) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, value: *mut i32) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, handler: RawPtr) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, result: *mut RawPtr) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, result: *mut T::Abi) -> ErrorCode,
+    PhantomData<T>,
+);
+
+#[repr(C)]
+struct InfoVtable(
+    unsafe extern "system" fn(this: RawPtr, iid: &Guid, interface: *mut RawPtr) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr) -> u32,
+    unsafe extern "system" fn(this: RawPtr) -> u32,
+    unsafe extern "system" fn(this: RawPtr, count: *mut u32, values: *mut *mut Guid) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, value: *mut RawPtr) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, value: *mut i32) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, result: *mut u32) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, result: *mut AsyncStatus) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, result: *mut ErrorCode) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr) -> ErrorCode,
+);
+
+impl AsyncKind for Action {
+    type Interface = IAsyncAction;
+    type Handler = AsyncActionCompletedHandler;
+    type Output = ();
+    type Vtable = ActionVtable;
+
+    const VTABLE: ActionVtable = ActionVtable(
+        StockAsync::<Self>::QueryInterface,
+        StockAsync::<Self>::AddRef,
+        StockAsync::<Self>::Release,
+        get_iids,
+        get_runtime_class_name,
+        get_trust_level,
+        StockAsync::<Self>::put_Completed,
+        StockAsync::<Self>::get_Completed,
+        StockAsync::<Self>::GetResults,
+    );
+
+    fn invoke(handler: &Self::Handler, sender: &Self::Interface, status: AsyncStatus) {
+        let _ = handler.invoke(sender, status);
+    }
+}
+
+impl<T: RuntimeType + 'static> AsyncKind for Operation<T> {
+    type Interface = IAsyncOperation<T>;
+    type Handler = AsyncOperationCompletedHandler<T>;
+    type Output = T::DefaultType;
+    type Vtable = OperationVtable<T>;
+
+    const VTABLE: OperationVtable<T> = OperationVtable(
+        StockAsync::<Self>::QueryInterface,
+        StockAsync::<Self>::AddRef,
+        StockAsync::<Self>::Release,
+        get_iids,
+        get_runtime_class_name,
+        get_trust_level,
+        StockAsync::<Self>::put_Completed,
+        StockAsync::<Self>::get_Completed,
+        StockAsync::<Self>::GetResults,
+        PhantomData,
+    );
+
+    fn invoke(handler: &Self::Handler, sender: &Self::Interface, status: AsyncStatus) {
+        let _ = handler.invoke(sender, status);
+    }
+}
+
+// The interface is first, followed by `IAsyncInfo`, so the `IAsyncInfo` methods find the object
+// one pointer back.
+#[repr(C)]
+struct StockAsync<K: AsyncKind> {
+    vtable: *const K::Vtable,
+    info_vtable: *const InfoVtable,
+    count: RefCount,
+    id: u32,
+    state: Mutex<State<K>>,
+}
+
+struct State<K: AsyncKind> {
+    status: AsyncStatus,
+    future: Option<Pin<Box<dyn Future<Output = Result<K::Output>> + Send>>>,
+    polling: bool,
+    woken: bool,
+    result: Option<K::Output>,
+    error: ErrorCode,
+    handler: Option<K::Handler>,
+    handler_set: bool,
+}
+
+#[allow(non_snake_case)]
+impl<K: AsyncKind> StockAsync<K> {
+    const INFO_VTABLE: InfoVtable = InfoVtable(
+        Self::InfoQueryInterface,
+        Self::InfoAddRef,
+        Self::InfoRelease,
+        get_iids,
+        get_runtime_class_name,
+        get_trust_level,
+        Self::get_Id,
+        Self::get_Status,
+        Self::get_ErrorCode,
+        Self::Cancel,
+        Self::Close,
+    );
+
+    const WAKER: RawWakerVTable = RawWakerVTable::new(
+        Self::waker_clone,
+        Self::waker_wake,
+        Self::waker_wake_by_ref,
+        Self::waker_drop,
+    );
+
+    fn new(future: Pin<Box<dyn Future<Output = Result<K::Output>> + Send>>) -> K::Interface {
+        static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+
+        let com = Self {
+            vtable: &K::VTABLE,
+            info_vtable: &Self::INFO_VTABLE,
+            count: RefCount::new(),
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            state: Mutex::new(State {
+                status: AsyncStatus::Started,
+                future: Some(future),
+                polling: false,
+                woken: false,
+                result: None,
+                error: ErrorCode::S_OK,
+                handler: None,
+                handler_set: false,
+            }),
+        };
+
+        unsafe {
+            let this = Box::into_raw(Box::new(com));
+            Self::poll(this);
+            std::mem::transmute_copy(&this)
+        }
+    }
+
+    // Polls the future unless another thread already is, in which case that thread polls it
+    // again once it's done so that the wake isn't lost. The caller must hold a reference.
+    unsafe fn poll(this: *mut Self) {
+        loop {
+            let mut future = {
+                let mut state = (*this).state.lock().unwrap();
+
+                if state.polling {
+                    state.woken = true;
+                    return;
+                }
+
+                match state.future.take() {
+                    Some(future) => {
+                        state.polling = true;
+                        future
+                    }
+                    None => return,
+                }
+            };
+
+            let waker = Self::waker(this);
+            let poll = future.as_mut().poll(&mut Context::from_waker(&waker));
+            drop(waker);
+
+            let mut state = (*this).state.lock().unwrap();
+            state.polling = false;
+
+            // Canceled while being polled, in which case the handler has already been called.
+            if state.status != AsyncStatus::Started {
+                drop(state);
+                drop(future);
+                return;
+            }
+
+            match poll {
+                Poll::Pending => {
+                    state.future = Some(future);
+
+                    if !std::mem::take(&mut state.woken) {
+                        return;
+                    }
+                }
+                Poll::Ready(result) => {
+                    match result {
+                        Ok(value) => {
+                            state.status = AsyncStatus::Completed;
+                            state.result = Some(value);
+                        }
+                        Err(error) => {
+                            state.status = AsyncStatus::Error;
+                            state.error = error.code();
+                        }
+                    }
+
+                    let status = state.status;
+                    let handler = state.handler.clone();
+                    drop(state);
+                    drop(future);
+                    Self::complete(this, handler, status);
+                    return;
+                }
+            }
+        }
+    }
+
+    unsafe fn complete(this: *mut Self, handler: Option<K::Handler>, status: AsyncStatus) {
+        if let Some(handler) = handler {
+            let this = this as RawPtr;
+            K::invoke(&handler, std::mem::transmute(&this), status);
+        }
+    }
+
+    // Each waker holds a reference to the object so that it outlives the future.
+    unsafe fn waker(this: *mut Self) -> Waker {
+        (*this).count.add_ref();
+        Waker::from_raw(RawWaker::new(this as _, &Self::WAKER))
+    }
+
+    unsafe fn waker_clone(this: *const ()) -> RawWaker {
+        (*(this as *mut Self)).count.add_ref();
+        RawWaker::new(this, &Self::WAKER)
+    }
+
+    unsafe fn waker_wake(this: *const ()) {
+        Self::poll(this as _);
+        Self::Release(this as _);
+    }
+
+    unsafe fn waker_wake_by_ref(this: *const ()) {
+        Self::poll(this as _);
+    }
+
+    unsafe fn waker_drop(this: *const ()) {
+        Self::Release(this as _);
+    }
+
+    unsafe extern "system" fn QueryInterface(
+        this: RawPtr,
+        iid: &Guid,
+        interface: *mut RawPtr,
+    ) -> ErrorCode {
+        let this = this as *mut Self;
+
+        *interface = if iid == &<K::Interface as Interface>::IID
+            || iid == &<IUnknown as Interface>::IID
+            || iid == &<Object as Interface>::IID
+        {
+            &mut (*this).vtable as *mut _ as _
+        } else if iid == &<IAsyncInfo as Interface>::IID {
+            &mut (*this).info_vtable as *mut _ as _
+        } else {
+            std::ptr::null_mut()
+        };
+
+        if (*interface).is_null() {
+            ErrorCode::E_NOINTERFACE
+        } else {
+            (*this).count.add_ref();
+            ErrorCode::S_OK
+        }
+    }
+
+    unsafe extern "system" fn AddRef(this: RawPtr) -> u32 {
+        let this = this as *mut Self;
+        (*this).count.add_ref()
+    }
+
+    unsafe extern "system" fn Release(this: RawPtr) -> u32 {
+        let this = this as *mut Self;
+        let remaining = (*this).count.release();
+
+        if remaining == 0 {
+            drop(Box::from_raw(this));
+        }
+
+        remaining
+    }
+
+    unsafe extern "system" fn put_Completed(this: RawPtr, handler: RawPtr) -> ErrorCode {
+        let this = this as *mut Self;
+        let handler: &Option<K::Handler> = std::mem::transmute(&handler);
+
+        let status = {
+            let mut state = (*this).state.lock().unwrap();
+
+            if state.handler_set {
+                return E_ILLEGAL_DELEGATE_ASSIGNMENT;
+            }
+
+            state.handler_set = true;
+            state.handler = handler.clone();
+            state.status
+        };
+
+        // A handler set after the operation has finished is called right away.
+        if status != AsyncStatus::Started {
+            Self::complete(this, handler.clone(), status);
+        }
+
+        ErrorCode::S_OK
+    }
+
+    unsafe extern "system" fn get_Completed(this: RawPtr, result: *mut RawPtr) -> ErrorCode {
+        let this = this as *mut Self;
+        let handler = (*this).state.lock().unwrap().handler.clone();
+        *result = std::mem::transmute_copy(&handler);
+        std::mem::forget(handler);
+        ErrorCode::S_OK
+    }
+
+    // Checks that the results may be read, returning the error to report otherwise.
+    fn check_results(state: &State<K>) -> ErrorCode {
+        match state.status {
+            AsyncStatus::Completed => ErrorCode::S_OK,
+            AsyncStatus::Error => state.error,
+            AsyncStatus::Canceled => ErrorCode::ERROR_CANCELLED,
+            _ => E_ILLEGAL_METHOD_CALL,
+        }
+    }
+
+    unsafe fn from_info(this: RawPtr) -> *mut Self {
+        (this as *mut RawPtr).sub(1) as _
+    }
+
+    unsafe extern "system" fn InfoQueryInterface(
+        this: RawPtr,
+        iid: &Guid,
+        interface: *mut RawPtr,
+    ) -> ErrorCode {
+        Self::QueryInterface(Self::from_info(this) as _, iid, interface)
+    }
+
+    unsafe extern "system" fn InfoAddRef(this: RawPtr) -> u32 {
+        Self::AddRef(Self::from_info(this) as _)
+    }
+
+    unsafe extern "system" fn InfoRelease(this: RawPtr) -> u32 {
+        Self::Release(Self::from_info(this) as _)
+    }
+
+    unsafe extern "system" fn get_Id(this: RawPtr, result: *mut u32) -> ErrorCode {
+        *result = (*Self::from_info(this)).id;
+        ErrorCode::S_OK
+    }
+
+    unsafe extern "system" fn get_Status(this: RawPtr, result: *mut AsyncStatus) -> ErrorCode {
+        *result = (*Self::from_info(this)).state.lock().unwrap().status;
+        ErrorCode::S_OK
+    }
+
+    unsafe extern "system" fn get_ErrorCode(this: RawPtr, result: *mut ErrorCode) -> ErrorCode {
+        *result = (*Self::from_info(this)).state.lock().unwrap().error;
+        ErrorCode::S_OK
+    }
+
+    // Drops the future, unless it's being polled, in which case it's dropped once the poll
+    // returns.
+    unsafe extern "system" fn Cancel(this: RawPtr) -> ErrorCode {
+        let this = Self::from_info(this);
+
+        let (future, handler) = {
+            let mut state = (*this).state.lock().unwrap();
+
+            if state.status != AsyncStatus::Started {
+                return ErrorCode::S_OK;
+            }
+
+            state.status = AsyncStatus::Canceled;
+            (state.future.take(), state.handler.clone())
+        };
+
+        drop(future);
+        Self::complete(this, handler, AsyncStatus::Canceled);
+        ErrorCode::S_OK
+    }
+
+    unsafe extern "system" fn Close(this: RawPtr) -> ErrorCode {
+        let this = Self::from_info(this);
+
+        if (*this).state.lock().unwrap().status == AsyncStatus::Started {
+            E_ILLEGAL_STATE_CHANGE
+        } else {
+            ErrorCode::S_OK
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+impl StockAsync<Action> {
+    unsafe extern "system" fn GetResults(this: RawPtr) -> ErrorCode {
+        let this = this as *mut Self;
+        Self::check_results(&(*this).state.lock().unwrap())
+    }
+}
+
+#[allow(non_snake_case)]
+impl<T: RuntimeType + 'static> StockAsync<Operation<T>> {
+    unsafe extern "system" fn GetResults(this: RawPtr, result: *mut T::Abi) -> ErrorCode {
+        let this = this as *mut Self;
+        let state = (*this).state.lock().unwrap();
+        let code = Self::check_results(&state);
+
+        if code == ErrorCode::S_OK {
+            if let Some(value) = &state.result {
+                *result = std::mem::transmute_copy(value);
+                std::mem::forget(value.clone());
+            }
+        }
+
+        code
+    }
+}
+
+unsafe extern "system" fn get_iids(
+    _: RawPtr,
+    count: *mut u32,
+    values: *mut *mut Guid,
+) -> ErrorCode {
+    *count = 0;
+    *values = std::ptr::null_mut();
+    ErrorCode::S_OK
+}
+
+unsafe extern "system" fn get_runtime_class_name(_: RawPtr, value: *mut RawPtr) -> ErrorCode {
+    *value = std::ptr::null_mut();
+    ErrorCode::S_OK
+}
+
+unsafe extern "system" fn get_trust_level(_: RawPtr, value: *mut i32) -> ErrorCode {
+    *value = 0;
+    ErrorCode::S_OK
+}
@@ -0,0 +1,15 @@
+use crate::{ErrorCode, Guid, BOOL, NTSTATUS};
+
+// Each of these is made up of integers without any padding, so every bit pattern is valid and
+// the all-zeroes value is the default.
+unsafe impl ::bytemuck::Zeroable for Guid {}
+unsafe impl ::bytemuck::Pod for Guid {}
+
+unsafe impl ::bytemuck::Zeroable for ErrorCode {}
+unsafe impl ::bytemuck::Pod for ErrorCode {}
+
+unsafe impl ::bytemuck::Zeroable for BOOL {}
+unsafe impl ::bytemuck::Pod for BOOL {}
+
+unsafe impl ::bytemuck::Zeroable for NTSTATUS {}
+unsafe impl ::bytemuck::Pod for NTSTATUS {}
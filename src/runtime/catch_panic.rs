@@ -0,0 +1,25 @@
+use crate::*;
+
+/// Calls an implementation of a COM or WinRT method, or of a delegate, on behalf of an ABI shim
+/// generated by the bindings or the `implement` macro, and should not be used directly.
+///
+/// Unwinding across the ABI is undefined behavior, so a panic is instead caught and returned as
+/// an `E_FAIL` error, with the panic's message as the error's message.
+pub fn catch_panic<F: FnOnce() -> ErrorCode>(upcall: F) -> ErrorCode {
+    const E_FAIL: ErrorCode = ErrorCode(0x8000_4005);
+
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(upcall)) {
+        Ok(code) => code,
+        Err(payload) => {
+            let message = if let Some(message) = payload.downcast_ref::<&str>() {
+                message
+            } else if let Some(message) = payload.downcast_ref::<String>() {
+                message.as_str()
+            } else {
+                "panic"
+            };
+
+            Error::new(E_FAIL, message).into()
+        }
+    }
+}
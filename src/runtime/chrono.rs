@@ -0,0 +1,57 @@
+use super::date_time::{EPOCH_DIFFERENCE, TICKS_PER_SECOND};
+use crate::foundation::{DateTime, TimeSpan};
+use crate::Param;
+
+// As with `std::time::Duration`, ticks beyond the range of an `i64` saturate rather than wrap.
+fn seconds_to_ticks(seconds: i64, nanos: i64) -> i64 {
+    seconds
+        .saturating_mul(TICKS_PER_SECOND)
+        .saturating_add(nanos / 100)
+}
+
+impl std::convert::From<::chrono::Duration> for TimeSpan {
+    fn from(value: ::chrono::Duration) -> Self {
+        Self {
+            duration: seconds_to_ticks(value.num_seconds(), value.subsec_nanos() as i64),
+        }
+    }
+}
+
+impl std::convert::From<TimeSpan> for ::chrono::Duration {
+    fn from(value: TimeSpan) -> Self {
+        ::chrono::Duration::seconds(value.duration / TICKS_PER_SECOND)
+            + ::chrono::Duration::nanoseconds((value.duration % TICKS_PER_SECOND) * 100)
+    }
+}
+
+impl std::convert::From<::chrono::DateTime<::chrono::Utc>> for DateTime {
+    fn from(value: ::chrono::DateTime<::chrono::Utc>) -> Self {
+        Self {
+            universal_time: seconds_to_ticks(
+                value.timestamp().saturating_add(EPOCH_DIFFERENCE),
+                value.timestamp_subsec_nanos() as i64,
+            ),
+        }
+    }
+}
+
+impl std::convert::From<DateTime> for ::chrono::DateTime<::chrono::Utc> {
+    fn from(value: DateTime) -> Self {
+        let seconds = value.universal_time.div_euclid(TICKS_PER_SECOND) - EPOCH_DIFFERENCE;
+        let nanos = value.universal_time.rem_euclid(TICKS_PER_SECOND) * 100;
+
+        ::chrono::TimeZone::timestamp_opt(&::chrono::Utc, seconds, nanos as u32).unwrap()
+    }
+}
+
+impl<'a> std::convert::Into<Param<'a, TimeSpan>> for ::chrono::Duration {
+    fn into(self) -> Param<'a, TimeSpan> {
+        Param::Owned(self.into())
+    }
+}
+
+impl<'a> std::convert::Into<Param<'a, DateTime>> for ::chrono::DateTime<::chrono::Utc> {
+    fn into(self) -> Param<'a, DateTime> {
+        Param::Owned(self.into())
+    }
+}
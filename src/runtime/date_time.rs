@@ -0,0 +1,66 @@
+use crate::foundation::DateTime;
+use crate::*;
+use std::convert::TryFrom;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// The number of seconds between the Windows epoch of 1601-01-01 and the Unix epoch of 1970-01-01.
+pub(crate) const EPOCH_DIFFERENCE: i64 = 11_644_473_600;
+
+// The number of 100-nanosecond intervals, or ticks, in a second.
+pub(crate) const TICKS_PER_SECOND: i64 = 10_000_000;
+
+// Ticks beyond the range of an `i64` saturate rather than wrap since that is some 29,000 years.
+pub(crate) fn duration_to_ticks(value: Duration) -> i64 {
+    let ticks = value.as_nanos() / 100;
+
+    if ticks > i64::MAX as u128 {
+        i64::MAX
+    } else {
+        ticks as i64
+    }
+}
+
+pub(crate) fn ticks_to_duration(ticks: u64) -> Duration {
+    let ticks_per_second = TICKS_PER_SECOND as u64;
+    Duration::new(
+        ticks / ticks_per_second,
+        (ticks % ticks_per_second) as u32 * 100,
+    )
+}
+
+impl std::convert::From<SystemTime> for DateTime {
+    fn from(value: SystemTime) -> Self {
+        let ticks = match value.duration_since(UNIX_EPOCH) {
+            Ok(after) => duration_to_ticks(after),
+            Err(before) => -duration_to_ticks(before.duration()),
+        };
+
+        Self {
+            universal_time: ticks.saturating_add(EPOCH_DIFFERENCE * TICKS_PER_SECOND),
+        }
+    }
+}
+
+// A `DateTime` may lie outside the range of a `SystemTime`, which on Windows cannot precede the
+// Windows epoch, in which case the conversion fails with `E_BOUNDS`.
+impl TryFrom<DateTime> for SystemTime {
+    type Error = Error;
+
+    fn try_from(value: DateTime) -> Result<Self> {
+        let ticks = value.universal_time as i128 - (EPOCH_DIFFERENCE * TICKS_PER_SECOND) as i128;
+        let duration = ticks_to_duration(ticks.unsigned_abs() as u64);
+
+        if ticks >= 0 {
+            UNIX_EPOCH.checked_add(duration)
+        } else {
+            UNIX_EPOCH.checked_sub(duration)
+        }
+        .ok_or_else(|| Error::fast_error(ErrorCode::E_BOUNDS))
+    }
+}
+
+impl<'a> std::convert::Into<Param<'a, DateTime>> for SystemTime {
+    fn into(self) -> Param<'a, DateTime> {
+        Param::Owned(self.into())
+    }
+}
@@ -0,0 +1,82 @@
+use crate::*;
+use foundation::EventRegistrationToken;
+use std::sync::Mutex;
+
+/// A list of delegates registered with an event, for use by classes implemented in Rust.
+///
+/// Handlers are added and removed with the tokens handed out by `add`, and are invoked
+/// together by `call`. Handlers whose remote objects have gone away are removed as they fail.
+pub struct EventSource<T> {
+    delegates: Mutex<Delegates<T>>,
+}
+
+struct Delegates<T> {
+    next: i64,
+    list: Vec<(i64, T)>,
+}
+
+impl<T: Interface + Clone> EventSource<T> {
+    pub fn new() -> Self {
+        Self {
+            delegates: Mutex::new(Delegates {
+                next: 1,
+                list: Vec::new(),
+            }),
+        }
+    }
+
+    /// Registers the delegate, returning the token that later removes it.
+    pub fn add(&self, delegate: &T) -> EventRegistrationToken {
+        let mut delegates = self.delegates.lock().unwrap();
+        let value = delegates.next;
+        delegates.next += 1;
+        delegates.list.push((value, delegate.clone()));
+        EventRegistrationToken { value }
+    }
+
+    /// Removes the delegate registered with the token. Unknown tokens are ignored.
+    pub fn remove(&self, token: EventRegistrationToken) {
+        self.delegates
+            .lock()
+            .unwrap()
+            .list
+            .retain(|(value, _)| *value != token.value);
+    }
+
+    /// Returns whether any delegates are registered.
+    pub fn is_empty(&self) -> bool {
+        self.delegates.lock().unwrap().list.is_empty()
+    }
+
+    /// Invokes each delegate registered when the call begins.
+    ///
+    /// The callback is called without holding the lock, so handlers may add or remove
+    /// delegates. A delegate that fails because its object is no longer reachable is removed,
+    /// and other errors are ignored so that one failing handler doesn't starve the rest.
+    pub fn call<F: FnMut(&T) -> Result<()>>(&self, mut callback: F) {
+        let list: Vec<(i64, T)> = self.delegates.lock().unwrap().list.clone();
+
+        for (value, delegate) in &list {
+            if let Err(error) = callback(delegate) {
+                if is_disconnected(error.code()) {
+                    self.remove(EventRegistrationToken { value: *value });
+                }
+            }
+        }
+    }
+}
+
+impl<T: Interface + Clone> Default for EventSource<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The errors returned when calling a delegate whose process or script context is gone.
+fn is_disconnected(code: ErrorCode) -> bool {
+    const RPC_E_DISCONNECTED: ErrorCode = ErrorCode(0x8001_0108);
+    const RPC_S_SERVER_UNAVAILABLE: ErrorCode = ErrorCode(0x8007_06BA);
+    const JSCRIPT_E_CANTEXECUTE: ErrorCode = ErrorCode(0x8902_0001);
+
+    code == RPC_E_DISCONNECTED || code == RPC_S_SERVER_UNAVAILABLE || code == JSCRIPT_E_CANTEXECUTE
+}
@@ -0,0 +1,299 @@
+use crate::foundation::collections::{IIterable, IIterator};
+use crate::*;
+
+// A stock implementation of `IIterable<T>` over a vector of values so that Rust collections can be
+// passed directly to WinRT methods expecting an `IIterable<T>` parameter.
+
+const E_BOUNDS: ErrorCode = ErrorCode(0x8000_000B);
+
+impl<T: RuntimeType + 'static> std::iter::FromIterator<T::DefaultType> for IIterable<T>
+where
+    T::DefaultType: Clone,
+{
+    fn from_iter<I: IntoIterator<Item = T::DefaultType>>(iter: I) -> Self {
+        StockIterable::<T>::new(iter.into_iter().collect())
+    }
+}
+
+impl<'a, T: RuntimeType + 'static> From<Vec<T::DefaultType>> for Param<'a, IIterable<T>>
+where
+    T::DefaultType: Clone,
+{
+    fn from(values: Vec<T::DefaultType>) -> Self {
+        Param::Owned(StockIterable::<T>::new(values))
+    }
+}
+
+impl<'a, T: RuntimeType + 'static> From<&[T::DefaultType]> for Param<'a, IIterable<T>>
+where
+    T::DefaultType: Clone,
+{
+    fn from(values: &[T::DefaultType]) -> Self {
+        Param::Owned(StockIterable::<T>::new(values.to_vec()))
+    }
+}
+
+#[repr(C)]
+struct IterableVtable<T: RuntimeType + 'static>(
+    unsafe extern "system" fn(this: RawPtr, iid: &Guid, interface: *mut RawPtr) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr) -> u32,
+    unsafe extern "system" fn(this: RawPtr) -> u32,
+    unsafe extern "system" fn(this: RawPtr, count: *mut u32, values: *mut *mut Guid) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, value: *mut RawPtr) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, value: *mut i32) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, result: *mut RawPtr) -> ErrorCode,
+    std::marker::PhantomData<T>,
+);
+
+#[repr(C)]
+struct IteratorVtable<T: RuntimeType + 'static>(
+    unsafe extern "system" fn(this: RawPtr, iid: &Guid, interface: *mut RawPtr) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr) -> u32,
+    unsafe extern "system" fn(this: RawPtr) -> u32,
+    unsafe extern "system" fn(this: RawPtr, count: *mut u32, values: *mut *mut Guid) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, value: *mut RawPtr) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, value: *mut i32) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, result: *mut T::Abi) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, result: *mut bool) -> ErrorCode,
+    unsafe extern "system" fn(this: RawPtr, result: *mut bool) -> ErrorCode,
+    unsafe extern "system" fn(
+        this: RawPtr,
+        capacity: u32,
+        items: *mut T::Abi,
+        result: *mut u32,
+    ) -> ErrorCode,
+    std::marker::PhantomData<T>,
+);
+
+#[repr(C)]
+struct StockIterable<T: RuntimeType + 'static> {
+    vtable: *const IterableVtable<T>,
+    count: RefCount,
+    values: Vec<T::DefaultType>,
+}
+
+#[repr(C)]
+struct StockIterator<T: RuntimeType + 'static> {
+    vtable: *const IteratorVtable<T>,
+    count: RefCount,
+    iterable: IIterable<T>,
+    current: usize,
+}
+
+#[allow(non_snake_case)]
+impl<T: RuntimeType + 'static> StockIterable<T>
+where
+    T::DefaultType: Clone,
+{
+    const VTABLE: IterableVtable<T> = IterableVtable(
+        Self::QueryInterface,
+        Self::AddRef,
+        Self::Release,
+        get_iids,
+        get_runtime_class_name,
+        get_trust_level,
+        Self::First,
+        std::marker::PhantomData,
+    );
+
+    fn new(values: Vec<T::DefaultType>) -> IIterable<T> {
+        let com = Self {
+            vtable: &Self::VTABLE,
+            count: RefCount::new(),
+            values,
+        };
+
+        unsafe { std::mem::transmute(Box::new(com)) }
+    }
+
+    unsafe extern "system" fn QueryInterface(
+        this: RawPtr,
+        iid: &Guid,
+        interface: *mut RawPtr,
+    ) -> ErrorCode {
+        let this = this as *mut Self;
+
+        *interface = if iid == &<IIterable<T> as Interface>::IID
+            || iid == &<IUnknown as Interface>::IID
+            || iid == &<Object as Interface>::IID
+        {
+            this as _
+        } else {
+            std::ptr::null_mut()
+        };
+
+        if (*interface).is_null() {
+            ErrorCode::E_NOINTERFACE
+        } else {
+            (*this).count.add_ref();
+            ErrorCode::S_OK
+        }
+    }
+
+    unsafe extern "system" fn AddRef(this: RawPtr) -> u32 {
+        let this = this as *mut Self;
+        (*this).count.add_ref()
+    }
+
+    unsafe extern "system" fn Release(this: RawPtr) -> u32 {
+        let this = this as *mut Self;
+        let remaining = (*this).count.release();
+
+        if remaining == 0 {
+            drop(Box::from_raw(this));
+        }
+
+        remaining
+    }
+
+    unsafe extern "system" fn First(this: RawPtr, result: *mut RawPtr) -> ErrorCode {
+        let iterable: &IIterable<T> = std::mem::transmute(&this);
+
+        let com = StockIterator::<T> {
+            vtable: &StockIterator::<T>::VTABLE,
+            count: RefCount::new(),
+            iterable: iterable.clone(),
+            current: 0,
+        };
+
+        *result = Box::into_raw(Box::new(com)) as _;
+        ErrorCode::S_OK
+    }
+}
+
+#[allow(non_snake_case)]
+impl<T: RuntimeType + 'static> StockIterator<T>
+where
+    T::DefaultType: Clone,
+{
+    const VTABLE: IteratorVtable<T> = IteratorVtable(
+        Self::QueryInterface,
+        Self::AddRef,
+        Self::Release,
+        get_iids,
+        get_runtime_class_name,
+        get_trust_level,
+        Self::Current,
+        Self::HasCurrent,
+        Self::MoveNext,
+        Self::GetMany,
+        std::marker::PhantomData,
+    );
+
+    unsafe fn values<'a>(this: *mut Self) -> &'a [T::DefaultType] {
+        let iterable = (*this).iterable.abi() as *const StockIterable<T>;
+        &(*iterable).values
+    }
+
+    unsafe extern "system" fn QueryInterface(
+        this: RawPtr,
+        iid: &Guid,
+        interface: *mut RawPtr,
+    ) -> ErrorCode {
+        let this = this as *mut Self;
+
+        *interface = if iid == &<IIterator<T> as Interface>::IID
+            || iid == &<IUnknown as Interface>::IID
+            || iid == &<Object as Interface>::IID
+        {
+            this as _
+        } else {
+            std::ptr::null_mut()
+        };
+
+        if (*interface).is_null() {
+            ErrorCode::E_NOINTERFACE
+        } else {
+            (*this).count.add_ref();
+            ErrorCode::S_OK
+        }
+    }
+
+    unsafe extern "system" fn AddRef(this: RawPtr) -> u32 {
+        let this = this as *mut Self;
+        (*this).count.add_ref()
+    }
+
+    unsafe extern "system" fn Release(this: RawPtr) -> u32 {
+        let this = this as *mut Self;
+        let remaining = (*this).count.release();
+
+        if remaining == 0 {
+            drop(Box::from_raw(this));
+        }
+
+        remaining
+    }
+
+    unsafe extern "system" fn Current(this: RawPtr, result: *mut T::Abi) -> ErrorCode {
+        let this = this as *mut Self;
+
+        match Self::values(this).get((*this).current) {
+            Some(value) => {
+                *result = std::mem::transmute_copy(value);
+                std::mem::forget(value.clone());
+                ErrorCode::S_OK
+            }
+            None => E_BOUNDS,
+        }
+    }
+
+    unsafe extern "system" fn HasCurrent(this: RawPtr, result: *mut bool) -> ErrorCode {
+        let this = this as *mut Self;
+        *result = (*this).current < Self::values(this).len();
+        ErrorCode::S_OK
+    }
+
+    unsafe extern "system" fn MoveNext(this: RawPtr, result: *mut bool) -> ErrorCode {
+        let this = this as *mut Self;
+        let len = Self::values(this).len();
+
+        if (*this).current < len {
+            (*this).current += 1;
+        }
+
+        *result = (*this).current < len;
+        ErrorCode::S_OK
+    }
+
+    unsafe extern "system" fn GetMany(
+        this: RawPtr,
+        capacity: u32,
+        items: *mut T::Abi,
+        result: *mut u32,
+    ) -> ErrorCode {
+        let this = this as *mut Self;
+        let values = Self::values(this);
+        let remaining = &values[(*this).current.min(values.len())..];
+        let count = remaining.len().min(capacity as usize);
+
+        for (index, value) in remaining[..count].iter().enumerate() {
+            *items.add(index) = std::mem::transmute_copy(value);
+            std::mem::forget(value.clone());
+        }
+
+        (*this).current += count;
+        *result = count as u32;
+        ErrorCode::S_OK
+    }
+}
+
+unsafe extern "system" fn get_iids(
+    _: RawPtr,
+    count: *mut u32,
+    values: *mut *mut Guid,
+) -> ErrorCode {
+    *count = 0;
+    *values = std::ptr::null_mut();
+    ErrorCode::S_OK
+}
+
+unsafe extern "system" fn get_runtime_class_name(_: RawPtr, value: *mut RawPtr) -> ErrorCode {
+    *value = std::ptr::null_mut();
+    ErrorCode::S_OK
+}
+
+unsafe extern "system" fn get_trust_level(_: RawPtr, value: *mut i32) -> ErrorCode {
+    *value = 0;
+    ErrorCode::S_OK
+}
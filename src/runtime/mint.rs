@@ -0,0 +1,107 @@
+use crate::foundation::numerics::{Matrix3x2, Matrix4x4, Quaternion, Vector2, Vector3, Vector4};
+
+// Conversions to and from the `mint` interoperability types, which math libraries such as `glam`
+// and `nalgebra` convert to and from in turn. The numerics matrices use row vectors, so they map
+// onto mint's row-major matrices with the translation in the last row.
+
+macro_rules! vector {
+    ($t:ident, $($field:ident),+) => {
+        impl ::std::convert::From<$t> for ::mint::$t<f32> {
+            fn from(value: $t) -> Self {
+                Self { $($field: value.$field),+ }
+            }
+        }
+        impl ::std::convert::From<::mint::$t<f32>> for $t {
+            fn from(value: ::mint::$t<f32>) -> Self {
+                Self { $($field: value.$field),+ }
+            }
+        }
+    };
+}
+
+vector!(Vector2, x, y);
+vector!(Vector3, x, y, z);
+vector!(Vector4, x, y, z, w);
+
+impl ::std::convert::From<Quaternion> for ::mint::Quaternion<f32> {
+    fn from(value: Quaternion) -> Self {
+        Self {
+            v: ::mint::Vector3 {
+                x: value.x,
+                y: value.y,
+                z: value.z,
+            },
+            s: value.w,
+        }
+    }
+}
+
+impl ::std::convert::From<::mint::Quaternion<f32>> for Quaternion {
+    fn from(value: ::mint::Quaternion<f32>) -> Self {
+        Self {
+            x: value.v.x,
+            y: value.v.y,
+            z: value.v.z,
+            w: value.s,
+        }
+    }
+}
+
+impl ::std::convert::From<Matrix3x2> for ::mint::RowMatrix3x2<f32> {
+    fn from(value: Matrix3x2) -> Self {
+        [
+            [value.m11, value.m12],
+            [value.m21, value.m22],
+            [value.m31, value.m32],
+        ]
+        .into()
+    }
+}
+
+impl ::std::convert::From<::mint::RowMatrix3x2<f32>> for Matrix3x2 {
+    fn from(value: ::mint::RowMatrix3x2<f32>) -> Self {
+        Self {
+            m11: value.x.x,
+            m12: value.x.y,
+            m21: value.y.x,
+            m22: value.y.y,
+            m31: value.z.x,
+            m32: value.z.y,
+        }
+    }
+}
+
+impl ::std::convert::From<Matrix4x4> for ::mint::RowMatrix4<f32> {
+    fn from(value: Matrix4x4) -> Self {
+        [
+            [value.m11, value.m12, value.m13, value.m14],
+            [value.m21, value.m22, value.m23, value.m24],
+            [value.m31, value.m32, value.m33, value.m34],
+            [value.m41, value.m42, value.m43, value.m44],
+        ]
+        .into()
+    }
+}
+
+impl ::std::convert::From<::mint::RowMatrix4<f32>> for Matrix4x4 {
+    fn from(value: ::mint::RowMatrix4<f32>) -> Self {
+        Self {
+            m11: value.x.x,
+            m12: value.x.y,
+            m13: value.x.z,
+            m14: value.x.w,
+            m21: value.y.x,
+            m22: value.y.y,
+            m23: value.y.z,
+            m24: value.y.w,
+            m31: value.z.x,
+            m32: value.z.y,
+            m33: value.z.z,
+            m34: value.z.w,
+            m41: value.w.x,
+            m42: value.w.y,
+            m43: value.w.z,
+            m44: value.w.w,
+        }
+    }
+}
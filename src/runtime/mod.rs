@@ -1,23 +1,54 @@
+mod activation_host;
+mod agile_reference;
 mod array;
+mod async_future;
+mod async_operation;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+mod catch_panic;
+#[cfg(feature = "chrono")]
+mod chrono;
 mod com;
+mod date_time;
 mod delay_load;
+mod event_source;
 mod factory_cache;
 mod guid;
 mod heap;
+mod iterable;
+#[cfg(feature = "mint")]
+mod mint;
 mod numerics;
+mod owned_handle;
+mod owned_memory;
 mod param;
+mod process;
+mod progress_stream;
 mod ref_count;
+#[cfg(feature = "serde")]
+mod serde;
 mod time_span;
+mod wait_handle;
 mod waiter;
 
+pub use activation_host::*;
+pub use agile_reference::*;
 pub use array::*;
+pub use async_future::*;
+pub use catch_panic::*;
 pub use com::*;
 pub use delay_load::*;
+pub use event_source::*;
 pub use factory_cache::*;
 pub use guid::*;
 pub use heap::*;
 pub use numerics::*;
+pub use owned_handle::*;
+pub use owned_memory::*;
 pub use param::*;
+pub use process::*;
+pub use progress_stream::*;
 pub use ref_count::*;
 pub use time_span::*;
+pub use wait_handle::*;
 pub use waiter::*;
@@ -1,7 +1,10 @@
-use windows::foundation::numerics::{Matrix3x2, Matrix4x4, Vector2, Vector3, Vector4};
+use windows::foundation::numerics::{Matrix3x2, Matrix4x4, Quaternion, Vector2, Vector3, Vector4};
 
 // Vector2
 impl Vector2 {
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
     pub fn zero() -> Self {
         Self { x: 0f32, y: 0f32 }
     }
@@ -194,6 +197,9 @@ impl ::std::ops::Mul<f32> for &Vector2 {
 
 // Vector3
 impl Vector3 {
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
     pub fn zero() -> Self {
         Self {
             x: 0f32,
@@ -415,6 +421,9 @@ impl ::std::ops::Mul<f32> for &Vector3 {
 
 // Vector4
 impl Vector4 {
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
     pub fn zero() -> Self {
         Self {
             x: 0f32,
@@ -812,6 +821,34 @@ impl ::std::ops::Mul<f32> for &Matrix3x2 {
 
 // Matrix4x4
 impl Matrix4x4 {
+    pub fn identity() -> Self {
+        Self {
+            m11: 1.0,
+            m12: 0.0,
+            m13: 0.0,
+            m14: 0.0,
+            m21: 0.0,
+            m22: 1.0,
+            m23: 0.0,
+            m24: 0.0,
+            m31: 0.0,
+            m32: 0.0,
+            m33: 1.0,
+            m34: 0.0,
+            m41: 0.0,
+            m42: 0.0,
+            m43: 0.0,
+            m44: 1.0,
+        }
+    }
+    pub fn translation(x: f32, y: f32, z: f32) -> Self {
+        Self {
+            m41: x,
+            m42: y,
+            m43: z,
+            ..Self::identity()
+        }
+    }
     fn impl_add(&self, rhs: &Self) -> Self {
         Self {
             m11: self.m11 + rhs.m11,
@@ -979,6 +1016,169 @@ impl ::std::ops::Mul<f32> for &Matrix4x4 {
     }
 }
 
+// Quaternion
+impl Quaternion {
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+    pub fn identity() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 1.0,
+        }
+    }
+    pub fn from_axis_angle(axis: &Vector3, angle: f32) -> Self {
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        Self {
+            x: axis.x * sin,
+            y: axis.y * sin,
+            z: axis.z * sin,
+            w: cos,
+        }
+    }
+    pub fn dot(&self, rhs: &Self) -> f32 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+    pub fn length_squared(&self) -> f32 {
+        self.dot(self)
+    }
+    pub fn length(&self) -> f32 {
+        self.length_squared().sqrt()
+    }
+    pub fn normalize(&self) -> Self {
+        self.impl_mul_f32(1.0 / self.length())
+    }
+    pub fn conjugate(&self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: self.w,
+        }
+    }
+
+    fn impl_add(&self, rhs: &Self) -> Self {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+            w: self.w + rhs.w,
+        }
+    }
+    fn impl_sub(&self, rhs: &Self) -> Self {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+            w: self.w - rhs.w,
+        }
+    }
+    // The Hamilton product, which applies the rotation `rhs` followed by `self`.
+    fn impl_mul(&self, rhs: &Self) -> Self {
+        Self {
+            x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        }
+    }
+    fn impl_mul_f32(&self, rhs: f32) -> Self {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+            w: self.w * rhs,
+        }
+    }
+}
+
+impl ::std::ops::Add<Quaternion> for Quaternion {
+    type Output = Quaternion;
+    fn add(self, rhs: Quaternion) -> Quaternion {
+        self.impl_add(&rhs)
+    }
+}
+impl ::std::ops::Add<&Quaternion> for Quaternion {
+    type Output = Quaternion;
+    fn add(self, rhs: &Quaternion) -> Quaternion {
+        self.impl_add(rhs)
+    }
+}
+impl ::std::ops::Add<Quaternion> for &Quaternion {
+    type Output = Quaternion;
+    fn add(self, rhs: Quaternion) -> Quaternion {
+        self.impl_add(&rhs)
+    }
+}
+impl ::std::ops::Add<&Quaternion> for &Quaternion {
+    type Output = Quaternion;
+    fn add(self, rhs: &Quaternion) -> Quaternion {
+        self.impl_add(rhs)
+    }
+}
+impl ::std::ops::Sub<Quaternion> for Quaternion {
+    type Output = Quaternion;
+    fn sub(self, rhs: Quaternion) -> Quaternion {
+        self.impl_sub(&rhs)
+    }
+}
+impl ::std::ops::Sub<&Quaternion> for Quaternion {
+    type Output = Quaternion;
+    fn sub(self, rhs: &Quaternion) -> Quaternion {
+        self.impl_sub(rhs)
+    }
+}
+impl ::std::ops::Sub<Quaternion> for &Quaternion {
+    type Output = Quaternion;
+    fn sub(self, rhs: Quaternion) -> Quaternion {
+        self.impl_sub(&rhs)
+    }
+}
+impl ::std::ops::Sub<&Quaternion> for &Quaternion {
+    type Output = Quaternion;
+    fn sub(self, rhs: &Quaternion) -> Quaternion {
+        self.impl_sub(rhs)
+    }
+}
+impl ::std::ops::Mul<Quaternion> for Quaternion {
+    type Output = Quaternion;
+    fn mul(self, rhs: Quaternion) -> Quaternion {
+        self.impl_mul(&rhs)
+    }
+}
+impl ::std::ops::Mul<&Quaternion> for Quaternion {
+    type Output = Quaternion;
+    fn mul(self, rhs: &Quaternion) -> Quaternion {
+        self.impl_mul(rhs)
+    }
+}
+impl ::std::ops::Mul<Quaternion> for &Quaternion {
+    type Output = Quaternion;
+    fn mul(self, rhs: Quaternion) -> Quaternion {
+        self.impl_mul(&rhs)
+    }
+}
+impl ::std::ops::Mul<&Quaternion> for &Quaternion {
+    type Output = Quaternion;
+    fn mul(self, rhs: &Quaternion) -> Quaternion {
+        self.impl_mul(rhs)
+    }
+}
+impl ::std::ops::Mul<f32> for Quaternion {
+    type Output = Quaternion;
+    fn mul(self, rhs: f32) -> Quaternion {
+        self.impl_mul_f32(rhs)
+    }
+}
+impl ::std::ops::Mul<f32> for &Quaternion {
+    type Output = Quaternion;
+    fn mul(self, rhs: f32) -> Quaternion {
+        self.impl_mul_f32(rhs)
+    }
+}
+
 #[link(name = "d2d1")]
 extern "system" {
     fn D2D1MakeRotateMatrix(angle: f32, center: Vector2, matrix: &mut Matrix3x2);
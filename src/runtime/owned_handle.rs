@@ -0,0 +1,70 @@
+/// A handle type that the metadata pairs with the function that frees it, such as `CloseHandle`
+/// for `HANDLE` or `RegCloseKey` for `HKEY`.
+///
+/// This is implemented by the generated handle types and lets them be wrapped in [`Owned`].
+pub trait Free: Copy + Default {
+    /// Frees the handle, doing nothing if it's null or invalid.
+    ///
+    /// # Safety
+    ///
+    /// The handle must be owned by the caller and not used once it's freed.
+    unsafe fn free(&mut self);
+}
+
+/// An owned handle that's freed when the value is dropped.
+///
+/// Pass `set_abi` to an API's out parameter, or wrap a handle an API returns with `new`, to take
+/// ownership of it.
+#[repr(transparent)]
+pub struct Owned<T: Free>(T);
+
+impl<T: Free> Owned<T> {
+    /// Takes ownership of a handle.
+    ///
+    /// # Safety
+    ///
+    /// The handle must be null, invalid, or owned by the caller and not freed elsewhere.
+    pub unsafe fn new(handle: T) -> Self {
+        Self(handle)
+    }
+
+    /// Frees any handle already owned and returns the out pointer to be filled in.
+    pub fn set_abi(&mut self) -> *mut T {
+        unsafe { self.0.free() };
+        self.0 = T::default();
+        &mut self.0
+    }
+
+    /// Releases ownership of the handle without freeing it.
+    pub fn into_raw(self) -> T {
+        let handle = self.0;
+        std::mem::forget(self);
+        handle
+    }
+}
+
+impl<T: Free> Default for Owned<T> {
+    fn default() -> Self {
+        Self(T::default())
+    }
+}
+
+impl<T: Free> std::ops::Deref for Owned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Free> Drop for Owned<T> {
+    fn drop(&mut self) {
+        unsafe { self.0.free() };
+    }
+}
+
+impl<T: Free + std::fmt::Debug> std::fmt::Debug for Owned<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Owned({:?})", self.0)
+    }
+}
@@ -0,0 +1,124 @@
+use crate::*;
+
+macro_rules! owned_memory {
+    ($(#[$doc:meta])* $name:ident, $free:path) => {
+        $(#[$doc])*
+        ///
+        /// Pass `set_abi` to an API's out parameter to take ownership of the memory it returns,
+        /// which is then freed when the value is dropped.
+        #[repr(transparent)]
+        pub struct $name<T>(*mut T);
+
+        impl<T> $name<T> {
+            /// Creates an empty value that doesn't own any memory.
+            pub fn new() -> Self {
+                Self(std::ptr::null_mut())
+            }
+
+            /// Takes ownership of memory allocated by the matching allocator.
+            ///
+            /// # Safety
+            ///
+            /// The pointer must be null or point to memory that may be freed by this type.
+            pub unsafe fn from_raw(ptr: *mut T) -> Self {
+                Self(ptr)
+            }
+
+            /// Returns whether any memory is owned.
+            pub fn is_null(&self) -> bool {
+                self.0.is_null()
+            }
+
+            /// Returns a pointer to the owned memory, which may be null.
+            pub fn as_ptr(&self) -> *const T {
+                self.0
+            }
+
+            /// Frees any memory already owned and returns the out pointer to be filled in.
+            pub fn set_abi(&mut self) -> *mut *mut T {
+                self.free();
+                &mut self.0
+            }
+
+            /// Releases ownership of the memory without freeing it.
+            pub fn into_raw(self) -> *mut T {
+                let ptr = self.0;
+                std::mem::forget(self);
+                ptr
+            }
+
+            /// Returns a reference to the value, or `None` if no memory is owned.
+            ///
+            /// # Safety
+            ///
+            /// Any memory owned must hold a valid `T`.
+            pub unsafe fn as_ref(&self) -> Option<&T> {
+                self.0.as_ref()
+            }
+
+            /// Returns the owned memory as a slice of `len` elements.
+            ///
+            /// # Safety
+            ///
+            /// Any memory owned must hold `len` valid elements, as reported by the API that
+            /// allocated it.
+            pub unsafe fn as_slice(&self, len: usize) -> &[T] {
+                if self.0.is_null() {
+                    &[]
+                } else {
+                    std::slice::from_raw_parts(self.0, len)
+                }
+            }
+
+            fn free(&mut self) {
+                if !self.0.is_null() {
+                    unsafe { $free(self.0 as _) };
+                    self.0 = std::ptr::null_mut();
+                }
+            }
+        }
+
+        impl<T> Default for $name<T> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<T> Drop for $name<T> {
+            fn drop(&mut self) {
+                self.free();
+            }
+        }
+
+        impl<T> std::fmt::Debug for $name<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}({:?})", stringify!($name), self.0)
+            }
+        }
+    };
+}
+
+owned_memory! {
+    /// Memory returned by an API that must be freed with `CoTaskMemFree`.
+    CoTaskMem, CoTaskMemFree
+}
+
+owned_memory! {
+    /// Memory returned by an API that must be freed with `LocalFree`.
+    LocalMem, LocalFree
+}
+
+owned_memory! {
+    /// Memory returned by an API that must be freed with `HeapFree` on the process heap.
+    HeapMem, heap_free
+}
+
+#[link(name = "ole32")]
+extern "system" {
+    fn CoTaskMemFree(ptr: RawPtr);
+}
+
+#[link(name = "kernel32")]
+extern "system" {
+    fn LocalFree(ptr: RawPtr) -> RawPtr;
+}
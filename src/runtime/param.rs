@@ -1,22 +1,47 @@
+use crate::foundation::IReference;
 use crate::*;
 
 // A WinRT method parameter used to accept either a reference or value. `Param` is used by the
 // generated bindings and should not generally be used directly.
+//
+// A reference is borrowed rather than copied wherever the referenced type shares the parameter's
+// binary representation. Only those borrowed parameters are tied to the value they were formed
+// from by the `'a` lifetime, as the doc test below checks; owned parameters hold copies and the
+// ABI value returned by `abi` isn't tied to the parameter at all.
+/// ```compile_fail,E0515
+/// fn dangling(object: windows::Object) -> windows::Param<'static, windows::Object> {
+///     windows::Param::from(&object)
+/// }
+/// ```
 #[doc(hidden)]
 pub enum Param<'a, T: Abi> {
     Borrowed(&'a T),
     Owned(T),
     None,
+    // A value that couldn't be converted, such as one that failed to be boxed, whose error is
+    // returned by the method it's passed to.
+    Failed(Error),
 }
 
 impl<'a, T: Abi> Param<'a, T> {
+    // Returns the error of a value that couldn't be converted, which the generated bindings check
+    // for before making the call.
+    pub fn ok(self) -> Result<Self> {
+        match self {
+            Param::Failed(error) => Err(error),
+            param => Ok(param),
+        }
+    }
+
+    // The returned ABI value is only valid for as long as the `Param` itself, which is why the
+    // generated bindings only ever call this within the expression that makes the call.
     pub fn abi(&mut self) -> T::Abi {
         match self {
             Param::Borrowed(value) => value.abi(),
             Param::Owned(value) => value.abi(),
             // It is always safe to form an `Abi` type's binary representation from an all-zero
             // byte-pattern as this represents the null or default state for every type.
-            Param::None => unsafe { std::mem::zeroed() },
+            Param::None | Param::Failed(_) => unsafe { std::mem::zeroed() },
         }
     }
 }
@@ -62,3 +87,57 @@ impl<'a> From<String> for Param<'a, HString> {
         Param::Owned(value.into())
     }
 }
+
+impl<'a> From<&'a String> for Param<'a, HString> {
+    fn from(value: &'a String) -> Self {
+        Param::Owned(value.as_str().into())
+    }
+}
+
+// Optional WinRT values are passed as `IReference<T>` so these allow a value, or `None`, to be
+// passed directly and boxed on the caller's behalf.
+macro_rules! reference_param {
+    ($(($t:ty, $m:ident)),+) => {
+        $(impl<'a> From<$t> for Param<'a, IReference<$t>> {
+            fn from(value: $t) -> Self {
+                box_reference(foundation::PropertyValue::$m(value))
+            }
+        }
+        impl<'a> From<Option<$t>> for Param<'a, IReference<$t>> {
+            fn from(value: Option<$t>) -> Self {
+                match value {
+                    Some(value) => value.into(),
+                    None => Param::None,
+                }
+            }
+        })*
+    };
+}
+
+reference_param! {
+    (bool, create_boolean),
+    (u8, create_uint8),
+    (i16, create_int16),
+    (u16, create_uint16),
+    (i32, create_int32),
+    (u32, create_uint32),
+    (i64, create_int64),
+    (u64, create_uint64),
+    (f32, create_single),
+    (f64, create_double),
+    (HString, create_string),
+    (Guid, create_guid)
+}
+
+impl<'a> From<&str> for Param<'a, IReference<HString>> {
+    fn from(value: &str) -> Self {
+        box_reference(foundation::PropertyValue::create_string(value))
+    }
+}
+
+fn box_reference<'a, T: RuntimeType>(object: Result<Object>) -> Param<'a, IReference<T>> {
+    match object.and_then(|object| object.cast()) {
+        Ok(value) => Param::Owned(value),
+        Err(error) => Param::Failed(error),
+    }
+}
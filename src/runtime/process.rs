@@ -0,0 +1,582 @@
+use crate::*;
+use std::collections::BTreeMap;
+
+/// Builds the command line, environment, and startup information for a new process and starts
+/// it with `CreateProcessW`.
+///
+/// Arguments are quoted the way the C runtime parses them, so they arrive intact however many
+/// spaces, quotes, or backslashes they contain.
+///
+/// ```rust,no_run
+/// # fn main() -> windows::Result<()> {
+/// let process = windows::ProcessBuilder::new("cmd.exe")
+///     .args(&["/c", "echo", "hello world"])
+///     .spawn()?;
+///
+/// assert!(process.wait()? == 0);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ProcessBuilder {
+    program: String,
+    args: Vec<String>,
+    env: Option<BTreeMap<String, (String, String)>>,
+    current_dir: Option<String>,
+    handles: Option<Vec<RawPtr>>,
+    std_handles: Option<[RawPtr; 3]>,
+    pseudo_console: Option<RawPtr>,
+    job: Option<RawPtr>,
+    flags: u32,
+    suspended: bool,
+}
+
+impl ProcessBuilder {
+    /// Starts building a process that runs the given program, which is found the same way the
+    /// command interpreter finds it if no path is given.
+    pub fn new(program: &str) -> Self {
+        Self {
+            program: program.to_string(),
+            args: Vec::new(),
+            env: None,
+            current_dir: None,
+            handles: None,
+            std_handles: None,
+            pseudo_console: None,
+            job: None,
+            flags: 0,
+            suspended: false,
+        }
+    }
+
+    /// Adds an argument to the command line.
+    pub fn arg(mut self, arg: &str) -> Self {
+        self.args.push(arg.to_string());
+        self
+    }
+
+    /// Adds several arguments to the command line.
+    pub fn args<I: IntoIterator<Item = S>, S: AsRef<str>>(mut self, args: I) -> Self {
+        self.args
+            .extend(args.into_iter().map(|arg| arg.as_ref().to_string()));
+        self
+    }
+
+    /// Sets an environment variable for the new process, which otherwise inherits the
+    /// environment of the current process.
+    pub fn env(mut self, key: &str, value: &str) -> Self {
+        self.env_mut()
+            .insert(key.to_uppercase(), (key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Removes an environment variable inherited from the current process.
+    pub fn env_remove(mut self, key: &str) -> Self {
+        self.env_mut().remove(&key.to_uppercase());
+        self
+    }
+
+    /// Starts the new process with an empty environment, aside from any variables set
+    /// afterwards.
+    pub fn env_clear(mut self) -> Self {
+        self.env = Some(BTreeMap::new());
+        self
+    }
+
+    /// Sets the working directory of the new process.
+    pub fn current_dir(mut self, dir: &str) -> Self {
+        self.current_dir = Some(dir.to_string());
+        self
+    }
+
+    /// Lets the new process inherit the given handles, and only those, with
+    /// `PROC_THREAD_ATTRIBUTE_HANDLE_LIST`. The handles must themselves be inheritable and
+    /// include any standard handles that are set.
+    pub fn inherit_handles(mut self, handles: &[RawPtr]) -> Self {
+        self.handles = Some(handles.to_vec());
+        self
+    }
+
+    /// Sets the standard input, output, and error handles of the new process.
+    pub fn std_handles(mut self, input: RawPtr, output: RawPtr, error: RawPtr) -> Self {
+        self.std_handles = Some([input, output, error]);
+        self
+    }
+
+    /// Attaches the new process to a pseudo console created with `CreatePseudoConsole`.
+    pub fn pseudo_console(mut self, console: RawPtr) -> Self {
+        self.pseudo_console = Some(console);
+        self
+    }
+
+    /// Assigns the new process to a job object before it starts running.
+    pub fn job(mut self, job: RawPtr) -> Self {
+        self.job = Some(job);
+        self
+    }
+
+    /// Adds process creation flags, such as `CREATE_NO_WINDOW`.
+    pub fn creation_flags(mut self, flags: u32) -> Self {
+        self.flags |= flags;
+        self
+    }
+
+    /// Leaves the new process's main thread suspended until `Process::resume` is called.
+    pub fn suspended(mut self, suspended: bool) -> Self {
+        self.suspended = suspended;
+        self
+    }
+
+    /// Returns the command line the process is started with.
+    pub fn command_line(&self) -> String {
+        let mut line = String::new();
+        quote(&self.program, &mut line);
+
+        for arg in &self.args {
+            line.push(' ');
+            quote(arg, &mut line);
+        }
+
+        line
+    }
+
+    /// Starts the process.
+    pub fn spawn(&self) -> Result<Process> {
+        const CREATE_SUSPENDED: u32 = 0x0000_0004;
+        const CREATE_UNICODE_ENVIRONMENT: u32 = 0x0000_0400;
+        const EXTENDED_STARTUPINFO_PRESENT: u32 = 0x0008_0000;
+        const STARTF_USESTDHANDLES: u32 = 0x0000_0100;
+        const PROC_THREAD_ATTRIBUTE_HANDLE_LIST: usize = 0x0002_0002;
+        const PROC_THREAD_ATTRIBUTE_PSEUDOCONSOLE: usize = 0x0002_0016;
+
+        let mut command_line = wide(&self.command_line());
+        let current_dir = self.current_dir.as_deref().map(wide);
+        let environment = self.env.as_ref().map(environment_block);
+
+        let mut attributes = AttributeList::new(
+            self.handles.is_some() as u32 + self.pseudo_console.is_some() as u32,
+        )?;
+
+        if let Some(handles) = &self.handles {
+            attributes.update(
+                PROC_THREAD_ATTRIBUTE_HANDLE_LIST,
+                handles.as_ptr() as _,
+                handles.len() * std::mem::size_of::<RawPtr>(),
+            )?;
+        }
+
+        // The pseudo console handle is passed as the value itself rather than a pointer to it.
+        if let Some(console) = self.pseudo_console {
+            attributes.update(
+                PROC_THREAD_ATTRIBUTE_PSEUDOCONSOLE,
+                console,
+                std::mem::size_of::<RawPtr>(),
+            )?;
+        }
+
+        let mut startup = STARTUPINFOEXW::default();
+        startup.startup.cb = std::mem::size_of::<STARTUPINFOEXW>() as u32;
+        startup.attributes = attributes.as_ptr();
+
+        if let Some([input, output, error]) = self.std_handles {
+            startup.startup.flags |= STARTF_USESTDHANDLES;
+            startup.startup.std_input = input;
+            startup.startup.std_output = output;
+            startup.startup.std_error = error;
+        }
+
+        let mut flags = self.flags | EXTENDED_STARTUPINFO_PRESENT | CREATE_UNICODE_ENVIRONMENT;
+
+        if self.suspended || self.job.is_some() {
+            flags |= CREATE_SUSPENDED;
+        }
+
+        let mut info = PROCESS_INFORMATION::default();
+
+        unsafe {
+            if CreateProcessW(
+                std::ptr::null(),
+                command_line.as_mut_ptr(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                (self.handles.is_some() || self.std_handles.is_some()) as i32,
+                flags,
+                environment
+                    .as_ref()
+                    .map_or(std::ptr::null_mut(), |block| block.as_ptr() as _),
+                current_dir
+                    .as_ref()
+                    .map_or(std::ptr::null(), |dir| dir.as_ptr()),
+                &startup,
+                &mut info,
+            ) == 0
+            {
+                return Err(ErrorCode::from_thread().into());
+            }
+        }
+
+        let process = Process {
+            process: info.process,
+            thread: info.thread,
+            id: info.process_id,
+        };
+
+        if let Some(job) = self.job {
+            unsafe {
+                if AssignProcessToJobObject(job, process.process) == 0 {
+                    let error = ErrorCode::from_thread();
+                    TerminateProcess(process.process, 1);
+                    return Err(error.into());
+                }
+            }
+
+            if !self.suspended {
+                process.resume()?;
+            }
+        }
+
+        Ok(process)
+    }
+
+    fn env_mut(&mut self) -> &mut BTreeMap<String, (String, String)> {
+        self.env.get_or_insert_with(|| {
+            std::env::vars_os()
+                .filter_map(|(key, value)| {
+                    Some((key.into_string().ok()?, value.into_string().ok()?))
+                })
+                .map(|(key, value)| (key.to_uppercase(), (key, value)))
+                .collect()
+        })
+    }
+}
+
+/// A process started by a `ProcessBuilder`, which closes its process and thread handles when
+/// dropped without terminating the process.
+#[derive(Debug)]
+pub struct Process {
+    process: RawPtr,
+    thread: RawPtr,
+    id: u32,
+}
+
+impl Process {
+    /// The process identifier.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// The process handle, which remains owned by the `Process`.
+    pub fn handle(&self) -> RawPtr {
+        self.process
+    }
+
+    /// The handle of the process's main thread, which remains owned by the `Process`.
+    pub fn thread_handle(&self) -> RawPtr {
+        self.thread
+    }
+
+    /// Resumes the main thread of a process that was started suspended.
+    pub fn resume(&self) -> Result<()> {
+        if unsafe { ResumeThread(self.thread) } == u32::MAX {
+            Err(ErrorCode::from_thread().into())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Blocks until the process exits, returning its exit code.
+    pub fn wait(&self) -> Result<u32> {
+        const INFINITE: u32 = 0xFFFF_FFFF;
+        const WAIT_FAILED: u32 = 0xFFFF_FFFF;
+
+        if unsafe { WaitForSingleObject(self.process, INFINITE) } == WAIT_FAILED {
+            return Err(ErrorCode::from_thread().into());
+        }
+
+        self.exit_code()
+            .map(|code| code.expect("process has exited"))
+    }
+
+    /// Returns the exit code of the process, or `None` if it's still running.
+    pub fn exit_code(&self) -> Result<Option<u32>> {
+        const STILL_ACTIVE: u32 = 259;
+        const WAIT_OBJECT_0: u32 = 0;
+
+        let mut code = 0;
+
+        if unsafe { GetExitCodeProcess(self.process, &mut code) } == 0 {
+            return Err(ErrorCode::from_thread().into());
+        }
+
+        // A process may also exit with the code that means it's still running.
+        if code == STILL_ACTIVE && unsafe { WaitForSingleObject(self.process, 0) } != WAIT_OBJECT_0
+        {
+            Ok(None)
+        } else {
+            Ok(Some(code))
+        }
+    }
+
+    /// Terminates the process with the given exit code.
+    pub fn kill(&self, code: u32) -> Result<()> {
+        if unsafe { TerminateProcess(self.process, code) } == 0 {
+            Err(ErrorCode::from_thread().into())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Drop for Process {
+    fn drop(&mut self) {
+        unsafe {
+            CloseHandle(self.thread);
+            CloseHandle(self.process);
+        }
+    }
+}
+
+unsafe impl Send for Process {}
+unsafe impl Sync for Process {}
+
+// Quotes an argument so that `CommandLineToArgvW` and the C runtime parse it back unchanged.
+// Backslashes are only special when they precede a quote, in which case they're doubled.
+fn quote(arg: &str, line: &mut String) {
+    if !arg.is_empty() && !arg.contains(&[' ', '\t', '\n', '\u{b}', '"'][..]) {
+        line.push_str(arg);
+        return;
+    }
+
+    line.push('"');
+    let mut backslashes = 0;
+
+    for c in arg.chars() {
+        match c {
+            '\\' => backslashes += 1,
+            '"' => {
+                line.extend(std::iter::repeat('\\').take(backslashes * 2 + 1));
+                line.push('"');
+                backslashes = 0;
+            }
+            _ => {
+                line.extend(std::iter::repeat('\\').take(backslashes));
+                line.push(c);
+                backslashes = 0;
+            }
+        }
+    }
+
+    line.extend(std::iter::repeat('\\').take(backslashes * 2));
+    line.push('"');
+}
+
+fn wide(value: &str) -> Vec<u16> {
+    value.encode_utf16().chain(std::iter::once(0)).collect()
+}
+
+// A sequence of null-terminated `key=value` strings ending with an empty string.
+fn environment_block(env: &BTreeMap<String, (String, String)>) -> Vec<u16> {
+    let mut block: Vec<u16> = Vec::new();
+
+    for (key, value) in env.values() {
+        block.extend(key.encode_utf16());
+        block.push('=' as u16);
+        block.extend(value.encode_utf16());
+        block.push(0);
+    }
+
+    if block.is_empty() {
+        block.push(0);
+    }
+
+    block.push(0);
+    block
+}
+
+struct AttributeList(Vec<usize>);
+
+impl AttributeList {
+    fn new(count: u32) -> Result<Self> {
+        if count == 0 {
+            return Ok(Self(Vec::new()));
+        }
+
+        let mut size = 0;
+
+        unsafe {
+            InitializeProcThreadAttributeList(std::ptr::null_mut(), count, 0, &mut size);
+            let mut list = vec![
+                0usize;
+                (size + std::mem::size_of::<usize>() - 1)
+                    / std::mem::size_of::<usize>()
+            ];
+
+            if InitializeProcThreadAttributeList(list.as_mut_ptr() as _, count, 0, &mut size) == 0 {
+                return Err(ErrorCode::from_thread().into());
+            }
+
+            Ok(Self(list))
+        }
+    }
+
+    fn update(&mut self, attribute: usize, value: RawPtr, size: usize) -> Result<()> {
+        unsafe {
+            if UpdateProcThreadAttribute(
+                self.as_ptr(),
+                0,
+                attribute,
+                value,
+                size,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            ) == 0
+            {
+                return Err(ErrorCode::from_thread().into());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn as_ptr(&mut self) -> RawPtr {
+        if self.0.is_empty() {
+            std::ptr::null_mut()
+        } else {
+            self.0.as_mut_ptr() as _
+        }
+    }
+}
+
+impl Drop for AttributeList {
+    fn drop(&mut self) {
+        if !self.0.is_empty() {
+            unsafe { DeleteProcThreadAttributeList(self.0.as_mut_ptr() as _) }
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct STARTUPINFOW {
+    cb: u32,
+    reserved: *mut u16,
+    desktop: *mut u16,
+    title: *mut u16,
+    x: u32,
+    y: u32,
+    x_size: u32,
+    y_size: u32,
+    x_count_chars: u32,
+    y_count_chars: u32,
+    fill_attribute: u32,
+    flags: u32,
+    show_window: u16,
+    reserved2_size: u16,
+    reserved2: *mut u8,
+    std_input: RawPtr,
+    std_output: RawPtr,
+    std_error: RawPtr,
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct STARTUPINFOEXW {
+    startup: STARTUPINFOW,
+    attributes: RawPtr,
+}
+
+impl Default for STARTUPINFOEXW {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct PROCESS_INFORMATION {
+    process: RawPtr,
+    thread: RawPtr,
+    process_id: u32,
+    thread_id: u32,
+}
+
+impl Default for PROCESS_INFORMATION {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+#[link(name = "kernel32")]
+extern "system" {
+    fn CreateProcessW(
+        application_name: *const u16,
+        command_line: *mut u16,
+        process_attributes: RawPtr,
+        thread_attributes: RawPtr,
+        inherit_handles: i32,
+        creation_flags: u32,
+        environment: RawPtr,
+        current_directory: *const u16,
+        startup_info: *const STARTUPINFOEXW,
+        process_information: *mut PROCESS_INFORMATION,
+    ) -> i32;
+    fn InitializeProcThreadAttributeList(
+        list: RawPtr,
+        count: u32,
+        flags: u32,
+        size: *mut usize,
+    ) -> i32;
+    fn UpdateProcThreadAttribute(
+        list: RawPtr,
+        flags: u32,
+        attribute: usize,
+        value: RawPtr,
+        size: usize,
+        previous_value: RawPtr,
+        return_size: *mut usize,
+    ) -> i32;
+    fn DeleteProcThreadAttributeList(list: RawPtr);
+    fn AssignProcessToJobObject(job: RawPtr, process: RawPtr) -> i32;
+    fn ResumeThread(thread: RawPtr) -> u32;
+    fn TerminateProcess(process: RawPtr, code: u32) -> i32;
+    fn GetExitCodeProcess(process: RawPtr, code: *mut u32) -> i32;
+    fn WaitForSingleObject(handle: RawPtr, milliseconds: u32) -> u32;
+    fn CloseHandle(handle: RawPtr) -> i32;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command_line(args: &[&str]) -> String {
+        ProcessBuilder::new("app.exe").args(args).command_line()
+    }
+
+    #[test]
+    fn quoting() {
+        assert!(command_line(&[]) == "app.exe");
+        assert!(command_line(&["a", "b"]) == "app.exe a b");
+        assert!(command_line(&[""]) == r#"app.exe """#);
+        assert!(command_line(&["hello world"]) == r#"app.exe "hello world""#);
+        assert!(command_line(&[r#"say "hi""#]) == r#"app.exe "say \"hi\"""#);
+        assert!(command_line(&[r"C:\dir\"]) == r"app.exe C:\dir\");
+        assert!(command_line(&[r"C:\my dir\"]) == r#"app.exe "C:\my dir\\""#);
+        assert!(command_line(&[r#"a\"b c"#]) == r#"app.exe "a\\\"b c""#);
+    }
+
+    #[test]
+    fn environment() {
+        let builder = ProcessBuilder::new("app.exe")
+            .env_clear()
+            .env("Path", "C:\\")
+            .env("PATH", "D:\\")
+            .env("other", "1")
+            .env_remove("OTHER");
+
+        let block = environment_block(builder.env.as_ref().unwrap());
+        assert!(String::from_utf16(&block).unwrap() == "PATH=D:\\\0\0");
+
+        let block = environment_block(&BTreeMap::new());
+        assert!(block == [0, 0]);
+    }
+}
@@ -0,0 +1,163 @@
+use crate::*;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, Weak};
+use std::task::{Context, Poll, Waker};
+
+/// The progress reported by an async action or operation, as returned by its `progress_stream`
+/// method.
+///
+/// Reports are queued as the operation makes them and are read in order with `next`, or as a
+/// `futures::Stream` with the `futures-core` feature. The stream ends once the operation has
+/// completed, failed, or been canceled and the reports made before then have been read. The
+/// operation may still be waited on with `get` or `.await`, whether or not the stream has been
+/// read to its end.
+///
+/// The operation's progress handler only holds the stream weakly, so reports stop being queued
+/// once the stream is dropped.
+pub struct ProgressStream<T: RuntimeType> {
+    shared: Arc<Mutex<Progress<T>>>,
+}
+
+struct Progress<T: RuntimeType> {
+    reports: VecDeque<T::DefaultType>,
+    completed: bool,
+    waker: Option<Waker>,
+}
+
+/// Queues the reports of a `ProgressStream`, used by the generated bindings and should not be
+/// used directly.
+#[doc(hidden)]
+pub struct ProgressSink<T: RuntimeType>(Weak<Mutex<Progress<T>>>);
+
+impl<T: RuntimeType> ProgressStream<T> {
+    #[doc(hidden)]
+    pub fn new() -> (Self, ProgressSink<T>) {
+        let shared = Arc::new(Mutex::new(Progress {
+            reports: VecDeque::new(),
+            completed: false,
+            waker: None,
+        }));
+
+        let sink = ProgressSink(Arc::downgrade(&shared));
+        (Self { shared }, sink)
+    }
+
+    /// Returns the next report, waiting for one if none are queued, or `None` once the
+    /// operation is no longer running.
+    pub fn next(&mut self) -> ProgressNext<'_, T> {
+        ProgressNext(self)
+    }
+
+    /// Polls for the next report, registering the context's waker to be woken when another
+    /// report is made or the operation finishes.
+    pub fn poll_next(&mut self, context: &mut Context) -> Poll<Option<T::DefaultType>> {
+        let mut progress = self.shared.lock().unwrap();
+
+        if let Some(report) = progress.reports.pop_front() {
+            Poll::Ready(Some(report))
+        } else if progress.completed {
+            Poll::Ready(None)
+        } else {
+            progress.waker = Some(context.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+// The sink is only handed to the operation's progress and completion handlers, which the
+// operation calls on whichever thread it reports progress or completes on, along with the
+// reports themselves.
+unsafe impl<T: RuntimeType> Send for ProgressSink<T> {}
+
+impl<T: RuntimeType> ProgressSink<T> {
+    pub fn report(&self, report: &T::DefaultType) {
+        self.update(|progress| progress.reports.push_back(report.clone()));
+    }
+
+    pub fn complete(&self) {
+        self.update(|progress| progress.completed = true);
+    }
+
+    // Does nothing once the stream has been dropped.
+    fn update<F: FnOnce(&mut Progress<T>)>(&self, update: F) {
+        if let Some(shared) = self.0.upgrade() {
+            let waker = {
+                let mut progress = shared.lock().unwrap();
+                update(&mut progress);
+                progress.waker.take()
+            };
+
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl<T: RuntimeType> Clone for ProgressSink<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// The future returned by `ProgressStream::next`.
+pub struct ProgressNext<'a, T: RuntimeType>(&'a mut ProgressStream<T>);
+
+impl<'a, T: RuntimeType> Future for ProgressNext<'a, T> {
+    type Output = Option<T::DefaultType>;
+
+    fn poll(mut self: Pin<&mut Self>, context: &mut Context) -> Poll<Self::Output> {
+        self.0.poll_next(context)
+    }
+}
+
+#[cfg(feature = "futures-core")]
+impl<T: RuntimeType> futures_core::Stream for ProgressStream<T> {
+    type Item = T::DefaultType;
+
+    fn poll_next(self: Pin<&mut Self>, context: &mut Context) -> Poll<Option<Self::Item>> {
+        self.get_mut().poll_next(context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_stream() {
+        let (mut stream, sink) = ProgressStream::<u32>::new();
+        let waker = noop_waker();
+        let mut context = Context::from_waker(&waker);
+
+        assert!(stream.poll_next(&mut context).is_pending());
+
+        sink.report(&1);
+        sink.report(&2);
+        sink.complete();
+        sink.report(&3);
+
+        assert!(stream.poll_next(&mut context) == Poll::Ready(Some(1)));
+        assert!(stream.poll_next(&mut context) == Poll::Ready(Some(2)));
+        assert!(stream.poll_next(&mut context) == Poll::Ready(Some(3)));
+        assert!(stream.poll_next(&mut context) == Poll::Ready(None));
+
+        // Reports made after the stream is dropped are discarded.
+        drop(stream);
+        sink.report(&4);
+    }
+
+    fn noop_waker() -> Waker {
+        use std::task::{RawWaker, RawWakerVTable};
+
+        fn clone(_: *const ()) -> RawWaker {
+            RawWaker::new(std::ptr::null(), &VTABLE)
+        }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+        unsafe { Waker::from_raw(clone(std::ptr::null())) }
+    }
+}
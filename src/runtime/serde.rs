@@ -0,0 +1,67 @@
+use crate::{ErrorCode, Guid, BOOL, NTSTATUS};
+use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+// GUIDs are written in their registry format, without braces, so that they read the same in
+// JSON as they do everywhere else.
+impl Serialize for Guid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:?}", self))
+    }
+}
+
+impl<'de> Deserialize<'de> for Guid {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = <&str>::deserialize(deserializer)?;
+
+        let valid = value.len() == 36
+            && value.bytes().enumerate().all(|(index, byte)| match index {
+                8 | 13 | 18 | 23 => byte == b'-',
+                _ => byte.is_ascii_hexdigit(),
+            });
+
+        if valid {
+            Ok(value.into())
+        } else {
+            Err(de::Error::invalid_value(
+                de::Unexpected::Str(value),
+                &"a GUID such as 00000000-0000-0000-C000-000000000046",
+            ))
+        }
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        u32::deserialize(deserializer).map(Self)
+    }
+}
+
+impl Serialize for BOOL {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_bool(self.as_bool())
+    }
+}
+
+impl<'de> Deserialize<'de> for BOOL {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        bool::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl Serialize for NTSTATUS {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for NTSTATUS {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        i32::deserialize(deserializer).map(Self)
+    }
+}
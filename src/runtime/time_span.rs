@@ -1,17 +1,27 @@
+use super::date_time::{duration_to_ticks, ticks_to_duration};
 use crate::foundation::TimeSpan;
-use crate::Param;
+use crate::*;
+use std::convert::TryFrom;
 
 impl std::convert::From<std::time::Duration> for TimeSpan {
     fn from(value: std::time::Duration) -> Self {
         Self {
-            duration: (value.as_nanos() / 100) as i64,
+            duration: duration_to_ticks(value),
         }
     }
 }
 
-impl std::convert::From<TimeSpan> for std::time::Duration {
-    fn from(value: TimeSpan) -> Self {
-        std::time::Duration::from_nanos((value.duration * 100) as u64)
+// A `TimeSpan` may be negative whereas a `Duration` may not, in which case the conversion fails
+// with `E_BOUNDS`.
+impl TryFrom<TimeSpan> for std::time::Duration {
+    type Error = Error;
+
+    fn try_from(value: TimeSpan) -> Result<Self> {
+        if value.duration < 0 {
+            Err(Error::fast_error(ErrorCode::E_BOUNDS))
+        } else {
+            Ok(ticks_to_duration(value.duration as u64))
+        }
     }
 }
 
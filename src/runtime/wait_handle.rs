@@ -0,0 +1,109 @@
+use crate::*;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// A future that completes once a kernel object, such as an event, process, mutex, or waitable
+/// timer, is signaled.
+///
+/// The wait is handed to the thread pool with `RegisterWaitForSingleObject` rather than blocking
+/// a thread of its own, so awaiting a process's exit or a named event composes with other
+/// futures.
+pub struct WaitHandle {
+    wait: RawPtr,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Default)]
+struct State {
+    signaled: bool,
+    waker: Option<Waker>,
+}
+
+impl WaitHandle {
+    /// Starts waiting on the handle.
+    ///
+    /// # Safety
+    ///
+    /// The handle must remain open until the future completes or is dropped.
+    pub unsafe fn new(handle: RawPtr) -> Result<Self> {
+        const INFINITE: u32 = 0xFFFF_FFFF;
+        const WT_EXECUTEONLYONCE: u32 = 0x0000_0008;
+
+        let state = Arc::new(Mutex::new(State::default()));
+        let context = Arc::into_raw(state.clone()) as RawPtr;
+        let mut wait = std::ptr::null_mut();
+
+        if RegisterWaitForSingleObject(
+            &mut wait,
+            handle,
+            callback,
+            context,
+            INFINITE,
+            WT_EXECUTEONLYONCE,
+        ) == 0
+        {
+            drop(Arc::from_raw(context as *const Mutex<State>));
+            return Err(ErrorCode::from_thread().into());
+        }
+
+        Ok(Self { wait, state })
+    }
+}
+
+impl Future for WaitHandle {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+
+        if state.signaled {
+            Poll::Ready(())
+        } else {
+            state.waker = Some(context.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for WaitHandle {
+    fn drop(&mut self) {
+        const INVALID_HANDLE_VALUE: RawPtr = -1isize as RawPtr;
+
+        unsafe {
+            // Waits for a callback that's already running, so that it's safe to release the
+            // reference it would otherwise release.
+            UnregisterWaitEx(self.wait, INVALID_HANDLE_VALUE);
+
+            if !self.state.lock().unwrap().signaled {
+                drop(Arc::from_raw(Arc::as_ptr(&self.state)));
+            }
+        }
+    }
+}
+
+unsafe impl Send for WaitHandle {}
+
+extern "system" fn callback(context: RawPtr, _timed_out: u8) {
+    let state = unsafe { Arc::from_raw(context as *const Mutex<State>) };
+    let mut state = state.lock().unwrap();
+    state.signaled = true;
+
+    if let Some(waker) = state.waker.take() {
+        waker.wake();
+    }
+}
+
+#[link(name = "kernel32")]
+extern "system" {
+    fn RegisterWaitForSingleObject(
+        wait: *mut RawPtr,
+        handle: RawPtr,
+        callback: extern "system" fn(RawPtr, u8),
+        context: RawPtr,
+        milliseconds: u32,
+        flags: u32,
+    ) -> i32;
+    fn UnregisterWaitEx(wait: RawPtr, event: RawPtr) -> i32;
+}
@@ -13,6 +13,9 @@ impl Waiter {
     }
 }
 
+// The event may be signaled from any thread, such as the one that completes an async operation.
+unsafe impl Send for WaiterSignaler {}
+
 impl WaiterSignaler {
     /// Signals the `Waiter`. This is unsafe because the lifetime of `WaiterSignaler` is not tied
     /// to the lifetime of the `Waiter`. This is not possible in this case because the `Waiter`
@@ -50,6 +50,14 @@ impl HString {
         String::from_utf16_lossy(self.as_wide())
     }
 
+    /// Get the contents of this `HString` as an `OsString`, keeping any wide characters that
+    /// aren't valid UTF-16, as file names may hold.
+    #[cfg(windows)]
+    pub fn to_os_string(&self) -> std::ffi::OsString {
+        use std::os::windows::ffi::OsStringExt;
+        std::ffi::OsString::from_wide(self.as_wide())
+    }
+
     /// Clear the contents of the string and free the memory if `self` holds the
     /// last reference to the string data.
     pub fn clear(&mut self) {
@@ -131,11 +139,13 @@ impl Drop for HString {
     }
 }
 
+// Wide characters that aren't valid UTF-16 are written as the replacement character, as with
+// `to_string_lossy`, rather than failing the whole format.
 impl std::fmt::Display for HString {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use std::fmt::Write;
         for c in std::char::decode_utf16(self.as_wide().iter().cloned()) {
-            f.write_char(c.map_err(|_| std::fmt::Error)?)?
+            f.write_char(c.unwrap_or(std::char::REPLACEMENT_CHARACTER))?
         }
         Ok(())
     }
@@ -165,6 +175,37 @@ impl From<&String> for HString {
     }
 }
 
+// The length of an `OsStr` is that of its internal encoding, which has at least as many bytes
+// as the string has wide characters.
+#[cfg(windows)]
+impl From<&std::ffi::OsStr> for HString {
+    fn from(value: &std::ffi::OsStr) -> Self {
+        use std::os::windows::ffi::OsStrExt;
+        unsafe { Self::from_wide_iter(value.encode_wide(), value.len() as u32) }
+    }
+}
+
+#[cfg(windows)]
+impl From<std::ffi::OsString> for HString {
+    fn from(value: std::ffi::OsString) -> Self {
+        value.as_os_str().into()
+    }
+}
+
+#[cfg(windows)]
+impl From<&std::path::Path> for HString {
+    fn from(value: &std::path::Path) -> Self {
+        value.as_os_str().into()
+    }
+}
+
+#[cfg(windows)]
+impl From<&HString> for std::ffi::OsString {
+    fn from(value: &HString) -> Self {
+        value.to_os_string()
+    }
+}
+
 impl PartialEq for HString {
     fn eq(&self, other: &Self) -> bool {
         self.as_wide() == other.as_wide()
@@ -366,4 +407,30 @@ mod tests {
         let s = h.to_string_lossy();
         assert_eq!(s, "𝄞mu�ic");
     }
+
+    #[test]
+    fn hstring_display_lossy() {
+        // 𝄞mu<invalid>ic
+        let wide_data = &[0xD834, 0xDD1E, 0x006d, 0x0075, 0xD800, 0x0069, 0x0063];
+        let h = StringType::from_wide(wide_data);
+        assert_eq!(h.to_string(), "𝄞mu�ic");
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn hstring_os_string() {
+        use std::os::windows::ffi::OsStringExt;
+
+        // Unpaired surrogates survive the round trip.
+        let wide_data = &[0x0066, 0xD800, 0x0069, 0x006c, 0x0065];
+        let os = std::ffi::OsString::from_wide(wide_data);
+        let h = StringType::from(os.as_os_str());
+        assert!(h.as_wide() == wide_data);
+        assert!(h.to_os_string() == os);
+
+        let path = std::path::Path::new("C:\\Windows\\System32");
+        let h = StringType::from(path);
+        assert!(h == "C:\\Windows\\System32");
+        assert!(std::ffi::OsString::from(&h) == *path.as_os_str());
+    }
 }
@@ -1,7 +1,9 @@
 mod bstring;
 mod costring;
 mod hstring;
+mod string_param;
 
 pub use bstring::*;
 pub use costring::*;
 pub use hstring::*;
+pub use string_param::*;
@@ -0,0 +1,120 @@
+use crate::*;
+use std::marker::PhantomData;
+
+// A null-terminated string passed to a Win32 function, formed from a Rust string and converted
+// on the caller's behalf, or from a pointer that's passed as is. `StringParam` is used by the
+// generated bindings and should not generally be used directly.
+//
+// Wide strings, as `u16`, are converted from UTF-8 to UTF-16. ANSI strings, as `i8`, are passed
+// as UTF-8, which the functions read in the process's code page, so text beyond ASCII is best
+// passed to the wide function instead.
+//
+// The pointer returned by `abi` is only valid for as long as the `StringParam` itself, which is
+// why the generated bindings only ever call it within the expression that makes the call.
+#[doc(hidden)]
+pub enum StringParam<'a, C> {
+    Borrowed(*const C, PhantomData<&'a [C]>),
+    Owned(Vec<C>),
+}
+
+impl<'a, C> StringParam<'a, C> {
+    pub fn abi(&self) -> *const C {
+        match self {
+            StringParam::Borrowed(value, _) => *value,
+            StringParam::Owned(value) => value.as_ptr(),
+        }
+    }
+
+    fn raw(value: *const C) -> Self {
+        StringParam::Borrowed(value, PhantomData)
+    }
+}
+
+impl<'a> From<&str> for StringParam<'a, u16> {
+    fn from(value: &str) -> Self {
+        StringParam::Owned(value.encode_utf16().chain(std::iter::once(0)).collect())
+    }
+}
+
+impl<'a> From<String> for StringParam<'a, u16> {
+    fn from(value: String) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl<'a> From<&String> for StringParam<'a, u16> {
+    fn from(value: &String) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl<'a> From<&HString> for StringParam<'a, u16> {
+    fn from(value: &HString) -> Self {
+        StringParam::Owned(
+            value
+                .as_wide()
+                .iter()
+                .copied()
+                .chain(std::iter::once(0))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(windows)]
+impl<'a> From<&std::ffi::OsStr> for StringParam<'a, u16> {
+    fn from(value: &std::ffi::OsStr) -> Self {
+        use std::os::windows::ffi::OsStrExt;
+        StringParam::Owned(value.encode_wide().chain(std::iter::once(0)).collect())
+    }
+}
+
+#[cfg(windows)]
+impl<'a> From<&std::path::Path> for StringParam<'a, u16> {
+    fn from(value: &std::path::Path) -> Self {
+        value.as_os_str().into()
+    }
+}
+
+impl<'a> From<&str> for StringParam<'a, i8> {
+    fn from(value: &str) -> Self {
+        StringParam::Owned(
+            value
+                .bytes()
+                .map(|byte| byte as i8)
+                .chain(std::iter::once(0))
+                .collect(),
+        )
+    }
+}
+
+impl<'a> From<String> for StringParam<'a, i8> {
+    fn from(value: String) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl<'a> From<&String> for StringParam<'a, i8> {
+    fn from(value: &String) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl<'a> From<&'a std::ffi::CStr> for StringParam<'a, i8> {
+    fn from(value: &'a std::ffi::CStr) -> Self {
+        StringParam::Borrowed(value.as_ptr() as _, PhantomData)
+    }
+}
+
+// Pointers, including null for optional strings, are passed as is.
+impl<'a, C> From<*const C> for StringParam<'a, C> {
+    fn from(value: *const C) -> Self {
+        Self::raw(value)
+    }
+}
+
+impl<'a, C> From<*mut C> for StringParam<'a, C> {
+    fn from(value: *mut C) -> Self {
+        Self::raw(value)
+    }
+}
@@ -58,7 +58,9 @@ unsafe impl<T: Interface> Abi for Option<T> {
         self as *mut _ as *mut _
     }
 
-    unsafe fn from_abi(_: Self::Abi) -> Result<Self> {
-        panic!("Option<T> should not not be used for return types. Use Result<T> instead.");
+    // A null pointer is returned as `None`, which is used for values that are optional by design
+    // such as an `IReference<T>`. Interfaces that must not be null are returned as `Result<T>`.
+    unsafe fn from_abi(abi: Self::Abi) -> Result<Self> {
+        Ok(std::mem::transmute_copy(&abi))
     }
 }
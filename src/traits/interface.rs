@@ -36,3 +36,9 @@ pub unsafe trait Interface: Sized + Abi {
         }
     }
 }
+
+/// Returns the interface identifier (IID) of `T`, such as for a call to `QueryInterface` or
+/// `CoCreateInstance` written generically over the interface it requests.
+pub fn uuidof<T: Interface>() -> Guid {
+    T::IID
+}